@@ -7,7 +7,7 @@ use std::sync::Mutex;
 use structopt::StructOpt;
 use console::{style, Term};
 
-use lib::{Address, ImplicitAddress, PublicKey, ToBase58Check};
+use lib::{Address, ImplicitAddress, ToBase58Check};
 use lib::api::*;
 use lib::http_api::HttpApi;
 use lib::explorer_api::TzStats;
@@ -40,18 +40,19 @@ impl Display for CommandError {
 
 /// Test cli functionalities.
 ///
-/// Pass public and private keys of the account, which will be the
+/// Pass name of the account in the local keystore, which will be the
 /// source of funds. Needs to have tezos in balance!
+///
+/// Since commands are run without interactivity, the account needs to be
+/// imported without encryption: `tezedge-client keys import --unencrypted`.
 #[derive(StructOpt)]
 pub struct TestAll {
     #[structopt(short = "E", long)]
     pub endpoint: String,
 
-    #[structopt(long = "public-key")]
-    pub public_key: String,
-
-    #[structopt(long = "private-key")]
-    pub private_key: String,
+    /// Name of the local keystore account.
+    #[structopt(long)]
+    pub from: String,
 
     // #[structopt(long = "trezor")]
     // pub use_trezor: bool,
@@ -220,7 +221,6 @@ impl TestAll {
 
     fn transfer_local_command(
         &self,
-        from: &Address,
         to: &Address,
         amount: &str,
         fee: Option<&str>,
@@ -228,12 +228,10 @@ impl TestAll {
     {
         let mut command = self.cli_command();
         command
-            .arg("unsafe-transfer-local")
+            .arg("transfer")
             .arg("--no-prompt")
             .arg("--endpoint").arg(&self.endpoint)
-            .arg("--private-key").arg(&self.private_key)
-            .arg("--public-key").arg(&self.public_key)
-            .arg("--from").arg(from.to_base58check())
+            .arg("--from").arg(&self.from)
             .arg("--to").arg(to.to_base58check())
             .arg("--amount").arg(amount);
 
@@ -246,16 +244,15 @@ impl TestAll {
 
     fn transfer_local(
         &self,
-        from: &Address,
         to: &Address,
         amount: &str,
         fee: Option<&str>,
     ) -> Result<String, CommandError>
     {
-        let mut command = self.transfer_local_command(from, to, amount, fee.clone());
+        let mut command = self.transfer_local_command(to, amount, fee.clone());
         let command_str = format!("{:?}", &command);
 
-        let output = command.output().expect("failed to get output of unsafe-transfer-local command!");
+        let output = command.output().expect("failed to get output of transfer command!");
 
         if output.status.success() {
             Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
@@ -680,9 +677,6 @@ impl TestAll {
         spinner.finish_succeed("build successful!");
 
         let key_path_prefix = "m/44'/1729'/0'".to_string();
-        let public_key = PublicKey::from_base58check(&self.public_key)
-            .map_err(|_| "invalid --public-key passed")?;
-        let local_address: Address = public_key.hash().into();
 
         let (key_path_i, trezor_key_path, trezor_address)
             = self.hw_find_unrevealed("trezor", &key_path_prefix, 0)?;
@@ -698,7 +692,7 @@ impl TestAll {
             .start();
 
         let op_hash = spinner.fail_if(
-            self.transfer_local(&local_address, &trezor_address, "2", None),
+            self.transfer_local(&trezor_address, "2", None),
         )?;
         spinner.finish_succeed(format!(
             "funds transfered to trezor account. Operation hash: {}",
@@ -714,7 +708,7 @@ impl TestAll {
             .start();
 
         let op_hash = spinner.fail_if(
-            self.transfer_local(&local_address, &ledger_address, "2", None),
+            self.transfer_local(&ledger_address, "2", None),
         )?;
         spinner.finish_succeed(format!(
             "funds transfered to ledger account. Operation hash: {}",
@@ -748,7 +742,7 @@ impl TestAll {
             .start();
 
         let op_hash = spinner.fail_if(
-            self.transfer_local(&local_address, &trezor_address, "5", None),
+            self.transfer_local(&trezor_address, "5", None),
         )?;
         spinner.finish_succeed(format!(
             "funds transfered to trezor account. Operation hash: {}",
@@ -764,7 +758,7 @@ impl TestAll {
             .start();
 
         let op_hash = spinner.fail_if(
-            self.transfer_local(&local_address, &ledger_address, "5", None),
+            self.transfer_local(&ledger_address, "5", None),
         )?;
         spinner.finish_succeed(format!(
             "funds transfered to ledger account. Operation hash: {}",
@@ -0,0 +1,135 @@
+use structopt::StructOpt;
+use console::style;
+
+use lib::{
+    Forge, ToBase58Check, ImplicitAddress,
+    NewOperationGroup, NewActivationOperation,
+};
+use lib::crypto::hex;
+use lib::api::*;
+use lib::http_api::HttpApi;
+use lib::explorer_api::TzStats;
+
+use cli_spinner::SpinnerBuilder;
+use crate::commands::CommandError;
+use crate::common::{exit_with_error, Keystore};
+use crate::common::operation_command::{ParseAddressError, AddressKind};
+
+/// Activate fundraiser account.
+///
+/// Activation doesn't need to be signed, so only fundraiser address
+/// and activation code (secret) are needed.
+///
+/// Outputs operation hash to stdout in case of success.
+#[derive(StructOpt)]
+pub struct Activate {
+    /// Node's rpc endpoint.
+    ///
+    /// Sample Testnet nodes:
+    /// - https://api.tez.ie/rpc/edonet
+    /// - https://rpctest.tzbeta.net
+    /// - https://testnet-tezos.giganode.io
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    /// Fundraiser address (tz1) to activate.
+    ///
+    /// Name of the account in the local keystore can be used as well.
+    #[structopt(short, long)]
+    pub from: String,
+
+    /// Activation code (secret) of the fundraiser account.
+    #[structopt(long)]
+    pub secret: String,
+}
+
+impl Activate {
+    fn get_address(&self) -> Result<ImplicitAddress, CommandError> {
+        let keystore = Keystore::open_default()?;
+        if let Some(account) = keystore.find(&self.from) {
+            return Ok(account.address());
+        }
+
+        Ok(ImplicitAddress::from_base58check(&self.from)
+            .map_err(|error| ParseAddressError {
+                kind: AddressKind::Source,
+                error,
+                address: self.from.clone(),
+            })?)
+    }
+
+    pub fn execute(self) -> Result<(), CommandError> {
+        let pkh = self.get_address()?;
+        if !matches!(pkh, ImplicitAddress::tz1(_)) {
+            exit_with_error(format!(
+                "only {} addresses can be activated.",
+                style("tz1").bold(),
+            ));
+        }
+        let secret = NewActivationOperation::parse_secret(&self.secret)?;
+
+        let api = HttpApi::new(self.endpoint.clone());
+
+        let spinner = SpinnerBuilder::new()
+            .with_prefix(style("[1/3]").bold().dim())
+            .with_text("fetching necessary data from the node")
+            .start();
+
+        let version = api.get_version_info()?;
+        let protocol_info = api.get_protocol_info()?;
+        let head_block_hash = api.get_head_block_hash()?;
+
+        spinner.finish_succeed("fetched necessary data from the node");
+
+        let operation_group = NewOperationGroup::new(
+            head_block_hash,
+            protocol_info.next_protocol_hash,
+        ).with_operation(NewActivationOperation { pkh: pkh.clone(), secret });
+
+        let spinner = SpinnerBuilder::new()
+            .with_prefix(style("[2/3]").bold().dim())
+            .with_text("simulating the operation")
+            .start();
+
+        api.run_operation(&operation_group)?;
+
+        spinner.finish_succeed("operation simulated");
+
+        let spinner = SpinnerBuilder::new()
+            .with_prefix(style("[3/3]").bold().dim())
+            .with_text("injecting the operation")
+            .start();
+
+        // activation is injected without a signature.
+        let forged_operation = hex::encode(operation_group.forge().as_ref());
+        let operation_hash = match api.inject_operations(&forged_operation)? {
+            serde_json::Value::String(hash) => hash,
+            value => value.to_string(),
+        };
+
+        spinner.finish_succeed(format!(
+            "injected activation of {}",
+            style(pkh.to_base58check()).bold(),
+        ));
+
+        match TzStats::new(version.get_network()) {
+            Ok(tzstats) => {
+                eprintln!(
+                    "\n  {}View operation at: {}/{}",
+                    emojies::FINGER_POINTER_RIGHT,
+                    style(tzstats.operation_link_prefix()).cyan(),
+                    style(&operation_hash).cyan(),
+                );
+            }
+            Err(_) => {
+                eprintln!("\nOperation hash: {}", style(&operation_hash).green());
+            }
+        };
+
+        if !console::user_attended() {
+            println!("{}", &operation_hash);
+        }
+
+        Ok(())
+    }
+}
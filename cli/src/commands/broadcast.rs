@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+use structopt::StructOpt;
+use console::style;
+
+use lib::api::GetProtocolInfo;
+use lib::http_api::HttpApi;
+
+use crate::commands::CommandError;
+use crate::common::{exit_with_error, OfflineOperation};
+use crate::common::operation_command::inject_operation;
+
+/// Inject an operation signed using `sign` command.
+///
+/// Outputs operation hash to stdout in case of success.
+#[derive(StructOpt)]
+pub struct Broadcast {
+    /// Node's rpc endpoint.
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    /// File with the operation signed using `sign` command.
+    #[structopt(parse(from_os_str))]
+    pub input: PathBuf,
+}
+
+impl Broadcast {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let mut api = HttpApi::new(self.endpoint.clone());
+        let mut operation = OfflineOperation::read(&self.input, true)?;
+        let sig_info = operation.signature_info()?;
+
+        let protocol = api.get_protocol_info()?.next_protocol_hash;
+        if operation.protocol.is_empty() {
+            // hex file doesn't contain the protocol.
+            operation.protocol = protocol;
+        } else if operation.protocol != protocol {
+            exit_with_error(format!(
+                "operation was prepared for protocol {}, but node is using {}. Please prepare it again.",
+                style(&operation.protocol).bold(),
+                style(&protocol).red(),
+            ));
+        }
+
+        inject_operation(&mut api, &operation.operation_group()?, &sig_info)
+    }
+}
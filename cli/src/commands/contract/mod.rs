@@ -0,0 +1,27 @@
+use structopt::StructOpt;
+
+pub mod show;
+pub use show::Show;
+
+use crate::commands::CommandError;
+
+/// Inspect smart contracts.
+#[derive(StructOpt)]
+pub enum Contract {
+    Show(Show),
+}
+
+impl Contract {
+    /// Get node endpoint.
+    pub fn get_endpoint(&self) -> Option<&str> {
+        match self {
+            Self::Show(cmd) => Some(cmd.endpoint.as_str()),
+        }
+    }
+
+    pub fn execute(self) -> Result<(), CommandError> {
+        match self {
+            Self::Show(c) => c.execute(),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use structopt::StructOpt;
+use console::style;
+
+use lib::{
+    OriginatedAddress, ToBase58Check, Forge, NewOriginationScript,
+    contract_entrypoints, format_token_amount,
+};
+use lib::micheline::{Micheline, BigUint};
+use lib::api::{GetContractBalance, GetContractDelegate, GetContractStorage, GetContractCode};
+use lib::http_api::HttpApi;
+
+use crate::commands::CommandError;
+
+/// Show balance, delegate, storage and entrypoints of the contract.
+///
+/// Outputs to stdout, as human readable text or json (see --json).
+#[derive(StructOpt)]
+pub struct Show {
+    /// Node's rpc endpoint.
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    /// Output as json. Micheline values are in the node's json format
+    /// and balance is in µꜩ.
+    #[structopt(long)]
+    pub json: bool,
+
+    /// Address of the contract (KT1).
+    pub contract: String,
+}
+
+/// Indent every line, except the first one.
+fn indent(text: &str, indent: &str) -> String {
+    text.lines().collect::<Vec<_>>().join(&format!("\n{}", indent))
+}
+
+impl Show {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let contract = OriginatedAddress::from_base58check(&self.contract)
+            .map_err(|_| format!("invalid contract address: {}", style(&self.contract).red()))?;
+        let address = contract.clone().into();
+        let api = HttpApi::new(&self.endpoint);
+
+        let balance = api.get_contract_balance(&address)?;
+        let delegate = api.get_contract_delegate(&address)?;
+        let script = NewOriginationScript {
+            code: api.get_contract_code(&contract)?,
+            storage: serde_json::from_value::<Micheline>(api.get_contract_storage(&contract)?)?,
+        };
+        let parameter_type = script.parameter_type()
+            .ok_or("contract's code doesn't contain parameter type.")?;
+        let entrypoints = contract_entrypoints(parameter_type);
+        let code_size = script.code.forge().take().len();
+
+        if self.json {
+            let output = serde_json::json!({
+                "address": contract.to_base58check(),
+                "balance": balance.to_string(),
+                "delegate": delegate.map(|delegate| delegate.to_base58check()),
+                "code_size": code_size,
+                "parameter_type": parameter_type,
+                "entrypoints": entrypoints.iter()
+                    .map(|entrypoint| serde_json::json!({
+                        "name": entrypoint.name,
+                        "type": entrypoint.parameter_type,
+                    }))
+                    .collect::<Vec<_>>(),
+                "storage": script.storage,
+            });
+            println!("{}", serde_json::to_string_pretty(&output)?);
+            return Ok(());
+        }
+
+        println!("{} {}", style("Contract:").bold(), contract.to_base58check());
+        println!(
+            "{} {} ꜩ",
+            style("Balance:").bold(),
+            format_token_amount(&BigUint::from(balance), 6),
+        );
+        println!(
+            "{} {}",
+            style("Delegate:").bold(),
+            delegate.map(|delegate| delegate.to_base58check())
+                .unwrap_or_else(|| "none".to_string()),
+        );
+        println!("{} {} bytes", style("Code size:").bold(), code_size);
+
+        println!("\n{}\n  {}", style("Parameter type:").bold(), indent(&parameter_type.to_string(), "  "));
+
+        println!("\n{}", style("Entrypoints:").bold());
+        for entrypoint in entrypoints.iter() {
+            println!(
+                "  - {}: {}",
+                style(&entrypoint.name).green(),
+                indent(&entrypoint.parameter_type.to_string(), "    "),
+            );
+        }
+
+        println!("\n{}\n  {}", style("Storage:").bold(), indent(&script.storage.to_string(), "  "));
+
+        Ok(())
+    }
+}
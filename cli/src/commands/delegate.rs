@@ -29,13 +29,8 @@ pub struct Delegate {
     #[structopt(short = "E", long)]
     pub endpoint: String,
 
-    /// Use Trezor device.
-    #[structopt(long = "trezor")]
-    pub use_trezor: bool,
-
-    /// Use Ledger device.
-    #[structopt(long = "ledger")]
-    pub use_ledger: bool,
+    #[structopt(flatten)]
+    pub signer: SignerOptions,
 
     /// Key Derivation Path.
     ///
@@ -51,7 +46,11 @@ pub struct Delegate {
     /// When delegating from scriptless smart contract (KT1) address,
     /// this needs to be that KT1 address.
     ///
-    /// Otherwise use key derivation path, like: "m/44'/1729'/0'/0'"
+    /// When using Trezor or Ledger, use key derivation path,
+    /// like: "m/44'/1729'/0'/0'"
+    ///
+    /// Otherwise use name or address of the account in the local
+    /// keystore (see `keys import`).
     #[structopt(short, long)]
     pub from: String,
 
@@ -74,13 +73,12 @@ pub struct Delegate {
 }
 
 impl RawOperationCommand for Delegate {
-    fn get_raw_options(&self) -> RawOptions {
-        RawOptions {
-            api_type: "http".to_string(),
-            no_prompt: self.no_prompt,
-            use_trezor: self.use_trezor,
-            use_ledger: self.use_ledger,
-        }
+    fn get_no_prompt(&self) -> bool {
+        self.no_prompt
+    }
+
+    fn get_signer_options(&self) -> &SignerOptions {
+        &self.signer
     }
 
     fn get_api_endpoint(&self) -> String {
@@ -0,0 +1,69 @@
+use structopt::StructOpt;
+
+use lib::{ToBase58Check, Fa2BalanceOf, Fa2BalanceRequest, format_token_amount};
+use lib::api::RunView;
+use lib::http_api::HttpApi;
+
+use crate::commands::CommandError;
+use crate::commands::token::{parse_contract, parse_address};
+use super::{parse_token_id, split_arg, TokenDecimals};
+
+/// Get token balances, using `balance_of` view of the FA2 contract.
+///
+/// Outputs balances to stdout, one per line, in the same order as
+/// requested: "<owner> <token_id> <balance>".
+#[derive(StructOpt)]
+pub struct Balance {
+    /// Node's rpc endpoint.
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    /// Address of the FA2 token contract.
+    #[structopt(short, long)]
+    pub contract: String,
+
+    /// Number of decimals of the token, used to format the balances.
+    ///
+    /// If not specified, it's read from the token metadata of the
+    /// contract, or 0 is used if it's not available.
+    #[structopt(long)]
+    pub decimals: Option<u32>,
+
+    /// Balances to query, like: "<owner>:<token_id>".
+    ///
+    /// Owner is an address, or name of the account in the local keystore.
+    #[structopt(required = true)]
+    pub requests: Vec<String>,
+}
+
+impl Balance {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let contract = parse_contract(&self.contract)?;
+        let view = Fa2BalanceOf {
+            requests: self.requests.iter()
+                .map(|request| {
+                    let parts = split_arg(request, "request", "<owner>:<token_id>", &[2])?;
+                    Ok(Fa2BalanceRequest {
+                        owner: parse_address(parts[0])?,
+                        token_id: parse_token_id(parts[1])?,
+                    })
+                })
+                .collect::<Result<_, CommandError>>()?,
+        };
+
+        let api = HttpApi::new(&self.endpoint);
+        let result = api.run_view(&contract, view.entrypoint(), &view.input())?;
+        let mut token_decimals = TokenDecimals::new(self.decimals, &self.endpoint, &contract);
+
+        for response in view.parse_result(&result)? {
+            let decimals = token_decimals.get(&response.request.token_id)?;
+            println!(
+                "{} {} {}",
+                response.request.owner.to_base58check(),
+                response.request.token_id,
+                format_token_amount(&response.balance, decimals),
+            );
+        }
+        Ok(())
+    }
+}
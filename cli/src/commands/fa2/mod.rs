@@ -0,0 +1,101 @@
+use structopt::StructOpt;
+use console::style;
+
+use lib::OriginatedAddress;
+use lib::micheline::BigUint;
+
+pub mod transfer;
+pub use transfer::Transfer;
+
+pub mod update_operators;
+pub use update_operators::UpdateOperators;
+
+pub mod balance;
+pub use balance::Balance;
+
+use crate::commands::CommandError;
+use crate::commands::token::token_decimals;
+
+/// Transfer and query FA2 (TZIP-12) multi-asset tokens.
+#[derive(StructOpt)]
+pub enum Fa2 {
+    Transfer(Transfer),
+    UpdateOperators(UpdateOperators),
+    Balance(Balance),
+}
+
+impl Fa2 {
+    /// Get node endpoint.
+    pub fn get_endpoint(&self) -> Option<&str> {
+        match self {
+            Self::Transfer(cmd) => Some(cmd.endpoint.as_str()),
+            Self::UpdateOperators(cmd) => Some(cmd.endpoint.as_str()),
+            Self::Balance(cmd) => Some(cmd.endpoint.as_str()),
+        }
+    }
+
+    pub fn execute(self) -> Result<(), CommandError> {
+        match self {
+            Self::Transfer(c) => c.execute(),
+            Self::UpdateOperators(c) => c.execute(),
+            Self::Balance(c) => c.execute(),
+        }
+    }
+}
+
+fn parse_token_id(token_id: &str) -> Result<BigUint, CommandError> {
+    Ok(token_id.parse()
+        .map_err(|_| format!("invalid token id: {}", style(token_id).red()))?)
+}
+
+/// Decimals of the tokens of the FA2 contract.
+///
+/// Unless given with `--decimals`, they are read from the token
+/// metadata of the contract, once for each token id.
+struct TokenDecimals<'a> {
+    decimals: Option<u32>,
+    endpoint: &'a str,
+    contract: &'a OriginatedAddress,
+    read: Vec<(BigUint, u32)>,
+}
+
+impl<'a> TokenDecimals<'a> {
+    fn new(decimals: Option<u32>, endpoint: &'a str, contract: &'a OriginatedAddress) -> Self {
+        Self { decimals, endpoint, contract, read: vec![] }
+    }
+
+    fn get(&mut self, token_id: &BigUint) -> Result<u32, CommandError> {
+        if let Some(decimals) = self.decimals {
+            return Ok(decimals);
+        }
+        if let Some((_, decimals)) = self.read.iter().find(|(id, _)| id == token_id) {
+            return Ok(*decimals);
+        }
+
+        let decimals = token_decimals(self.endpoint, self.contract, token_id)?;
+        self.read.push((token_id.clone(), decimals));
+        Ok(decimals)
+    }
+}
+
+/// Split colon separated argument, like: `<owner>:<token_id>`.
+fn split_arg<'a>(
+    arg: &'a str,
+    name: &str,
+    format: &str,
+    counts: &[usize],
+) -> Result<Vec<&'a str>, CommandError>
+{
+    let parts: Vec<_> = arg.split(':').collect();
+
+    if counts.contains(&parts.len()) {
+        Ok(parts)
+    } else {
+        Err(format!(
+            "invalid {} {}, expected: {}",
+            style(name).bold(),
+            style(arg).red(),
+            format,
+        ).into())
+    }
+}
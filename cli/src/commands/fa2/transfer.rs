@@ -0,0 +1,143 @@
+use structopt::StructOpt;
+use console::style;
+
+use lib::{ToBase58Check, Fa2Call, Fa2Transfer, Fa2TransferDestination};
+use lib::{parse_token_amount, format_token_amount};
+
+use crate::commands::CommandError;
+use crate::common::operation_command::*;
+use crate::commands::token::{parse_contract, parse_address};
+use super::{parse_token_id, split_arg, TokenDecimals};
+
+/// Transfer FA2 tokens.
+///
+/// Several transfers (of possibly different tokens and owners) are
+/// sent in a single call of the `transfer` entrypoint.
+///
+/// Outputs operation hash to stdout in case of success.
+#[derive(StructOpt)]
+pub struct Transfer {
+    /// Disable interactivity and accept default answers to prompts.
+    #[structopt(short = "y", long = "no-prompt")]
+    pub no_prompt: bool,
+
+    /// Node's rpc endpoint.
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    #[structopt(flatten)]
+    pub signer: SignerOptions,
+
+    /// Account, which sends the operation.
+    ///
+    /// When using Trezor or Ledger, use key derivation path,
+    /// like: "m/44'/1729'/0'/0'"
+    ///
+    /// Otherwise use name or address of the account in the local
+    /// keystore (see `keys import`).
+    #[structopt(short, long)]
+    pub from: String,
+
+    /// Address of the FA2 token contract.
+    #[structopt(short, long)]
+    pub contract: String,
+
+    /// Transfer, like: "[<owner>:]<to>:<token_id>:<amount>".
+    ///
+    /// Can be repeated. If owner isn't specified, tokens of the --from
+    /// account are transferred. Otherwise --from account needs to be
+    /// an operator of the owner (see `fa2 update-operators`).
+    #[structopt(long = "tx", required = true, number_of_values = 1)]
+    pub txs: Vec<String>,
+
+    /// Number of decimals of the token.
+    ///
+    /// Amounts are multiplied by 10^decimals to get the amounts in the
+    /// smallest units of the token. If not specified, it's read from
+    /// the token metadata of the contract, or 0 is used if it's not
+    /// available.
+    #[structopt(long)]
+    pub decimals: Option<u32>,
+
+    /// Fee for the operation.
+    ///
+    /// If not specified, fee will be estimated and you will be prompted
+    /// whether or not you accept estimate or would like to enter custom one.
+    #[structopt(long)]
+    pub fee: Option<String>,
+}
+
+impl RawOperationCommand for Transfer {
+    fn get_no_prompt(&self) -> bool {
+        self.no_prompt
+    }
+
+    fn get_signer_options(&self) -> &SignerOptions {
+        &self.signer
+    }
+
+    fn get_api_endpoint(&self) -> String {
+        self.endpoint.clone()
+    }
+
+    fn get_raw_key_path(&self) -> Option<&str> {
+        None
+    }
+
+    fn get_raw_from(&self) -> &str {
+        &self.from
+    }
+
+    fn get_raw_fee(&self) -> Option<&String> {
+        self.fee.as_ref()
+    }
+}
+
+impl Transfer {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let contract = parse_contract(&self.contract)?;
+        let format = "[<owner>:]<to>:<token_id>:<amount>";
+        let mut token_decimals = TokenDecimals::new(self.decimals, &self.endpoint, &contract);
+        let txs = self.txs.iter()
+            .map(|tx| {
+                let parts = split_arg(tx, "--tx", format, &[3, 4])?;
+                let (owner, parts) = match parts.len() {
+                    4 => (Some(parse_address(parts[0])?), &parts[1..]),
+                    _ => (None, &parts[..]),
+                };
+                let token_id = parse_token_id(parts[1])?;
+                let decimals = token_decimals.get(&token_id)?;
+                let destination = Fa2TransferDestination {
+                    to: parse_address(parts[0])?,
+                    token_id,
+                    amount: parse_token_amount(parts[2], decimals)?,
+                };
+                Ok((owner, destination, decimals))
+            })
+            .collect::<Result<Vec<_>, CommandError>>()?;
+
+        let mut command = self.parse()?;
+
+        // group transfers by the owner, keeping the order.
+        let mut transfers: Vec<Fa2Transfer> = vec![];
+        for (owner, destination, decimals) in txs {
+            let from = owner.unwrap_or_else(|| command.from.clone());
+
+            eprintln!(
+                "transferring {} tokens with id {} from {} to {}",
+                style(format_token_amount(&destination.amount, decimals)).green(),
+                style(&destination.token_id).bold(),
+                style(from.to_base58check()).bold(),
+                style(destination.to.to_base58check()).bold(),
+            );
+
+            match transfers.iter_mut().find(|transfer| transfer.from == from) {
+                Some(transfer) => transfer.txs.push(destination),
+                None => transfers.push(Fa2Transfer { from, txs: vec![destination] }),
+            }
+        }
+        eprintln!();
+
+        command.call_contract(contract, Fa2Call::Transfer(transfers).into())
+    }
+}
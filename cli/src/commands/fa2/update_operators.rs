@@ -0,0 +1,120 @@
+use structopt::StructOpt;
+use console::style;
+
+use lib::{ToBase58Check, Fa2Call, Fa2Operator, Fa2OperatorUpdate};
+
+use crate::commands::CommandError;
+use crate::common::operation_command::*;
+use crate::commands::token::{parse_contract, parse_address};
+use super::{parse_token_id, split_arg};
+
+/// Add or remove operators of the --from account's FA2 tokens.
+///
+/// Operator can transfer owner's tokens with the given id.
+///
+/// Outputs operation hash to stdout in case of success.
+#[derive(StructOpt)]
+pub struct UpdateOperators {
+    /// Disable interactivity and accept default answers to prompts.
+    #[structopt(short = "y", long = "no-prompt")]
+    pub no_prompt: bool,
+
+    /// Node's rpc endpoint.
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    #[structopt(flatten)]
+    pub signer: SignerOptions,
+
+    /// Account, which sends the operation.
+    ///
+    /// When using Trezor or Ledger, use key derivation path,
+    /// like: "m/44'/1729'/0'/0'"
+    ///
+    /// Otherwise use name or address of the account in the local
+    /// keystore (see `keys import`).
+    #[structopt(short, long)]
+    pub from: String,
+
+    /// Address of the FA2 token contract.
+    #[structopt(short, long)]
+    pub contract: String,
+
+    /// Operator to add, like: "<operator>:<token_id>". Can be repeated.
+    #[structopt(long, number_of_values = 1, required_unless = "remove")]
+    pub add: Vec<String>,
+
+    /// Operator to remove, like: "<operator>:<token_id>". Can be repeated.
+    #[structopt(long, number_of_values = 1)]
+    pub remove: Vec<String>,
+
+    /// Fee for the operation.
+    ///
+    /// If not specified, fee will be estimated and you will be prompted
+    /// whether or not you accept estimate or would like to enter custom one.
+    #[structopt(long)]
+    pub fee: Option<String>,
+}
+
+impl RawOperationCommand for UpdateOperators {
+    fn get_no_prompt(&self) -> bool {
+        self.no_prompt
+    }
+
+    fn get_signer_options(&self) -> &SignerOptions {
+        &self.signer
+    }
+
+    fn get_api_endpoint(&self) -> String {
+        self.endpoint.clone()
+    }
+
+    fn get_raw_key_path(&self) -> Option<&str> {
+        None
+    }
+
+    fn get_raw_from(&self) -> &str {
+        &self.from
+    }
+
+    fn get_raw_fee(&self) -> Option<&String> {
+        self.fee.as_ref()
+    }
+}
+
+impl UpdateOperators {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let contract = parse_contract(&self.contract)?;
+        let parse_operator = |arg: &String| -> Result<_, CommandError> {
+            let parts = split_arg(arg, "operator", "<operator>:<token_id>", &[2])?;
+            Ok((parse_address(parts[0])?, parse_token_id(parts[1])?))
+        };
+        let add = self.add.iter().map(parse_operator).collect::<Result<Vec<_>, _>>()?;
+        let remove = self.remove.iter().map(parse_operator).collect::<Result<Vec<_>, _>>()?;
+
+        let mut command = self.parse()?;
+        let owner = command.from.clone();
+
+        let updates = add.into_iter()
+            .map(|op| (true, op))
+            .chain(remove.into_iter().map(|op| (false, op)))
+            .map(|(is_add, (operator, token_id))| {
+                eprintln!(
+                    "{} operator {} for token id {}",
+                    if is_add { style("adding").green() } else { style("removing").red() },
+                    style(operator.to_base58check()).bold(),
+                    style(&token_id).bold(),
+                );
+                let operator = Fa2Operator { owner: owner.clone(), operator, token_id };
+                if is_add {
+                    Fa2OperatorUpdate::Add(operator)
+                } else {
+                    Fa2OperatorUpdate::Remove(operator)
+                }
+            })
+            .collect();
+        eprintln!();
+
+        command.call_contract(contract, Fa2Call::UpdateOperators(updates).into())
+    }
+}
@@ -0,0 +1,110 @@
+use structopt::StructOpt;
+use console::style;
+
+use lib::{ToBase58Check, Curve, KeyDerivationPath, ed25519_keys_from_mnemonic};
+use lib::crypto::mnemonic::generate_mnemonic;
+use lib::signer::{LocalSigner, generate_private_key};
+
+use crate::commands::CommandError;
+use crate::common::{Keystore, KeystoreAccount, KeystoreError};
+use super::protect_key;
+
+/// Generate new key pair.
+///
+/// If name is passed, key will be stored in the local keystore under
+/// that name. Otherwise secret key will be output along with the
+/// public key and the address.
+///
+/// Outputs generated mnemonic (if any), keys and address to stdout.
+#[derive(StructOpt, Debug, Clone)]
+pub struct GenerateKey {
+    /// Name of the account, which can later be passed to --from.
+    name: Option<String>,
+
+    /// Curve of the key: ed25519 (tz1), secp256k1 (tz2) or p256 (tz3).
+    #[structopt(long, default_value = "ed25519")]
+    curve: Curve,
+
+    /// Generate new BIP39 mnemonic and derive ed25519 key from it.
+    ///
+    /// Mnemonic can be used to recover the key, so it needs to be
+    /// written down and stored securely.
+    #[structopt(long)]
+    mnemonic: bool,
+
+    /// Key derivation path used with --mnemonic.
+    #[structopt(long, default_value = "m/44'/1729'/0'/0'")]
+    path: String,
+
+    /// Store secret key without encryption.
+    ///
+    /// WARNING: anyone with access to the keystore file will be able
+    ///          to use the key. Should only be used for testing purposes!
+    #[structopt(long)]
+    unencrypted: bool,
+
+    /// Replace existing account with the same name.
+    #[structopt(long)]
+    force: bool,
+}
+
+impl GenerateKey {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let (mnemonic, private_key) = if self.mnemonic {
+            if self.curve != Curve::Ed25519 {
+                Err(format!(
+                    "{} is only supported for the {} curve",
+                    style("--mnemonic").bold(),
+                    style(Curve::Ed25519).bold(),
+                ))?;
+            }
+            let path: KeyDerivationPath = self.path.parse()?;
+            let mnemonic = generate_mnemonic();
+            let (_, private_key) = ed25519_keys_from_mnemonic(&mnemonic, "", &path)?;
+            (Some(mnemonic), private_key)
+        } else {
+            (None, generate_private_key(self.curve))
+        };
+
+        let public_key = LocalSigner::from_private_key(private_key.clone())?
+            .public_key()
+            .clone();
+        let address = public_key.hash().to_base58check();
+
+        if let Some(name) = self.name.clone() {
+            let mut keystore = Keystore::open_default()?;
+            // check before asking for a password.
+            if !self.force && keystore.accounts().iter().any(|x| x.name == name) {
+                Err(KeystoreError::AlreadyExists(name.clone()))?;
+            }
+
+            keystore.add(KeystoreAccount {
+                name: name.clone(),
+                public_key: public_key.clone(),
+                secret_key: protect_key(&private_key, self.unencrypted)?,
+            }, self.force)?;
+            keystore.save()?;
+
+            eprintln!(
+                "{} key generated and stored. Use {} to create operations with it.",
+                style("[+]").green(),
+                style(format!("--from {}", name)).bold(),
+            );
+        } else {
+            eprintln!(
+                "{} secret key is not stored anywhere. Make sure to save it securely!",
+                style("[WARN]").yellow(),
+            );
+        }
+
+        if let Some(mnemonic) = mnemonic {
+            println!("mnemonic: {}", mnemonic);
+        }
+        if self.name.is_none() {
+            println!("secret key: {}", private_key.to_base58check());
+        }
+        println!("public key: {}", public_key.to_base58check());
+        println!("address: {}", address);
+        Ok(())
+    }
+}
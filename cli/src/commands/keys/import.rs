@@ -0,0 +1,138 @@
+use structopt::StructOpt;
+use console::style;
+use dialoguer::theme::ColorfulTheme;
+
+use lib::{
+    ToBase58Check, PrivateKey, KeyDerivationPath,
+    ed25519_keys_from_mnemonic, fundraiser_keys,
+};
+use lib::signer::LocalSigner;
+
+use crate::commands::CommandError;
+use crate::common::{password_input, Keystore, KeystoreAccount, StoredSecretKey};
+use super::protect_key;
+
+/// Import secret key into the local keystore under the given name.
+///
+/// Secret key (edsk, spsk, p2sk or their encrypted edesk, spesk, p2esk
+/// versions) will be asked for interactively, so that it doesn't end up
+/// in the shell history.
+///
+/// With --fundraiser, mnemonic, email and password received during the
+/// fundraiser will be asked for instead.
+///
+/// With --mnemonic, BIP39 mnemonic (and optional passphrase) will be asked
+/// for instead and ed25519 key will be derived from it for the --path.
+/// Same as on Ledger and Trezor, initialized with the same mnemonic.
+///
+/// Outputs address of the imported key to stdout.
+#[derive(StructOpt, Debug, Clone)]
+pub struct ImportKey {
+    /// Name of the account, which can later be passed to --from.
+    name: String,
+
+    /// Store secret key without encryption.
+    ///
+    /// WARNING: anyone with access to the keystore file will be able
+    ///          to use the key. Should only be used for testing purposes!
+    #[structopt(long)]
+    unencrypted: bool,
+
+    /// Derive key from the BIP39 mnemonic.
+    #[structopt(long)]
+    mnemonic: bool,
+
+    /// Derive key of the fundraiser account.
+    #[structopt(long, conflicts_with = "mnemonic")]
+    fundraiser: bool,
+
+    /// Key derivation path used with --mnemonic.
+    #[structopt(long, default_value = "m/44'/1729'/0'/0'")]
+    path: String,
+
+    /// Replace existing account with the same name.
+    #[structopt(long)]
+    force: bool,
+}
+
+impl ImportKey {
+    fn private_key_from_mnemonic(&self) -> Result<PrivateKey, CommandError> {
+        let path: KeyDerivationPath = self.path.parse()?;
+        let mnemonic = password_input("mnemonic", false)?;
+        let passphrase = dialoguer::Password::with_theme(&ColorfulTheme::default())
+            .with_prompt("passphrase (leave empty if none)")
+            .allow_empty_password(true)
+            .interact()?;
+
+        let (public_key, private_key) = ed25519_keys_from_mnemonic(&mnemonic, &passphrase, &path)?;
+        eprintln!(
+            "{} derived address: {}",
+            style("[+]").green(),
+            style(public_key.hash().to_base58check()).bold(),
+        );
+
+        Ok(private_key)
+    }
+
+    fn private_key_from_fundraiser(&self) -> Result<PrivateKey, CommandError> {
+        let mnemonic = password_input("mnemonic", false)?;
+        let email = dialoguer::Input::<String>::with_theme(&ColorfulTheme::default())
+            .with_prompt("email")
+            .interact_text()?;
+        let password = password_input("fundraiser password", false)?;
+
+        let (public_key, private_key) = fundraiser_keys(&mnemonic, email.trim(), &password)?;
+        eprintln!(
+            "{} derived address: {}",
+            style("[+]").green(),
+            style(public_key.hash().to_base58check()).bold(),
+        );
+
+        Ok(private_key)
+    }
+
+    pub fn execute(self) -> Result<(), CommandError> {
+        let mut keystore = Keystore::open_default()?;
+
+        let secret_key = if self.mnemonic {
+            StoredSecretKey::Unencrypted(self.private_key_from_mnemonic()?)
+        } else if self.fundraiser {
+            StoredSecretKey::Unencrypted(self.private_key_from_fundraiser()?)
+        } else {
+            StoredSecretKey::parse(&password_input("secret key", false)?)?
+        };
+
+        let (private_key, secret_key) = match secret_key {
+            StoredSecretKey::Encrypted(_) => {
+                let password = password_input("password of the secret key", false)?;
+                (secret_key.decrypt(&password)?, secret_key)
+            }
+            StoredSecretKey::Unencrypted(private_key) => {
+                let secret_key = protect_key(&private_key, self.unencrypted)?;
+                (private_key, secret_key)
+            }
+        };
+
+        let public_key = LocalSigner::from_private_key(private_key)?
+            .public_key()
+            .clone();
+
+        let account = KeystoreAccount {
+            name: self.name,
+            public_key,
+            secret_key,
+        };
+        let address = account.address().to_base58check();
+
+        keystore.add(account, self.force)?;
+        keystore.save()?;
+
+        eprintln!(
+            "{} key imported. Use {} to create operations with it.",
+            style("[+]").green(),
+            style(format!("--from {}", address)).bold(),
+        );
+        println!("{}", address);
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use structopt::StructOpt;
+use console::style;
+
+use lib::ToBase58Check;
+
+use crate::commands::CommandError;
+use crate::common::Keystore;
+
+/// List accounts in the local keystore.
+///
+/// Outputs name and address of each account to stdout.
+#[derive(StructOpt, Debug, Clone)]
+pub struct ListKeys {}
+
+impl ListKeys {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let keystore = Keystore::open_default()?;
+
+        if keystore.accounts().is_empty() {
+            eprintln!(
+                "keystore is empty. Use {} to add a key.",
+                style("keys import <name>").bold(),
+            );
+        }
+
+        for account in keystore.accounts() {
+            println!(
+                "{}: {}{}",
+                account.name,
+                account.address().to_base58check(),
+                if account.secret_key.is_encrypted() { "" } else { " (unencrypted)" },
+            );
+        }
+        Ok(())
+    }
+}
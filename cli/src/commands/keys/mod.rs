@@ -0,0 +1,54 @@
+use structopt::StructOpt;
+
+pub mod import;
+pub use import::ImportKey;
+
+pub mod generate;
+pub use generate::GenerateKey;
+
+pub mod list;
+pub use list::ListKeys;
+
+pub mod remove;
+pub use remove::RemoveKey;
+
+use lib::{PrivateKey, EncryptedPrivateKey};
+
+use crate::commands::CommandError;
+use crate::common::{password_input, StoredSecretKey};
+
+/// Encrypt key with a password, asked from the user.
+///
+/// If `unencrypted` is `true`, key will be stored as is.
+fn protect_key(
+    private_key: &PrivateKey,
+    unencrypted: bool,
+) -> Result<StoredSecretKey, std::io::Error>
+{
+    if unencrypted {
+        return Ok(StoredSecretKey::Unencrypted(private_key.clone()));
+    }
+
+    let password = password_input("new password to encrypt the key with", true)?;
+    Ok(StoredSecretKey::Encrypted(EncryptedPrivateKey::encrypt(private_key, &password)))
+}
+
+/// Manage keys in the local keystore.
+#[derive(StructOpt, Debug, Clone)]
+pub enum Keys {
+    Import(ImportKey),
+    Gen(GenerateKey),
+    List(ListKeys),
+    Remove(RemoveKey),
+}
+
+impl Keys {
+    pub fn execute(self) -> Result<(), CommandError> {
+        match self {
+            Keys::Import(c) => c.execute(),
+            Keys::Gen(c) => c.execute(),
+            Keys::List(c) => c.execute(),
+            Keys::Remove(c) => c.execute(),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use structopt::StructOpt;
+use console::style;
+
+use lib::ToBase58Check;
+
+use crate::commands::CommandError;
+use crate::common::Keystore;
+
+/// Remove account from the local keystore.
+#[derive(StructOpt, Debug, Clone)]
+pub struct RemoveKey {
+    /// Name of the account to remove.
+    name: String,
+}
+
+impl RemoveKey {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let mut keystore = Keystore::open_default()?;
+        let account = keystore.remove(&self.name)?;
+        keystore.save()?;
+
+        eprintln!(
+            "{} removed {} ({}) from the keystore.",
+            style("[-]").red(),
+            style(&account.name).bold(),
+            account.address().to_base58check(),
+        );
+        Ok(())
+    }
+}
@@ -1,24 +1,43 @@
 use structopt::StructOpt;
 
 pub mod address;
+pub mod keys;
 pub mod transfer;
 pub mod delegate;
 pub mod originate;
-
-// These two are temporary, before local store will be implemented.
-pub mod transfer_local;
-pub mod delegate_local;
+pub mod activate;
+pub mod vote;
+pub mod signer;
+pub mod prepare;
+pub mod sign;
+pub mod broadcast;
+pub mod sign_message;
+pub mod verify_signature;
+pub mod multisig;
+pub mod token;
+pub mod fa2;
+pub mod contract;
 
 pub type CommandError = Box<dyn std::error::Error>;
 
 #[derive(StructOpt)]
 pub enum Command {
     Address(address::Address),
+    Keys(keys::Keys),
     Transfer(transfer::Transfer),
     Delegate(delegate::Delegate),
-    UnsafeTransferLocal(transfer_local::TransferLocal),
-    UnsafeDelegateLocal(delegate_local::DelegateLocal),
-    #[structopt(setting(structopt::clap::AppSettings::Hidden))]
+    Activate(activate::Activate),
+    Vote(vote::Vote),
+    Signer(signer::Signer),
+    Prepare(prepare::Prepare),
+    Sign(sign::Sign),
+    Broadcast(broadcast::Broadcast),
+    SignMessage(sign_message::SignMessage),
+    VerifySignature(verify_signature::VerifySignature),
+    Multisig(multisig::Multisig),
+    Token(token::Token),
+    Fa2(fa2::Fa2),
+    Contract(contract::Contract),
     Originate(originate::Originate),
 }
 
@@ -27,10 +46,21 @@ impl Command {
     pub fn get_endpoint(&self) -> Option<&str> {
         match self {
             Self::Address(_) => None,
+            Self::Keys(_) => None,
             Self::Transfer(cmd) => Some(cmd.endpoint.as_str()),
             Self::Delegate(cmd) => Some(cmd.endpoint.as_str()),
-            Self::UnsafeTransferLocal(cmd) => Some(cmd.endpoint.as_str()),
-            Self::UnsafeDelegateLocal(cmd) => Some(cmd.endpoint.as_str()),
+            Self::Activate(cmd) => Some(cmd.endpoint.as_str()),
+            Self::Vote(cmd) => Some(cmd.endpoint.as_str()),
+            Self::Signer(_) => None,
+            Self::Prepare(cmd) => Some(cmd.endpoint.as_str()),
+            Self::Sign(_) => None,
+            Self::Broadcast(cmd) => Some(cmd.endpoint.as_str()),
+            Self::SignMessage(_) => None,
+            Self::VerifySignature(_) => None,
+            Self::Multisig(cmd) => cmd.get_endpoint(),
+            Self::Token(cmd) => cmd.get_endpoint(),
+            Self::Fa2(cmd) => cmd.get_endpoint(),
+            Self::Contract(cmd) => cmd.get_endpoint(),
             Self::Originate(cmd) => Some(cmd.endpoint.as_str()),
         }
     }
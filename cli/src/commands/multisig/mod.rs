@@ -0,0 +1,156 @@
+use std::fs;
+use std::path::Path;
+use structopt::StructOpt;
+use serde::{Serialize, Deserialize};
+use console::style;
+
+use lib::{
+    OriginatedAddress, Signature, ToBase58Check,
+    MultisigAction, MultisigPayload, MultisigStorage,
+};
+use lib::micheline::Micheline;
+use lib::crypto::hex;
+use lib::api::GetContractStorage;
+
+pub mod show;
+pub use show::Show;
+
+pub mod prepare;
+pub use prepare::Prepare;
+
+pub mod sign;
+pub use sign::Sign;
+
+pub mod submit;
+pub use submit::Submit;
+
+use crate::commands::CommandError;
+
+/// Identifies the file as a multisig action exported by `multisig prepare`.
+pub const MULTISIG_ACTION_FORMAT: &str = "tezedge-client/multisig-action/v1";
+
+/// Manage the generic multisig contract.
+///
+/// Action is prepared once, signed by the keys of the contract in
+/// separate sessions and then submitted with collected signatures.
+#[derive(StructOpt)]
+pub enum Multisig {
+    Show(Show),
+    Prepare(Prepare),
+    Sign(Sign),
+    Submit(Submit),
+}
+
+impl Multisig {
+    /// Get node endpoint.
+    pub fn get_endpoint(&self) -> Option<&str> {
+        match self {
+            Self::Show(cmd) => Some(cmd.endpoint.as_str()),
+            Self::Prepare(cmd) => Some(cmd.endpoint.as_str()),
+            Self::Sign(_) => None,
+            Self::Submit(cmd) => Some(cmd.endpoint.as_str()),
+        }
+    }
+
+    pub fn execute(self) -> Result<(), CommandError> {
+        match self {
+            Self::Show(c) => c.execute(),
+            Self::Prepare(c) => c.execute(),
+            Self::Sign(c) => c.execute(),
+            Self::Submit(c) => c.execute(),
+        }
+    }
+}
+
+fn parse_contract(contract: &str) -> Result<OriginatedAddress, CommandError> {
+    Ok(OriginatedAddress::from_base58check(contract)
+        .map_err(|_| format!("invalid multisig contract address: {}", style(contract).red()))?)
+}
+
+/// Fetch counter, threshold and keys of the multisig contract.
+fn fetch_storage<A>(api: &A, contract: &OriginatedAddress) -> Result<MultisigStorage, CommandError>
+    where A: GetContractStorage,
+{
+    let storage: Micheline = serde_json::from_value(api.get_contract_storage(contract)?)?;
+    Ok(MultisigStorage::from_micheline(&storage)?)
+}
+
+fn describe_action(action: &MultisigAction) -> String {
+    match action {
+        MultisigAction::Transfer { to, amount } => format!(
+            "transfer {} µꜩ to {}",
+            style(amount).green(),
+            style(to.to_base58check()).bold(),
+        ),
+        MultisigAction::Delegate { delegate: Some(delegate) } => format!(
+            "set delegate to {}",
+            style(delegate.to_base58check()).bold(),
+        ),
+        MultisigAction::Delegate { delegate: None } => "withdraw delegate".to_string(),
+        MultisigAction::ChangeKeys { threshold, keys } => format!(
+            "change keys to (threshold: {}):{}",
+            style(threshold).bold(),
+            keys.iter()
+                .map(|key| format!("\n  - {}", key.to_base58check()))
+                .collect::<String>(),
+        ),
+    }
+}
+
+/// Multisig action, prepared using `multisig prepare` and signed by
+/// each key using `multisig sign`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MultisigActionFile {
+    pub format: String,
+    pub chain_id: String,
+    pub contract: OriginatedAddress,
+    /// Counter of the contract, when the action was prepared.
+    pub counter: u64,
+    pub action: MultisigAction,
+    /// Hex encoded packed bytes to sign.
+    ///
+    /// Only there for signing with other tools, bytes are always
+    /// computed from the fields above.
+    pub bytes: String,
+    #[serde(default)]
+    pub signatures: Vec<Signature>,
+}
+
+impl MultisigActionFile {
+    pub fn new(payload: &MultisigPayload) -> Result<Self, CommandError> {
+        Ok(Self {
+            format: MULTISIG_ACTION_FORMAT.to_string(),
+            chain_id: payload.chain_id.clone(),
+            contract: payload.contract.clone(),
+            counter: payload.counter,
+            action: payload.action.clone(),
+            bytes: hex::encode(payload.bytes_to_sign()?),
+            signatures: vec![],
+        })
+    }
+
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, CommandError> {
+        let file: Self = serde_json::from_str(&fs::read_to_string(path)?)?;
+
+        if file.format != MULTISIG_ACTION_FORMAT {
+            return Err(format!("unsupported multisig action file format: {}", file.format).into());
+        }
+        if file.bytes != hex::encode(file.payload().bytes_to_sign()?) {
+            return Err("bytes in the multisig action file don't match the action.".into());
+        }
+        Ok(file)
+    }
+
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<(), CommandError> {
+        Ok(fs::write(path, serde_json::to_string_pretty(self)? + "\n")?)
+    }
+
+    pub fn payload(&self) -> MultisigPayload {
+        MultisigPayload {
+            chain_id: self.chain_id.clone(),
+            contract: self.contract.clone(),
+            counter: self.counter,
+            action: self.action.clone(),
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::path::PathBuf;
+use structopt::StructOpt;
+use console::style;
+
+use lib::{Address, ImplicitAddress, PublicKey, MultisigAction, MultisigPayload};
+use lib::api::GetChainID;
+use lib::http_api::HttpApi;
+
+use crate::commands::CommandError;
+use crate::commands::transfer::parse_amount;
+use crate::common::operation_command::{ParseAddressError, AddressKind};
+use super::{parse_contract, fetch_storage, describe_action, MultisigActionFile};
+
+/// Prepare an action of the generic multisig contract for signing.
+///
+/// Reads current counter of the contract and writes the action, along
+/// with the bytes to sign, to the file. Each key can then sign it using
+/// `multisig sign` command.
+///
+/// Outputs hex encoded bytes to sign to stdout.
+#[derive(StructOpt)]
+pub struct Prepare {
+    /// Node's rpc endpoint.
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    /// Address of the multisig contract.
+    #[structopt(short, long)]
+    pub contract: String,
+
+    /// File to write prepared action to.
+    #[structopt(short, long)]
+    pub output: PathBuf,
+
+    #[structopt(subcommand)]
+    pub action: PrepareAction,
+}
+
+#[derive(StructOpt)]
+pub enum PrepareAction {
+    /// Transfer funds from the multisig contract.
+    Transfer {
+        /// Address to transfer funds to.
+        #[structopt(short, long)]
+        to: String,
+
+        /// Amount to transfer.
+        #[structopt(short, long)]
+        amount: String,
+    },
+    /// Set or withdraw delegate of the multisig contract.
+    Delegate {
+        /// Address to delegate funds to.
+        #[structopt(short, long, required_unless = "cancel")]
+        to: Option<String>,
+
+        /// Withdraw active delegation.
+        #[structopt(long, conflicts_with = "to")]
+        cancel: bool,
+    },
+    /// Replace keys and threshold of the multisig contract.
+    ChangeKeys {
+        /// Number of signatures required to execute an action.
+        #[structopt(long)]
+        threshold: u64,
+
+        /// Public key controlling the contract.
+        ///
+        /// Should be repeated for each key.
+        #[structopt(short, long = "key", required = true, number_of_values = 1)]
+        keys: Vec<String>,
+    },
+}
+
+impl PrepareAction {
+    fn parse(&self) -> Result<MultisigAction, CommandError> {
+        Ok(match self {
+            Self::Transfer { to, amount } => MultisigAction::Transfer {
+                to: Address::from_base58check(to)
+                    .map_err(|err| ParseAddressError {
+                        kind: AddressKind::Destination,
+                        error: err,
+                        address: to.clone(),
+                    })?,
+                amount: parse_amount(amount),
+            },
+            Self::Delegate { to, cancel } => MultisigAction::Delegate {
+                delegate: to.as_ref()
+                    .filter(|_| !cancel)
+                    .map(|to| {
+                        ImplicitAddress::from_base58check(to)
+                            .map_err(|err| ParseAddressError {
+                                kind: AddressKind::Destination,
+                                error: err,
+                                address: to.clone(),
+                            })
+                    })
+                    .transpose()?,
+            },
+            Self::ChangeKeys { threshold, keys } => {
+                if *threshold == 0 || *threshold as usize > keys.len() {
+                    return Err(format!(
+                        "{} must be between 1 and the number of keys ({})",
+                        style("--threshold").bold(),
+                        keys.len(),
+                    ).into());
+                }
+                MultisigAction::ChangeKeys {
+                    threshold: *threshold,
+                    keys: keys.iter()
+                        .map(|key| {
+                            PublicKey::from_base58check(key)
+                                .map_err(|_| format!("invalid public key: {}", style(key).red()))
+                        })
+                        .collect::<Result<_, _>>()?,
+                }
+            }
+        })
+    }
+}
+
+impl Prepare {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let contract = parse_contract(&self.contract)?;
+        let action = self.action.parse()?;
+
+        let api = HttpApi::new(self.endpoint.clone());
+        let storage = fetch_storage(&api, &contract)?;
+
+        let payload = MultisigPayload {
+            chain_id: api.get_chain_id()?,
+            contract,
+            counter: storage.counter,
+            action,
+        };
+        let file = MultisigActionFile::new(&payload)?;
+        file.write(&self.output)?;
+
+        eprintln!("{} {}", style("action:").bold(), describe_action(&file.action));
+        eprintln!(
+            "{} {} of {} keys",
+            style("required signatures:").bold(),
+            style(storage.threshold).bold(),
+            storage.keys.len(),
+        );
+        eprintln!("{} {}", style("bytes to sign:").bold(), file.bytes);
+        eprintln!(
+            "\naction prepared and written to: {}",
+            style(self.output.display()).bold(),
+        );
+        eprintln!(
+            "sign it with each key using {} command.",
+            style("multisig sign").bold(),
+        );
+
+        if !console::user_attended() {
+            println!("{}", file.bytes);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use structopt::StructOpt;
+
+use lib::ToBase58Check;
+use lib::http_api::HttpApi;
+
+use crate::commands::CommandError;
+use super::{parse_contract, fetch_storage};
+
+/// Show counter, threshold and keys of the multisig contract.
+///
+/// Outputs them to stdout.
+#[derive(StructOpt)]
+pub struct Show {
+    /// Node's rpc endpoint.
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    /// Address of the multisig contract.
+    pub contract: String,
+}
+
+impl Show {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let api = HttpApi::new(self.endpoint.clone());
+        let storage = fetch_storage(&api, &parse_contract(&self.contract)?)?;
+
+        println!("counter: {}", storage.counter);
+        println!("threshold: {}", storage.threshold);
+        println!("keys:");
+        for key in storage.keys.iter() {
+            println!("  - {}", key.to_base58check());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use std::path::PathBuf;
+use structopt::StructOpt;
+use console::style;
+
+use lib::ToBase58Check;
+use lib::crypto::hex;
+use lib::signer::verify_signature;
+
+use crate::commands::CommandError;
+use crate::common::exit_with_error;
+use crate::common::operation_command::{parse_signer, SignerOptions};
+use super::{describe_action, MultisigActionFile};
+
+/// Sign an action prepared using `multisig prepare` command.
+///
+/// Signature is added to the action file, so that it can be passed to
+/// the next signer, or submitted once enough signatures are collected.
+///
+/// Doesn't need network access. Trezor firmware can only sign
+/// operations, so `--trezor` is rejected. Trezor can still be used to
+/// submit collected signatures (see `multisig submit`).
+#[derive(StructOpt)]
+pub struct Sign {
+    /// Disable interactivity and accept default answers to prompts.
+    #[structopt(short = "y", long = "no-prompt")]
+    pub no_prompt: bool,
+
+    #[structopt(flatten)]
+    pub signer: SignerOptions,
+
+    /// Key to sign the action with.
+    ///
+    /// When using Ledger, use key derivation path,
+    /// like: "m/44'/1729'/0'/0'"
+    ///
+    /// Otherwise use name or address of the account in the local
+    /// keystore (see `keys import`).
+    #[structopt(short, long)]
+    pub key: String,
+
+    /// File with the action prepared using `multisig prepare` command.
+    #[structopt(parse(from_os_str))]
+    pub input: PathBuf,
+
+    /// File to write the action with added signature to.
+    ///
+    /// If not specified, input file is updated.
+    #[structopt(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+impl Sign {
+    pub fn execute(self) -> Result<(), CommandError> {
+        self.signer.reject_trezor("Trezor firmware can only sign operations, not multisig actions");
+        let mut file = MultisigActionFile::read(&self.input)?;
+        let bytes = file.payload().bytes_to_sign()?;
+
+        eprintln!("{} {}", style("contract:").bold(), file.contract.to_base58check());
+        eprintln!("{} {}", style("counter:").bold(), file.counter);
+        eprintln!("{} {}", style("action:").bold(), describe_action(&file.action));
+        eprintln!("{} {}\n", style("bytes to sign:").bold(), hex::encode(&bytes));
+
+        if !self.no_prompt {
+            let confirmed = dialoguer::Confirm::new()
+                .with_prompt("Sign this action?")
+                .default(false)
+                .interact()?;
+            if !confirmed {
+                exit_with_error("signing cancelled");
+            }
+        }
+
+        let options = self.signer.to_raw_options(self.no_prompt);
+        let (mut signer, _) = parse_signer(&options, &self.key, None)?;
+        let public_key = signer.public_key()?;
+
+        if self.signer.use_ledger {
+            eprintln!("please confirm signing on your Ledger.");
+        }
+        let signature = signer.sign_bytes(&bytes)?;
+        if !verify_signature(&public_key, &bytes, &signature) {
+            exit_with_error("signer returned invalid signature");
+        }
+
+        // replace previous signature of the same key, if signing again.
+        file.signatures.retain(|sig| !verify_signature(&public_key, &bytes, sig));
+        file.signatures.push(signature);
+
+        let output = self.output.as_ref().unwrap_or(&self.input);
+        file.write(output)?;
+
+        eprintln!(
+            "action signed by {} and written to: {}",
+            style(public_key.to_base58check()).bold(),
+            style(output.display()).bold(),
+        );
+        eprintln!(
+            "collected signatures: {}. Once there are enough, submit them using {} command.",
+            style(file.signatures.len()).green(),
+            style("multisig submit").bold(),
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use std::path::PathBuf;
+use structopt::StructOpt;
+use console::style;
+
+use lib::api::GetChainID;
+use lib::http_api::HttpApi;
+use lib::signer::verify_signature;
+
+use crate::commands::CommandError;
+use crate::common::exit_with_error;
+use crate::common::operation_command::*;
+use super::{fetch_storage, describe_action, MultisigActionFile};
+
+/// Submit an action signed using `multisig sign` command to the
+/// multisig contract.
+///
+/// Calls `main` entrypoint of the contract with the collected signatures.
+/// Operation can be sent from any account, not only the one of the keys.
+///
+/// Outputs operation hash to stdout in case of success.
+#[derive(StructOpt)]
+pub struct Submit {
+    /// Disable interactivity and accept default answers to prompts.
+    #[structopt(short = "y", long = "no-prompt")]
+    pub no_prompt: bool,
+
+    /// Node's rpc endpoint.
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    #[structopt(flatten)]
+    pub signer: SignerOptions,
+
+    /// Account, which sends the operation and pays the fees.
+    ///
+    /// When using Trezor or Ledger, use key derivation path,
+    /// like: "m/44'/1729'/0'/0'"
+    ///
+    /// Otherwise use name or address of the account in the local
+    /// keystore (see `keys import`).
+    #[structopt(short, long)]
+    pub from: String,
+
+    /// Fee for the operation.
+    ///
+    /// If not specified, fee will be estimated and you will be prompted
+    /// whether or not you accept estimate or would like to enter custom one.
+    #[structopt(long)]
+    pub fee: Option<String>,
+
+    /// File with the action signed using `multisig sign` command.
+    #[structopt(parse(from_os_str))]
+    pub input: PathBuf,
+}
+
+impl RawOperationCommand for Submit {
+    fn get_no_prompt(&self) -> bool {
+        self.no_prompt
+    }
+
+    fn get_signer_options(&self) -> &SignerOptions {
+        &self.signer
+    }
+
+    fn get_api_endpoint(&self) -> String {
+        self.endpoint.clone()
+    }
+
+    fn get_raw_key_path(&self) -> Option<&str> {
+        None
+    }
+
+    fn get_raw_from(&self) -> &str {
+        &self.from
+    }
+
+    fn get_raw_fee(&self) -> Option<&String> {
+        self.fee.as_ref()
+    }
+}
+
+impl Submit {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let file = MultisigActionFile::read(&self.input)?;
+        let payload = file.payload();
+        let bytes = payload.bytes_to_sign()?;
+
+        let api = HttpApi::new(self.endpoint.clone());
+        let chain_id = api.get_chain_id()?;
+        if chain_id != file.chain_id {
+            exit_with_error(format!(
+                "action was prepared for chain {}, but node is on {}.",
+                style(&file.chain_id).bold(),
+                style(&chain_id).red(),
+            ));
+        }
+
+        let storage = fetch_storage(&api, &file.contract)?;
+        if storage.counter != file.counter {
+            exit_with_error(format!(
+                "contract's counter is {}, but action was prepared for {}. Either it was already executed, or another action was. Please prepare it again.",
+                style(storage.counter).red(),
+                style(file.counter).bold(),
+            ));
+        }
+
+        // contract expects signatures in the same order as its keys.
+        let signatures = storage.keys.iter()
+            .map(|key| {
+                file.signatures.iter()
+                    .find(|sig| verify_signature(key, &bytes, sig))
+                    .cloned()
+            })
+            .collect::<Vec<_>>();
+        let signed = signatures.iter().filter(|sig| sig.is_some()).count() as u64;
+
+        if signed < storage.threshold {
+            exit_with_error(format!(
+                "action is signed by {} of the contract's keys, but {} signatures are required.",
+                style(signed).red(),
+                style(storage.threshold).bold(),
+            ));
+        }
+
+        eprintln!("{} {}", style("action:").bold(), describe_action(&file.action));
+        eprintln!(
+            "{} {} of {} keys\n",
+            style("signed by:").bold(),
+            style(signed).green(),
+            storage.keys.len(),
+        );
+
+        let parameters = payload.main_parameters(&signatures);
+        self.parse()?.call_contract(file.contract, parameters)
+    }
+}
@@ -0,0 +1,191 @@
+use std::path::PathBuf;
+use structopt::StructOpt;
+use console::style;
+
+use lib::{Address, ImplicitAddress, PublicKey};
+use lib::signer::{Signer, SignerError, OperationSignatureInfo};
+use lib::http_api::HttpApi;
+use lib::utils::parse_float_amount;
+
+use crate::commands::CommandError;
+use crate::commands::transfer::parse_transfers;
+use crate::common::{Keystore, OfflineOperation, OfflineOperationFileFormat};
+use crate::common::operation_command::*;
+
+/// Prepare an operation for signing on the offline (air-gapped) machine.
+///
+/// Fetches counter and branch from the node, estimates fees and writes
+/// unsigned operation to the file. Sign it using `sign` command on the
+/// offline machine and inject it using `broadcast` command.
+#[derive(StructOpt)]
+pub struct Prepare {
+    /// Disable interactivity and accept default answers to prompts.
+    #[structopt(short = "y", long = "no-prompt")]
+    pub no_prompt: bool,
+
+    /// Node's rpc endpoint.
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    /// Address of the account, which will sign the operation.
+    ///
+    /// When transfering/delegating from scriptless smart contract
+    /// (KT1) address, this needs to be that KT1 address.
+    ///
+    /// Name of the account in the local keystore can be used too.
+    #[structopt(short, long)]
+    pub from: String,
+
+    /// Public key of the --from account.
+    ///
+    /// Only needed if account isn't revealed yet.
+    #[structopt(long = "public-key")]
+    pub public_key: Option<String>,
+
+    /// Fee for the operation.
+    ///
+    /// If not specified, fee will be estimated and you will be prompted
+    /// whether or not you accept estimate or would like to enter custom one.
+    #[structopt(long)]
+    pub fee: Option<String>,
+
+    /// File to write prepared operation to.
+    #[structopt(short, long)]
+    pub output: PathBuf,
+
+    /// Format of the output file: "json" or "hex".
+    #[structopt(long, default_value = "json", possible_values = &["json", "hex"])]
+    pub format: OfflineOperationFileFormat,
+
+    #[structopt(subcommand)]
+    pub operation: PrepareOperation,
+}
+
+#[derive(StructOpt)]
+pub enum PrepareOperation {
+    /// Prepare a transaction.
+    Transfer {
+        /// Address to transfer funds to.
+        ///
+        /// Can be repeated along with --amount to prepare a batch.
+        #[structopt(short, long, required = true, number_of_values = 1)]
+        to: Vec<String>,
+
+        /// Amount to transfer.
+        ///
+        /// Should be specified once for each --to address, in the same order.
+        #[structopt(short, long, required = true, number_of_values = 1)]
+        amount: Vec<String>,
+    },
+    /// Prepare a delegation.
+    Delegate {
+        /// Address to delegate funds to.
+        #[structopt(short, long, required_unless = "cancel")]
+        to: Option<String>,
+
+        /// Cancel active delegation.
+        #[structopt(long, conflicts_with = "to")]
+        cancel: bool,
+    },
+}
+
+/// Signer, which only knows the public key. Used to build reveal
+/// operation without having access to the private key.
+struct PublicKeyOnly(Option<PublicKey>);
+
+impl Signer for PublicKeyOnly {
+    fn name(&self) -> String {
+        "public key".to_string()
+    }
+
+    fn public_key(&mut self) -> Result<PublicKey, SignerError> {
+        self.0.clone().ok_or_else(|| SignerError::other(format!(
+            "account isn't revealed yet, so {} is required to prepare the reveal operation",
+            style("--public-key").bold(),
+        )))
+    }
+
+    fn sign_forged_operation(
+        &mut self,
+        _: &[u8],
+    ) -> Result<OperationSignatureInfo, SignerError>
+    {
+        Err(SignerError::other("operation can't be signed while preparing it"))
+    }
+}
+
+impl Prepare {
+    /// Source address and it's public key, if known.
+    fn get_from(&self) -> Result<(Address, Option<PublicKey>), CommandError> {
+        let public_key = match self.public_key.as_ref() {
+            Some(key) => Some(PublicKey::from_base58check(key)?),
+            None => None,
+        };
+
+        // account in the keystore only needs public key to be known.
+        if let Some(account) = Keystore::open_default()?.find(&self.from) {
+            return Ok((
+                account.public_key.hash().into(),
+                public_key.or_else(|| Some(account.public_key.clone())),
+            ));
+        }
+
+        let from = Address::from_base58check(&self.from)
+            .map_err(|err| ParseAddressError {
+                kind: AddressKind::Source,
+                error: err,
+                address: self.from.clone(),
+            })?;
+        Ok((from, public_key))
+    }
+
+    pub fn execute(self) -> Result<(), CommandError> {
+        let (from, public_key) = self.get_from()?;
+        let fee = match self.fee.as_ref() {
+            Some(fee) => Some(parse_float_amount(fee)
+                .map_err(|_| InvalidFeeError(fee.to_string()))?),
+            None => None,
+        };
+
+        let mut command = OperationCommand {
+            options: OperationOptions { no_prompt: self.no_prompt },
+            from,
+            fee,
+            api: Box::new(HttpApi::new(self.endpoint.clone())),
+            state: OperationCommandState::default(),
+            signer: Box::new(PublicKeyOnly(public_key)),
+        };
+
+        let operation_group = match &self.operation {
+            PrepareOperation::Transfer { to, amount } => {
+                command.prepare_batch_transfer(parse_transfers(to, amount)?)?
+            }
+            PrepareOperation::Delegate { to, cancel } => {
+                let to = to.as_ref()
+                    .filter(|_| !cancel)
+                    .map(|to| {
+                        ImplicitAddress::from_base58check(to)
+                            .map_err(|err| ParseAddressError {
+                                kind: AddressKind::Destination,
+                                error: err,
+                                address: to.clone(),
+                            })
+                    })
+                    .transpose()?;
+                command.prepare_delegation(to)?
+            }
+        };
+
+        OfflineOperation::new(&operation_group).write(&self.output, self.format)?;
+
+        eprintln!(
+            "\noperation prepared and written to: {}",
+            style(self.output.display()).bold(),
+        );
+        eprintln!(
+            "sign it on the offline machine using {} command.",
+            style("sign").bold(),
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use std::path::PathBuf;
+use structopt::StructOpt;
+use console::style;
+
+use lib::NewOperationWithKind;
+use lib::crypto::ToBase58Check;
+
+use crate::commands::CommandError;
+use crate::common::{exit_with_error, OfflineOperation, OfflineOperationFileFormat};
+use crate::common::operation_command::{parse_signer, verify_signed_operation, SignerOptions};
+
+/// Sign an operation prepared using `prepare` command.
+///
+/// Doesn't need network access, so it can be used on the offline
+/// (air-gapped) machine. Signed operation can be injected using
+/// `broadcast` command.
+#[derive(StructOpt)]
+pub struct Sign {
+    /// Disable interactivity and accept default answers to prompts.
+    #[structopt(short = "y", long = "no-prompt")]
+    pub no_prompt: bool,
+
+    #[structopt(flatten)]
+    pub signer: SignerOptions,
+
+    /// Key to sign the operation with.
+    ///
+    /// When using Trezor or Ledger, use key derivation path,
+    /// like: "m/44'/1729'/0'/0'"
+    ///
+    /// Otherwise use name or address of the account in the local
+    /// keystore (see `keys import`).
+    #[structopt(short, long)]
+    pub key: String,
+
+    /// File with the operation prepared using `prepare` command.
+    #[structopt(parse(from_os_str))]
+    pub input: PathBuf,
+
+    /// File to write signed operation to.
+    #[structopt(short, long)]
+    pub output: PathBuf,
+
+    /// Format of the output file: "json" or "hex".
+    #[structopt(long, default_value = "json", possible_values = &["json", "hex"])]
+    pub format: OfflineOperationFileFormat,
+}
+
+impl Sign {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let operation = OfflineOperation::read(&self.input, false)?;
+        let operation_group = operation.operation_group()?;
+
+        eprintln!("{}", style("operation to sign:").bold());
+        for op in operation_group.operations.iter().cloned() {
+            eprintln!(
+                "{}",
+                serde_json::to_string_pretty(&NewOperationWithKind::from(op))?,
+            );
+        }
+        eprintln!(
+            "\n{} {} µꜩ\n",
+            style("total fee:").bold(),
+            style(operation_group.total_fee()).green(),
+        );
+
+        if !self.no_prompt {
+            let confirmed = dialoguer::Confirm::new()
+                .with_prompt("Sign this operation?")
+                .default(false)
+                .interact()?;
+            if !confirmed {
+                exit_with_error("signing cancelled");
+            }
+        }
+
+        let options = self.signer.to_raw_options(self.no_prompt);
+        let (mut signer, _) = parse_signer(&options, &self.key, None)?;
+
+        // make sure the key is the one, which is expected to sign.
+        let public_key = signer.public_key()?;
+        let public_key_hash = public_key.hash();
+        let source = operation_group.operations.iter()
+            .find_map(|op| op.get_source());
+        if let Some(source) = source.filter(|source| **source != public_key_hash) {
+            exit_with_error(format!(
+                "operation needs to be signed by {}, but the key is for {}",
+                style(source.to_base58check()).bold(),
+                style(public_key_hash.to_base58check()).red(),
+            ));
+        }
+
+        if let Some(message) = signer.confirmation_message() {
+            eprintln!("{}", message);
+        }
+        let sig_info = signer.sign_forged_operation(&operation.forged_bytes()?)?;
+        verify_signed_operation(&operation_group, &public_key, &sig_info)?;
+
+        operation.with_signature(&sig_info).write(&self.output, self.format)?;
+
+        eprintln!(
+            "operation signed and written to: {}",
+            style(self.output.display()).bold(),
+        );
+        eprintln!(
+            "inject it from the online machine using {} command.",
+            style("broadcast").bold(),
+        );
+        Ok(())
+    }
+}
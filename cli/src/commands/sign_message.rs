@@ -0,0 +1,110 @@
+use std::str::FromStr;
+use structopt::StructOpt;
+use console::style;
+
+use lib::crypto::{hex, ToBase58Check};
+use lib::signer::pack_message;
+
+use crate::commands::CommandError;
+use crate::common::operation_command::{parse_signer, SignerOptions};
+
+/// Format of the message to sign or verify.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum MessageFormat {
+    /// Message packed as Micheline string (`0x05` prefixed), compatible
+    /// with Taquito and Beacon.
+    Micheline,
+    /// Message bytes as they are.
+    Raw,
+    /// Hex encoded bytes, signed as they are.
+    Hex,
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "micheline" => Ok(Self::Micheline),
+            "raw" => Ok(Self::Raw),
+            "hex" => Ok(Self::Hex),
+            _ => Err(format!("unknown format: {}. Expected \"micheline\", \"raw\" or \"hex\"", s)),
+        }
+    }
+}
+
+impl MessageFormat {
+    /// Bytes, which are signed for the `message`.
+    pub fn message_bytes(&self, message: &str) -> Result<Vec<u8>, CommandError> {
+        Ok(match self {
+            Self::Micheline => pack_message(message),
+            Self::Raw => message.as_bytes().to_vec(),
+            Self::Hex => hex::decode(message.trim_start_matches("0x"))
+                .map_err(|_| format!("invalid hex message: {}", style(message).red()))?,
+        })
+    }
+}
+
+/// Sign an arbitrary (off-chain) message.
+///
+/// Outputs signature to stdout.
+///
+/// Trezor firmware can only sign operations, so `--trezor` is rejected.
+#[derive(StructOpt)]
+pub struct SignMessage {
+    /// Disable interactivity and accept default answers to prompts.
+    #[structopt(short = "y", long = "no-prompt")]
+    pub no_prompt: bool,
+
+    #[structopt(flatten)]
+    pub signer: SignerOptions,
+
+    /// Key to sign the message with.
+    ///
+    /// When using Ledger, use key derivation path,
+    /// like: "m/44'/1729'/0'/0'"
+    ///
+    /// Otherwise use name or address of the account in the local
+    /// keystore (see `keys import`).
+    #[structopt(short, long)]
+    pub key: String,
+
+    /// Format of the message:
+    ///
+    /// - micheline: pack message as Micheline string (`0x05` prefix),
+    ///   same as Taquito and Beacon do.
+    ///
+    /// - raw: sign message bytes as they are.
+    ///
+    /// - hex: message is hex encoded bytes, signed as they are.
+    #[structopt(long, default_value = "micheline", possible_values = &["micheline", "raw", "hex"])]
+    pub format: MessageFormat,
+
+    /// Message to sign.
+    pub message: String,
+}
+
+impl SignMessage {
+    pub fn execute(self) -> Result<(), CommandError> {
+        self.signer.reject_trezor("Trezor firmware can only sign operations, not messages");
+        let bytes = self.format.message_bytes(&self.message)?;
+
+        let options = self.signer.to_raw_options(self.no_prompt);
+        let (mut signer, _) = parse_signer(&options, &self.key, None)?;
+        let public_key = signer.public_key()?;
+
+        eprintln!("{} {}", style("signing bytes:").bold(), hex::encode(&bytes));
+        if self.signer.use_ledger {
+            eprintln!("please confirm signing on your Ledger.");
+        }
+        let signature = signer.sign_bytes(&bytes)?;
+
+        eprintln!("{} {}", style("public key:").bold(), public_key.to_base58check());
+        eprintln!("{} {}", style("signature:").bold(), style(signature.to_base58check()).green());
+
+        if !console::user_attended() {
+            println!("{}", signature.to_base58check());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use structopt::StructOpt;
+
+pub mod serve;
+pub use serve::Serve;
+
+use crate::commands::CommandError;
+
+/// Remote signer, using Octez signer HTTP protocol.
+#[derive(StructOpt)]
+pub enum Signer {
+    Serve(Serve),
+}
+
+impl Signer {
+    pub fn execute(self) -> Result<(), CommandError> {
+        match self {
+            Signer::Serve(c) => c.execute(),
+        }
+    }
+}
@@ -0,0 +1,367 @@
+use std::fs;
+use std::path::PathBuf;
+use structopt::StructOpt;
+use console::style;
+use serde_json::json;
+
+use lib::{ImplicitAddress, PublicKey, Signature};
+use lib::crypto::{hex, ToBase58Check};
+use lib::signer::{Signer, SignerError, authentication_bytes, verify_signature};
+
+use crate::commands::CommandError;
+use crate::common::exit_with_error;
+use crate::common::operation_command::{parse_signer, SignerOptions};
+
+fn parse_magic_byte(s: &str) -> Result<u8, std::num::ParseIntError> {
+    u8::from_str_radix(s.trim().trim_start_matches("0x"), 16)
+}
+
+/// Run remote signer, implementing Octez signer HTTP protocol.
+///
+/// Requests are signed using Trezor, Ledger or a key from the
+/// local keystore. Each request is logged to stderr.
+#[derive(StructOpt)]
+pub struct Serve {
+    /// Disable interactivity and accept default answers to prompts.
+    #[structopt(short = "y", long = "no-prompt")]
+    pub no_prompt: bool,
+
+    /// Address to listen on.
+    #[structopt(long, default_value = "127.0.0.1:6732")]
+    pub address: String,
+
+    #[structopt(flatten)]
+    pub signer: SignerOptions,
+
+    /// Key to sign requests with.
+    ///
+    /// When using Trezor or Ledger, use key derivation path,
+    /// like: "m/44'/1729'/0'/0'"
+    ///
+    /// Otherwise use name or address of the account in the local
+    /// keystore (see `keys import`).
+    #[structopt(short, long)]
+    pub key: String,
+
+    /// File with public keys, one per line, which are allowed to send
+    /// signing requests.
+    ///
+    /// If not set, signing requests aren't authenticated.
+    #[structopt(long = "authorized-keys")]
+    pub authorized_keys: Option<PathBuf>,
+
+    /// Comma separated list of allowed magic bytes, like: "0x03".
+    ///
+    /// If not set, requests with any magic byte are signed.
+    #[structopt(
+        long = "magic-bytes",
+        use_delimiter = true,
+        parse(try_from_str = parse_magic_byte),
+    )]
+    pub magic_bytes: Vec<u8>,
+}
+
+#[derive(thiserror::Error, Debug)]
+enum RequestError {
+    #[error("not found")]
+    NotFound,
+    #[error("invalid request: {0}")]
+    BadRequest(String),
+    #[error("request isn't authenticated by any of the authorized keys")]
+    Unauthorized,
+    #[error("magic byte {0:#04x} isn't allowed")]
+    MagicByteNotAllowed(u8),
+    #[error("signing failed: {0}")]
+    Signing(#[from] SignerError),
+}
+
+impl RequestError {
+    fn status_code(&self) -> u16 {
+        match self {
+            Self::NotFound => 404,
+            Self::BadRequest(_) => 400,
+            Self::Unauthorized => 401,
+            Self::MagicByteNotAllowed(_) => 403,
+            Self::Signing(_) => 500,
+        }
+    }
+}
+
+/// Handles requests of the Octez signer HTTP protocol.
+struct SignerService {
+    key: Box<dyn Signer>,
+    public_key: PublicKey,
+    public_key_hash: ImplicitAddress,
+    /// If `Some`, signing requests must be signed by one of the keys.
+    authorized_keys: Option<Vec<PublicKey>>,
+    /// If not empty, only data with these magic bytes is signed.
+    magic_bytes: Vec<u8>,
+}
+
+impl SignerService {
+    fn new(
+        mut key: Box<dyn Signer>,
+        authorized_keys: Option<Vec<PublicKey>>,
+        magic_bytes: Vec<u8>,
+    ) -> Result<Self, CommandError>
+    {
+        let public_key = key.public_key()?;
+
+        Ok(Self {
+            key,
+            public_key_hash: public_key.hash(),
+            public_key,
+            authorized_keys,
+            magic_bytes,
+        })
+    }
+
+    fn is_authenticated(&self, data: &[u8], authentication: Option<&str>) -> bool {
+        let authorized_keys = match self.authorized_keys.as_ref() {
+            Some(keys) => keys,
+            None => return true,
+        };
+        let signature = match authentication.map(Signature::from_base58check) {
+            Some(Ok(signature)) => signature,
+            _ => return false,
+        };
+        let bytes = authentication_bytes(&self.public_key_hash, data);
+
+        authorized_keys.iter()
+            .any(|key| verify_signature(key, &bytes, &signature))
+    }
+
+    fn sign(
+        &mut self,
+        body: &str,
+        authentication: Option<&str>,
+    ) -> Result<serde_json::Value, RequestError>
+    {
+        let data = serde_json::from_str::<String>(body).ok()
+            .and_then(|data| hex::decode(data).ok())
+            .ok_or_else(|| RequestError::BadRequest("expected hex encoded json string".to_string()))?;
+
+        let magic_byte = *data.first()
+            .ok_or_else(|| RequestError::BadRequest("empty data".to_string()))?;
+        if !self.magic_bytes.is_empty() && !self.magic_bytes.contains(&magic_byte) {
+            return Err(RequestError::MagicByteNotAllowed(magic_byte));
+        }
+
+        if !self.is_authenticated(&data, authentication) {
+            return Err(RequestError::Unauthorized);
+        }
+
+        let signature = self.key.sign_bytes(&data)?;
+        Ok(json!({ "signature": signature.to_base58check() }))
+    }
+
+    fn handle(
+        &mut self,
+        method: &str,
+        url: &str,
+        body: &str,
+    ) -> Result<serde_json::Value, RequestError>
+    {
+        let (path, query) = match url.find('?') {
+            Some(index) => (&url[..index], &url[(index + 1)..]),
+            None => (url, ""),
+        };
+        let authentication = query.split('&')
+            .find_map(|param| param.strip_prefix("authentication="));
+        let key_path = format!("/keys/{}", self.public_key_hash.to_base58check());
+
+        match (method, path) {
+            ("GET", "/authorized_keys") => Ok(match self.authorized_keys.as_ref() {
+                Some(keys) => json!({
+                    "authorized_keys": keys.iter()
+                        .map(|key| key.hash().to_base58check())
+                        .collect::<Vec<_>>(),
+                }),
+                None => json!({}),
+            }),
+            ("GET", path) if path == key_path => {
+                Ok(json!({ "public_key": self.public_key.to_base58check() }))
+            }
+            ("POST", path) if path == key_path => self.sign(body, authentication),
+            _ => Err(RequestError::NotFound),
+        }
+    }
+
+    /// Serve requests until the server is closed.
+    fn serve(&mut self, server: tiny_http::Server) {
+        for mut request in server.incoming_requests() {
+            let mut body = String::new();
+            let result = match request.as_reader().read_to_string(&mut body) {
+                Ok(_) => self.handle(request.method().as_str(), request.url(), &body),
+                Err(err) => Err(RequestError::BadRequest(err.to_string())),
+            };
+
+            let remote_addr = request.remote_addr()
+                .map(|addr| addr.to_string())
+                .unwrap_or_default();
+            let path = request.url().split('?').next().unwrap_or("");
+
+            let (status_code, response) = match result {
+                Ok(response) => {
+                    eprintln!(
+                        "{} {} {} {}",
+                        style(&remote_addr).dim(),
+                        request.method(),
+                        path,
+                        style("200").green(),
+                    );
+                    (200, response)
+                }
+                Err(err) => {
+                    eprintln!(
+                        "{} {} {} {}: {}",
+                        style(&remote_addr).dim(),
+                        request.method(),
+                        path,
+                        style(err.status_code()).red(),
+                        err,
+                    );
+                    (err.status_code(), json!({ "error": err.to_string() }))
+                }
+            };
+
+            // unwrap is fine since header is valid.
+            let content_type = tiny_http::Header::from_bytes(
+                &b"Content-Type"[..],
+                &b"application/json"[..],
+            ).unwrap();
+            let response = tiny_http::Response::from_string(response.to_string())
+                .with_status_code(status_code)
+                .with_header(content_type);
+
+            if let Err(err) = request.respond(response) {
+                eprintln!("{} failed to respond: {}", style("[WARN]").yellow(), err);
+            }
+        }
+    }
+}
+
+/// Parse file with public keys, one per line. Empty lines and lines
+/// starting with `#` are ignored.
+fn parse_authorized_keys(contents: &str) -> Result<Vec<PublicKey>, String> {
+    contents.lines()
+        .map(str::trim)
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            PublicKey::from_base58check(line).map_err(|err| format!(
+                "invalid public key at line {} of authorized keys file: {}",
+                index + 1,
+                err,
+            ))
+        })
+        .collect()
+}
+
+impl Serve {
+    fn get_key(&self) -> Result<Box<dyn Signer>, CommandError> {
+        let mut options = self.signer.to_raw_options(self.no_prompt);
+        // requests are signed without user interaction.
+        options.trezor_pin_prompt = false;
+
+        let (signer, _) = parse_signer(&options, &self.key, None)?;
+        Ok(signer)
+    }
+
+    pub fn execute(self) -> Result<(), CommandError> {
+        let authorized_keys = match self.authorized_keys.as_ref() {
+            Some(path) => Some(parse_authorized_keys(&fs::read_to_string(path)?)?),
+            None => None,
+        };
+
+        let mut service = SignerService::new(
+            self.get_key()?,
+            authorized_keys,
+            self.magic_bytes.clone(),
+        )?;
+
+        let server = match tiny_http::Server::http(&self.address) {
+            Ok(server) => server,
+            Err(err) => exit_with_error(format!(
+                "failed to listen on {}: {}",
+                style(&self.address).bold(),
+                err,
+            )),
+        };
+
+        eprintln!(
+            "signing with {} ({}), listening on {}",
+            style(service.public_key_hash.to_base58check()).bold(),
+            service.public_key.to_base58check(),
+            style(format!("http://{}", self.address)).cyan(),
+        );
+        if service.authorized_keys.is_none() {
+            eprintln!(
+                "{} {} isn't set, so anyone who can reach the signer can sign with the key!",
+                style("[WARN]").yellow(),
+                style("--authorized-keys").bold(),
+            );
+        }
+        eprintln!();
+
+        service.serve(server);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use lib::signer::{RemoteSigner, RemoteSignerError, OPERATION_WATERMARK};
+    use lib::signer::test_utils::{local_signer, EDSK, EDPK, P2SK};
+    use super::*;
+
+    /// Start signer on a random port and return it's url.
+    fn start(authorized_keys: Option<Vec<PublicKey>>, magic_bytes: Vec<u8>) -> String {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+
+        thread::spawn(move || {
+            let key = local_signer(EDSK);
+            SignerService::new(Box::new(key), authorized_keys, magic_bytes)
+                .unwrap()
+                .serve(server)
+        });
+        url
+    }
+
+    #[test]
+    fn test_serve_remote_signer() {
+        let key = local_signer(EDSK);
+        let auth_key = local_signer(P2SK);
+        let pkh = key.public_key().hash();
+
+        let url = start(Some(vec![auth_key.public_key().clone()]), vec![OPERATION_WATERMARK]);
+
+        let remote_signer = RemoteSigner::new(&url, pkh.clone()).unwrap();
+        assert_eq!(remote_signer.public_key(), key.public_key());
+        // not authenticated.
+        assert!(matches!(
+            remote_signer.sign_forged_operation_bytes(b"operation"),
+            Err(RemoteSignerError::Status { code: 401, .. }),
+        ));
+
+        let remote_signer = remote_signer.with_authentication_key(auth_key);
+        assert_eq!(
+            remote_signer.sign_forged_operation_bytes(b"operation").unwrap().signature,
+            key.sign_forged_operation_bytes(b"operation").signature,
+        );
+        // magic byte not allowed.
+        assert!(matches!(
+            remote_signer.sign_bytes(b"\x01block"),
+            Err(RemoteSignerError::Status { code: 403, .. }),
+        ));
+    }
+
+    #[test]
+    fn test_parse_authorized_keys() {
+        let keys = parse_authorized_keys(&format!("# ci bot\n{}\n\n", EDPK)).unwrap();
+        assert_eq!(keys.len(), 1);
+        assert!(parse_authorized_keys("edpk").is_err());
+    }
+}
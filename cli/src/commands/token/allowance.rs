@@ -0,0 +1,63 @@
+use structopt::StructOpt;
+
+use lib::{Fa12View, format_token_amount};
+
+use crate::commands::CommandError;
+use super::{parse_contract, parse_address, read_amount, fa12_decimals};
+
+/// Get amount of tokens the spender is allowed to transfer from the
+/// owner's account (see `token approve`).
+///
+/// Outputs allowance to stdout.
+#[derive(StructOpt)]
+pub struct Allowance {
+    /// Node's rpc endpoint.
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    /// Address of the FA1.2 token contract.
+    #[structopt(short, long)]
+    pub contract: String,
+
+    /// Number of decimals of the token, used to format the allowance.
+    ///
+    /// If not specified, it's read from the token metadata of the
+    /// contract, or 0 is used if it's not available.
+    #[structopt(long)]
+    pub decimals: Option<u32>,
+
+    /// Id of the ledger big map (`big_map address ...`) of the contract.
+    ///
+    /// If specified, allowance is read from the big map, instead of
+    /// running `getAllowance` view.
+    #[structopt(long = "big-map")]
+    pub big_map: Option<u64>,
+
+    /// Owner of the tokens: address or name of the account in the local keystore.
+    #[structopt(long)]
+    pub owner: String,
+
+    /// Spender of the tokens: address or name of the account in the local keystore.
+    #[structopt(long)]
+    pub spender: String,
+}
+
+impl Allowance {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let contract = parse_contract(&self.contract)?;
+        let allowance = read_amount(
+            &self.endpoint,
+            &contract,
+            self.big_map,
+            Fa12View::GetAllowance {
+                owner: parse_address(&self.owner)?,
+                spender: parse_address(&self.spender)?,
+            },
+        )?;
+
+        let decimals = fa12_decimals(self.decimals, &self.endpoint, &contract)?;
+
+        println!("{}", format_token_amount(&allowance, decimals));
+        Ok(())
+    }
+}
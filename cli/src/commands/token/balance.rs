@@ -0,0 +1,53 @@
+use structopt::StructOpt;
+
+use lib::{Fa12View, format_token_amount};
+
+use crate::commands::CommandError;
+use super::{parse_contract, parse_address, read_amount, fa12_decimals};
+
+/// Get token balance of the account.
+///
+/// Outputs balance to stdout.
+#[derive(StructOpt)]
+pub struct Balance {
+    /// Node's rpc endpoint.
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    /// Address of the FA1.2 token contract.
+    #[structopt(short, long)]
+    pub contract: String,
+
+    /// Number of decimals of the token, used to format the balance.
+    ///
+    /// If not specified, it's read from the token metadata of the
+    /// contract, or 0 is used if it's not available.
+    #[structopt(long)]
+    pub decimals: Option<u32>,
+
+    /// Id of the ledger big map (`big_map address ...`) of the contract.
+    ///
+    /// If specified, balance is read from the big map, instead of
+    /// running `getBalance` view.
+    #[structopt(long = "big-map")]
+    pub big_map: Option<u64>,
+
+    /// Address of the account, or name of the account in the local keystore.
+    pub owner: String,
+}
+
+impl Balance {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let contract = parse_contract(&self.contract)?;
+        let balance = read_amount(
+            &self.endpoint,
+            &contract,
+            self.big_map,
+            Fa12View::GetBalance { owner: parse_address(&self.owner)? },
+        )?;
+        let decimals = fa12_decimals(self.decimals, &self.endpoint, &contract)?;
+
+        println!("{}", format_token_amount(&balance, decimals));
+        Ok(())
+    }
+}
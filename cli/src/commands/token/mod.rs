@@ -0,0 +1,159 @@
+use structopt::StructOpt;
+use console::style;
+
+use lib::{Address, OriginatedAddress, Fa12View, Fa12LedgerEntry, fa12_ledger_key};
+use lib::{NewOriginationScript, token_metadata_big_map, token_metadata_key, token_metadata_decimals};
+use lib::micheline::{Micheline, BigUint};
+use lib::api::{RunView, GetBigMapValue, GetContractCode, GetContractStorage};
+use lib::http_api::HttpApi;
+
+pub mod transfer;
+pub use transfer::Transfer;
+
+pub mod approve;
+pub use approve::Approve;
+
+pub mod balance;
+pub use balance::Balance;
+
+pub mod allowance;
+pub use allowance::Allowance;
+
+use crate::commands::CommandError;
+use crate::common::Keystore;
+
+/// Transfer and query FA1.2 (TZIP-7) tokens.
+#[derive(StructOpt)]
+pub enum Token {
+    Transfer(Transfer),
+    Approve(Approve),
+    Balance(Balance),
+    Allowance(Allowance),
+}
+
+impl Token {
+    /// Get node endpoint.
+    pub fn get_endpoint(&self) -> Option<&str> {
+        match self {
+            Self::Transfer(cmd) => Some(cmd.endpoint.as_str()),
+            Self::Approve(cmd) => Some(cmd.endpoint.as_str()),
+            Self::Balance(cmd) => Some(cmd.endpoint.as_str()),
+            Self::Allowance(cmd) => Some(cmd.endpoint.as_str()),
+        }
+    }
+
+    pub fn execute(self) -> Result<(), CommandError> {
+        match self {
+            Self::Transfer(c) => c.execute(),
+            Self::Approve(c) => c.execute(),
+            Self::Balance(c) => c.execute(),
+            Self::Allowance(c) => c.execute(),
+        }
+    }
+}
+
+pub fn parse_contract(contract: &str) -> Result<OriginatedAddress, CommandError> {
+    Ok(OriginatedAddress::from_base58check(contract)
+        .map_err(|_| format!("invalid token contract address: {}", style(contract).red()))?)
+}
+
+/// Parse address, or get it from the local keystore by account name.
+pub fn parse_address(address: &str) -> Result<Address, CommandError> {
+    if let Ok(address) = Address::from_base58check(address) {
+        return Ok(address);
+    }
+
+    match Keystore::open_default()?.find(address) {
+        Some(account) => Ok(account.address().into()),
+        None => Err(format!("invalid address: {}", style(address).red()).into()),
+    }
+}
+
+/// Number of decimals of the token, read from the `token_metadata` big
+/// map of the contract (TZIP-12, TZIP-21).
+///
+/// Falls back to 0 decimals with a warning, if contract doesn't have
+/// on-chain token metadata, or it doesn't specify decimals.
+pub fn token_decimals(
+    endpoint: &str,
+    contract: &OriginatedAddress,
+    token_id: &BigUint,
+) -> Result<u32, CommandError>
+{
+    let api = HttpApi::new(endpoint);
+    let script = NewOriginationScript {
+        code: api.get_contract_code(contract)?,
+        storage: serde_json::from_value::<Micheline>(api.get_contract_storage(contract)?)?,
+    };
+
+    let big_map = script.storage_type()
+        .and_then(|storage_type| token_metadata_big_map(storage_type, &script.storage));
+    let decimals = match big_map {
+        Some(big_map) => api.get_big_map_value(big_map, &token_metadata_key(token_id))?
+            .and_then(|value| token_metadata_decimals(&value)),
+        None => None,
+    };
+
+    Ok(decimals.unwrap_or_else(|| {
+        eprintln!(
+            "{} decimals of the token with id {} aren't found in the contract's metadata, using {}. Specify them with {}.\n",
+            style("[WARN]").yellow(),
+            token_id,
+            style("0 decimals").bold(),
+            style("--decimals").bold(),
+        );
+        0
+    }))
+}
+
+/// Decimals given with `--decimals`, or from the token metadata of the
+/// FA1.2 contract, which has a single token with id 0.
+fn fa12_decimals(
+    decimals: Option<u32>,
+    endpoint: &str,
+    contract: &OriginatedAddress,
+) -> Result<u32, CommandError>
+{
+    match decimals {
+        Some(decimals) => Ok(decimals),
+        None => token_decimals(endpoint, contract, &BigUint::from(0u32)),
+    }
+}
+
+/// Read amount using view entrypoint of the contract, or from the
+/// ledger big map, if `big_map` id is given.
+fn read_amount(
+    endpoint: &str,
+    contract: &OriginatedAddress,
+    big_map: Option<u64>,
+    view: Fa12View,
+) -> Result<BigUint, CommandError>
+{
+    let api = HttpApi::new(endpoint);
+
+    let big_map = match big_map {
+        Some(big_map) => big_map,
+        None => {
+            let result = api.run_view(contract, view.entrypoint(), &view.input())?;
+            return Ok(view.parse_result(&result)?);
+        }
+    };
+
+    let owner = match &view {
+        Fa12View::GetBalance { owner } => owner,
+        Fa12View::GetAllowance { owner, .. } => owner,
+        Fa12View::GetTotalSupply => {
+            return Err("total supply can't be read from the ledger big map.".into());
+        }
+    };
+    let entry = match api.get_big_map_value(big_map, &fa12_ledger_key(owner))? {
+        Some(value) => Fa12LedgerEntry::from_micheline(&value)?,
+        // owner never had any tokens.
+        None => return Ok(BigUint::default()),
+    };
+
+    Ok(match &view {
+        Fa12View::GetAllowance { spender, .. } => entry.allowance(spender),
+        _ => entry.balance,
+    })
+}
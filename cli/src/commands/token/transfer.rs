@@ -0,0 +1,121 @@
+use structopt::StructOpt;
+use console::style;
+
+use lib::{ToBase58Check, Fa12Call, parse_token_amount, format_token_amount};
+
+use crate::commands::CommandError;
+use crate::common::operation_command::*;
+use super::{parse_contract, parse_address, fa12_decimals};
+
+/// Transfer FA1.2 tokens.
+///
+/// Outputs operation hash to stdout in case of success.
+#[derive(StructOpt)]
+pub struct Transfer {
+    /// Disable interactivity and accept default answers to prompts.
+    #[structopt(short = "y", long = "no-prompt")]
+    pub no_prompt: bool,
+
+    /// Node's rpc endpoint.
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    #[structopt(flatten)]
+    pub signer: SignerOptions,
+
+    /// Account, which sends the operation.
+    ///
+    /// When using Trezor or Ledger, use key derivation path,
+    /// like: "m/44'/1729'/0'/0'"
+    ///
+    /// Otherwise use name or address of the account in the local
+    /// keystore (see `keys import`).
+    #[structopt(short, long)]
+    pub from: String,
+
+    /// Owner of the transferred tokens, if it isn't --from account.
+    ///
+    /// Owner needs to approve --from account to spend its tokens
+    /// first (see `token approve`).
+    #[structopt(long)]
+    pub owner: Option<String>,
+
+    /// Address of the FA1.2 token contract.
+    #[structopt(short, long)]
+    pub contract: String,
+
+    /// Address to transfer tokens to.
+    #[structopt(short, long)]
+    pub to: String,
+
+    /// Amount of tokens to transfer, like: "1.5".
+    #[structopt(short, long)]
+    pub amount: String,
+
+    /// Number of decimals of the token.
+    ///
+    /// Amount is multiplied by 10^decimals to get the amount in the
+    /// smallest units of the token. If not specified, it's read from
+    /// the token metadata of the contract, or 0 is used if it's not
+    /// available.
+    #[structopt(long)]
+    pub decimals: Option<u32>,
+
+    /// Fee for the operation.
+    ///
+    /// If not specified, fee will be estimated and you will be prompted
+    /// whether or not you accept estimate or would like to enter custom one.
+    #[structopt(long)]
+    pub fee: Option<String>,
+}
+
+impl RawOperationCommand for Transfer {
+    fn get_no_prompt(&self) -> bool {
+        self.no_prompt
+    }
+
+    fn get_signer_options(&self) -> &SignerOptions {
+        &self.signer
+    }
+
+    fn get_api_endpoint(&self) -> String {
+        self.endpoint.clone()
+    }
+
+    fn get_raw_key_path(&self) -> Option<&str> {
+        None
+    }
+
+    fn get_raw_from(&self) -> &str {
+        &self.from
+    }
+
+    fn get_raw_fee(&self) -> Option<&String> {
+        self.fee.as_ref()
+    }
+}
+
+impl Transfer {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let contract = parse_contract(&self.contract)?;
+        let to = parse_address(&self.to)?;
+        let decimals = fa12_decimals(self.decimals, &self.endpoint, &contract)?;
+        let value = parse_token_amount(&self.amount, decimals)?;
+
+        let mut command = self.parse()?;
+        let from = match self.owner.as_ref() {
+            Some(owner) => parse_address(owner)?,
+            None => command.from.clone(),
+        };
+
+        eprintln!(
+            "transferring {} tokens ({} in the smallest units) from {} to {}\n",
+            style(format_token_amount(&value, decimals)).green(),
+            value,
+            style(from.to_base58check()).bold(),
+            style(to.to_base58check()).bold(),
+        );
+
+        command.call_contract(contract, Fa12Call::Transfer { from, to, value }.into())
+    }
+}
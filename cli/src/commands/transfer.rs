@@ -29,13 +29,8 @@ pub struct Transfer {
     #[structopt(short = "E", long)]
     pub endpoint: String,
 
-    /// Use Trezor device.
-    #[structopt(long = "trezor")]
-    pub use_trezor: bool,
-
-    /// Use Ledger device.
-    #[structopt(long = "ledger")]
-    pub use_ledger: bool,
+    #[structopt(flatten)]
+    pub signer: SignerOptions,
 
     /// Key Derivation Path.
     ///
@@ -51,17 +46,26 @@ pub struct Transfer {
     /// When transfering from scriptless smart contract (KT1) address,
     /// this needs to be that KT1 address.
     ///
-    /// Otherwise use key derivation path, like: "m/44'/1729'/0'/0'"
+    /// When using Trezor or Ledger, use key derivation path,
+    /// like: "m/44'/1729'/0'/0'"
+    ///
+    /// Otherwise use name or address of the account in the local
+    /// keystore (see `keys import`).
     #[structopt(short, long)]
     pub from: String,
 
     /// Address to transfer funds to.
-    #[structopt(short, long)]
-    pub to: String,
+    ///
+    /// Can be repeated along with --amount to send multiple transactions
+    /// in a single operation group (batch).
+    #[structopt(short, long, required = true, number_of_values = 1)]
+    pub to: Vec<String>,
 
     /// Amount to transfer.
-    #[structopt(short, long)]
-    pub amount: String,
+    ///
+    /// Should be specified once for each --to address, in the same order.
+    #[structopt(short, long, required = true, number_of_values = 1)]
+    pub amount: Vec<String>,
 
     /// Fee for the transaction.
     ///
@@ -72,13 +76,12 @@ pub struct Transfer {
 }
 
 impl RawOperationCommand for Transfer {
-    fn get_raw_options(&self) -> RawOptions {
-        RawOptions {
-            api_type: "http".to_string(),
-            no_prompt: self.no_prompt,
-            use_trezor: self.use_trezor,
-            use_ledger: self.use_ledger,
-        }
+    fn get_no_prompt(&self) -> bool {
+        self.no_prompt
+    }
+
+    fn get_signer_options(&self) -> &SignerOptions {
+        &self.signer
     }
 
     fn get_api_endpoint(&self) -> String {
@@ -98,26 +101,49 @@ impl RawOperationCommand for Transfer {
     }
 }
 
-impl Transfer {
-    fn get_amount(&self) -> u64 {
-        match parse_float_amount(&self.amount) {
-            Ok(amount) => amount,
-            Err(_) => {
-                exit_with_error(format!(
-                    "invalid amount: {}",
-                    style(&self.amount).bold()
-                ));
-            }
+/// Parse `--to` and `--amount` arguments into transfers.
+pub fn parse_transfers(
+    to: &[String],
+    amount: &[String],
+) -> Result<Vec<(Address, u64)>, ParseAddressError>
+{
+    if to.len() != amount.len() {
+        exit_with_error(format!(
+            "number of {} and {} arguments must be the same",
+            style("--to").bold(),
+            style("--amount").bold(),
+        ));
+    }
+
+    to.iter()
+        .zip(amount.iter())
+        .map(|(to, amount)| {
+            let to = Address::from_base58check(to)
+                .map_err(|err| ParseAddressError {
+                    kind: AddressKind::Destination,
+                    error: err,
+                    address: to.clone(),
+                })?;
+            Ok((to, parse_amount(amount)))
+        })
+        .collect()
+}
+
+pub fn parse_amount(amount: &str) -> u64 {
+    match parse_float_amount(amount) {
+        Ok(amount) => amount,
+        Err(_) => {
+            exit_with_error(format!(
+                "invalid amount: {}",
+                style(amount).bold()
+            ));
         }
     }
+}
 
+impl Transfer {
     pub fn execute(self) -> Result<(), CommandError> {
-        let to = Address::from_base58check(&self.to)
-           .map_err(|err| ParseAddressError {
-               kind: AddressKind::Destination,
-               error: err,
-               address: self.to.clone(),
-           })?;
-        Ok(self.parse()?.transfer(to, self.get_amount())?)
+        let transfers = parse_transfers(&self.to, &self.amount)?;
+        Ok(self.parse()?.batch_transfer(transfers)?)
     }
 }
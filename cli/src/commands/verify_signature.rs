@@ -0,0 +1,57 @@
+use structopt::StructOpt;
+use console::style;
+
+use lib::{PublicKey, Signature};
+use lib::signer::verify_signature;
+
+use crate::commands::CommandError;
+use crate::commands::sign_message::MessageFormat;
+use crate::common::{exit_with_error, Keystore};
+
+/// Verify signature of an arbitrary (off-chain) message.
+///
+/// Exits with non-zero code if signature is invalid.
+#[derive(StructOpt)]
+pub struct VerifySignature {
+    /// Public key of the signer, or name or address of the account in
+    /// the local keystore.
+    #[structopt(short = "k", long = "public-key")]
+    pub public_key: String,
+
+    /// Signature to verify: edsig, spsig1, p2sig or generic sig.
+    #[structopt(short, long)]
+    pub signature: String,
+
+    /// Format of the message, same as for `sign-message`.
+    #[structopt(long, default_value = "micheline", possible_values = &["micheline", "raw", "hex"])]
+    pub format: MessageFormat,
+
+    /// Signed message.
+    pub message: String,
+}
+
+impl VerifySignature {
+    fn get_public_key(&self) -> Result<PublicKey, CommandError> {
+        if let Ok(public_key) = PublicKey::from_base58check(&self.public_key) {
+            return Ok(public_key);
+        }
+
+        match Keystore::open_default()?.find(&self.public_key) {
+            Some(account) => Ok(account.public_key.clone()),
+            None => Err(format!("invalid public key: {}", style(&self.public_key).red()).into()),
+        }
+    }
+
+    pub fn execute(self) -> Result<(), CommandError> {
+        let public_key = self.get_public_key()?;
+        let signature = Signature::from_base58check(&self.signature)?;
+        let bytes = self.format.message_bytes(&self.message)?;
+
+        if !verify_signature(&public_key, &bytes, &signature) {
+            exit_with_error("signature is invalid");
+        }
+
+        eprintln!("{} signature is valid", style(emojies::TICK).green());
+        Ok(())
+    }
+}
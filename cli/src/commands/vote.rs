@@ -0,0 +1,164 @@
+use structopt::StructOpt;
+use console::style;
+
+use lib::{Ballot, ProtocolHash};
+use lib::crypto::ToBase58Check;
+use lib::http_api::HttpApi;
+use lib::api::*;
+
+use cli_spinner::SpinnerBuilder;
+use crate::commands::CommandError;
+use crate::common::exit_with_error;
+use crate::common::operation_command::*;
+
+/// Vote on protocol amendments.
+///
+/// Without --ballot or --proposal, only shows the current voting period
+/// and proposals.
+///
+/// Outputs operation hash to stdout in case of success.
+#[derive(StructOpt)]
+pub struct Vote {
+    /// Disable interactivity and accept default answers to prompts.
+    #[structopt(short = "y", long = "no-prompt")]
+    pub no_prompt: bool,
+
+    /// Node's rpc endpoint.
+    ///
+    /// Sample Testnet nodes:
+    /// - https://api.tez.ie/rpc/edonet
+    /// - https://rpctest.tzbeta.net
+    /// - https://testnet-tezos.giganode.io
+    #[structopt(short = "E", long)]
+    pub endpoint: String,
+
+    #[structopt(flatten)]
+    pub signer: SignerOptions,
+
+    /// Delegate which submits the vote.
+    ///
+    /// When using Trezor or Ledger, use key derivation path,
+    /// like: "m/44'/1729'/0'/0'"
+    ///
+    /// Otherwise use name or address of the account in the local
+    /// keystore (see `keys import`).
+    #[structopt(short, long)]
+    pub from: Option<String>,
+
+    /// Ballot for the current proposal: yay, nay or pass.
+    ///
+    /// Can only be submitted during exploration or promotion period.
+    #[structopt(long, conflicts_with = "proposals")]
+    pub ballot: Option<Ballot>,
+
+    /// Protocol hash of the proposal to submit or upvote.
+    ///
+    /// Can be passed multiple times. Can only be submitted during
+    /// proposal period.
+    #[structopt(long = "proposal", number_of_values = 1)]
+    pub proposals: Vec<String>,
+}
+
+impl RawOperationCommand for Vote {
+    fn get_no_prompt(&self) -> bool {
+        self.no_prompt
+    }
+
+    fn get_signer_options(&self) -> &SignerOptions {
+        &self.signer
+    }
+
+    fn get_api_endpoint(&self) -> String {
+        self.endpoint.clone()
+    }
+
+    fn get_raw_key_path(&self) -> Option<&str> {
+        None
+    }
+
+    fn get_raw_from(&self) -> &str {
+        self.from.as_deref().unwrap_or("")
+    }
+
+    fn get_raw_fee(&self) -> Option<&String> {
+        None
+    }
+}
+
+impl Vote {
+    pub fn execute(self) -> Result<(), CommandError> {
+        let proposals = self.proposals.iter()
+            .map(|proposal| ProtocolHash::from_base58check(proposal))
+            .collect::<Result<Vec<_>, _>>()?;
+        let is_voting = self.ballot.is_some() || !proposals.is_empty();
+
+        if is_voting && self.from.is_none() {
+            exit_with_error(format!(
+                "{} argument is required to submit a vote.",
+                style("--from").bold(),
+            ));
+        }
+
+        let api = HttpApi::new(self.endpoint.clone());
+
+        let spinner = SpinnerBuilder::new()
+            .with_text("fetching current voting period")
+            .start();
+
+        let period = api.get_current_voting_period()?;
+        let current_proposal = api.get_current_proposal()?;
+        let submitted_proposals = match period.kind {
+            VotingPeriodKind::Proposal => api.get_proposals()?,
+            _ => vec![],
+        };
+
+        spinner.finish_succeed("fetched current voting period");
+
+        eprintln!(
+            "\n  Voting period: {} (index: {}, blocks remaining: {})",
+            style(period.kind).bold(),
+            period.index,
+            period.remaining,
+        );
+        if let Some(proposal) = current_proposal.as_ref() {
+            eprintln!("  Current proposal: {}", style(proposal.to_base58check()).bold());
+        }
+        if period.kind == VotingPeriodKind::Proposal {
+            eprintln!("  Proposals:");
+            if submitted_proposals.is_empty() {
+                eprintln!("    none");
+            }
+            for proposal in submitted_proposals.iter() {
+                eprintln!(
+                    "    {} (upvotes: {})",
+                    style(proposal.protocol_hash.to_base58check()).bold(),
+                    proposal.upvotes,
+                );
+            }
+        }
+        eprintln!();
+
+        if let Some(ballot) = self.ballot {
+            let proposal = match current_proposal {
+                Some(proposal) if period.kind.is_ballot_period() => proposal,
+                _ => exit_with_error(format!(
+                    "ballots can't be submitted during {} period.",
+                    style(period.kind).bold(),
+                )),
+            };
+
+            Ok(self.parse()?.submit_ballot(period.index, proposal, ballot)?)
+        } else if !proposals.is_empty() {
+            if period.kind != VotingPeriodKind::Proposal {
+                exit_with_error(format!(
+                    "proposals can't be submitted during {} period.",
+                    style(period.kind).bold(),
+                ));
+            }
+
+            Ok(self.parse()?.submit_proposals(period.index, proposals)?)
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -1,31 +1,46 @@
-use lib::{NewOperationGroup, NewTransactionParameters, Address};
-use lib::api::{RunOperation, RunOperationError, RunOperationContents};
+use lib::{NewOperationGroup, NewOperation, NewTransactionParameters, Address};
+use lib::api::{RunOperation, RunOperationError};
 
+/// Estimated gas consumption for each operation in the group.
+///
+/// Values are in the same order as operations in the group.
 #[derive(PartialEq, Debug, Clone)]
 pub struct OperationGroupGasConsumption {
-    pub reveal: Option<u64>,
-    pub transaction: Option<u64>,
-    pub delegation: Option<u64>,
+    pub operations: Vec<u64>,
+    /// Storage (in bytes) paid for by each operation, according
+    /// to the simulation.
+    pub paid_storage: Vec<u64>,
 }
 
 impl OperationGroupGasConsumption {
     pub fn total(&self) -> u64 {
-        self.reveal.unwrap_or(0)
-            + self.transaction.unwrap_or(0)
-            + self.delegation.unwrap_or(0)
+        self.operations.iter().sum()
     }
 }
 
-fn find_consumed_gas_for_kind(
-    kind: &str,
-    run_op_contents: &RunOperationContents,
-) -> Option<u64> {
-    run_op_contents.iter()
-        .find(|op| op.kind.as_str() == kind)
-        // Add 100 for safety
-        .map(|op| op.consumed_gas + 100)
-}
+/// Additional gas required when sending/delegating from Smart Contract (KT1).
+fn additional_gas(op: &NewOperation) -> u64 {
+    use NewTransactionParameters::*;
+
+    let params = match op {
+        NewOperation::Transaction(op) => op.parameters.as_ref(),
+        _ => None,
+    };
 
+    match params {
+        Some(Transfer { to, .. }) => {
+            match to {
+                Address::Implicit(_) => 1427,
+                Address::Originated(_) => 2863,
+            }
+        }
+        Some(SetDelegate(_)) => 1000,
+        Some(CancelDelegate) => 1000,
+        // simulation already accounts for the whole contract call.
+        Some(Call { .. }) => 0,
+        None => 0,
+    }
+}
 
 pub fn estimate_gas_consumption<A>(
     op: &NewOperationGroup,
@@ -34,28 +49,23 @@ pub fn estimate_gas_consumption<A>(
     where A: RunOperation + ?Sized,
 {
     let op_results = api.run_operation(op)?;
-    // additional gas required when sending/delegating from Smart Contract (KT1).
-    let tx_additional_gas = op.transaction.as_ref()
-        .map(|op| {
-            use NewTransactionParameters::*;
-            match op.parameters.as_ref() {
-                Some(Transfer { to, .. }) => {
-                    match to {
-                        Address::Implicit(_) => 1427,
-                        Address::Originated(_) => 2863,
-                    }
-                }
-                Some(SetDelegate(_)) => 1000,
-                Some(CancelDelegate) => 1000,
-                None => 0,
-            }
-        })
-        .unwrap_or(0);
+
+    if op_results.len() != op.operations.len() {
+        return Err(RunOperationError::Unknown(format!(
+            "expected results for {} operations, got {}",
+            op.operations.len(),
+            op_results.len(),
+        )));
+    }
 
     Ok(OperationGroupGasConsumption {
-        reveal: find_consumed_gas_for_kind("reveal", &op_results),
-        transaction: find_consumed_gas_for_kind("transaction", &op_results)
-            .map(|gas| gas + tx_additional_gas),
-        delegation: find_consumed_gas_for_kind("delegation", &op_results),
+        operations: op.operations.iter()
+            .zip(op_results.iter())
+            // Add 100 for safety
+            .map(|(op, result)| result.consumed_gas + 100 + additional_gas(op))
+            .collect(),
+        paid_storage: op_results.iter()
+            .map(|result| result.paid_storage_size_diff)
+            .collect(),
     })
 }
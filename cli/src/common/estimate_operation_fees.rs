@@ -1,4 +1,4 @@
-use lib::NewOperationGroup;
+use lib::{NewOperationGroup, NewOperation};
 
 use super::OperationGroupGasConsumption;
 
@@ -6,18 +6,17 @@ const BASE_FEE: u64 = 100;
 const MIN_NTEZ_PER_GAS: u64 = 100;
 const MIN_NTEZ_PER_BYTE: u64 = 1000;
 
+/// Estimated fee for each operation in the group.
+///
+/// Values are in the same order as operations in the group.
 #[derive(PartialEq, Debug, Clone)]
 pub struct OperationFees {
-    pub reveal: Option<u64>,
-    pub transaction: Option<u64>,
-    pub delegation: Option<u64>,
+    pub operations: Vec<u64>,
 }
 
 impl OperationFees {
     pub fn total(&self) -> u64 {
-        self.reveal.unwrap_or(0)
-            + self.transaction.unwrap_or(0)
-            + self.delegation.unwrap_or(0)
+        self.operations.iter().sum()
     }
 }
 
@@ -26,45 +25,67 @@ pub fn estimate_operation_fees(
     gas_consumption: &OperationGroupGasConsumption,
 ) -> OperationFees
 {
-    let reveal_fee = match (&op.reveal, gas_consumption.reveal) {
-        (Some(op), Some(consumed_gas)) => {
-            Some(op.estimate_fee(
-                BASE_FEE,
-                MIN_NTEZ_PER_BYTE,
-                MIN_NTEZ_PER_GAS,
-                consumed_gas,
-            ))
-        }
-        _ => None,
-    };
+    OperationFees {
+        operations: op.operations.iter()
+            .zip(gas_consumption.operations.iter())
+            .map(|(op, consumed_gas)| {
+                let fee = op.estimate_fee(
+                    BASE_FEE,
+                    MIN_NTEZ_PER_BYTE,
+                    MIN_NTEZ_PER_GAS,
+                    *consumed_gas,
+                );
 
-    let transaction_fee = match (&op.transaction, gas_consumption.transaction) {
-        (Some(op), Some(consumed_gas)) => {
-            Some(op.estimate_fee(
-                BASE_FEE,
-                MIN_NTEZ_PER_BYTE,
-                MIN_NTEZ_PER_GAS,
-                consumed_gas,
-            ))
-        }
-        _ => None,
-    };
+                match op {
+                    NewOperation::Reveal(_) => fee + 50,
+                    _ => fee + 100,
+                }
+            })
+            .collect(),
+    }
+}
 
-    let delegation_fee = match (&op.delegation, gas_consumption.delegation) {
-        (Some(op), Some(consumed_gas)) => {
-            Some(op.estimate_fee(
-                BASE_FEE,
-                MIN_NTEZ_PER_BYTE,
-                MIN_NTEZ_PER_GAS,
-                consumed_gas,
-            ))
-        }
-        _ => None,
-    };
+/// Split the `fee` between operations, in proportion to their
+/// estimated fees.
+///
+/// Remainder left after rounding down is added to the first operation,
+/// so that parts always add up to the `fee`. If all estimates are zero,
+/// the `fee` is split evenly.
+pub fn split_fee(fee: u64, estimated_fees: &[u64]) -> Vec<u64> {
+    if estimated_fees.is_empty() {
+        return vec![];
+    }
 
-    OperationFees {
-        reveal: reveal_fee.map(|x| x + 50),
-        transaction: transaction_fee.map(|x| x + 100),
-        delegation: delegation_fee.map(|x| x + 100),
+    let estimated_total = estimated_fees.iter().map(|fee| *fee as u128).sum::<u128>();
+
+    let mut parts = estimated_fees.iter()
+        .map(|estimated_fee| {
+            if estimated_total == 0 {
+                fee / estimated_fees.len() as u64
+            } else {
+                (fee as u128 * *estimated_fee as u128 / estimated_total) as u64
+            }
+        })
+        .collect::<Vec<_>>();
+
+    parts[0] += fee - parts.iter().sum::<u64>();
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_fee() {
+        assert_eq!(split_fee(1000, &[]), Vec::<u64>::new());
+        assert_eq!(split_fee(1000, &[400]), vec![1000]);
+        // estimated fee is kept as is.
+        assert_eq!(split_fee(1200, &[400, 500, 300]), vec![400, 500, 300]);
+        assert_eq!(split_fee(2400, &[400, 500, 300]), vec![800, 1000, 600]);
+        assert_eq!(split_fee(1000, &[1, 1, 1]), vec![334, 333, 333]);
+        assert_eq!(split_fee(10, &[0, 0, 0]), vec![4, 3, 3]);
+        assert_eq!(split_fee(0, &[400, 500]), vec![0, 0]);
+        assert_eq!(split_fee(u64::MAX, &[u64::MAX, u64::MAX]), vec![u64::MAX / 2 + 1, u64::MAX / 2]);
     }
 }
@@ -0,0 +1,304 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::common::password_input;
+
+use lib::{
+    ToBase58Check, ImplicitAddress, PublicKey, PrivateKey,
+    EncryptedPrivateKey, DecryptPrivateKeyError,
+};
+
+const KEYSTORE_DIR: &str = ".tezedge-client";
+const KEYSTORE_FILE: &str = "keystore.json";
+
+const ENCRYPTED_PREFIX: &str = "encrypted:";
+const UNENCRYPTED_PREFIX: &str = "unencrypted:";
+
+#[derive(thiserror::Error, Debug)]
+pub enum KeystoreError {
+    #[error("failed to access keystore: {0}")]
+    IO(#[from] io::Error),
+    #[error("invalid keystore file: {0}")]
+    InvalidFile(#[from] serde_json::Error),
+    #[error("home directory not found, so keystore location is unknown")]
+    HomeDirNotFound,
+    #[error("account \"{0}\" already exists in the keystore")]
+    AlreadyExists(String),
+    #[error("account \"{0}\" not found in the keystore")]
+    NotFound(String),
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error(transparent)]
+pub enum UnlockAccountError {
+    IO(#[from] io::Error),
+    DecryptPrivateKey(#[from] DecryptPrivateKeyError),
+    #[error("interactivity is turned off, but password is needed to decrypt the key of the account \"{0}\".")]
+    MissingPassword(String),
+}
+
+/// Secret key of the account, in the same format as Octez stores it:
+/// `encrypted:edesk...` or `unencrypted:edsk...`.
+#[derive(Clone)]
+pub enum StoredSecretKey {
+    Encrypted(EncryptedPrivateKey),
+    Unencrypted(PrivateKey),
+}
+
+impl StoredSecretKey {
+    /// Parse secret key with optional `encrypted:`/`unencrypted:` prefix.
+    pub fn parse(encoded: &str) -> Result<Self, lib::FromPrefixedBase58CheckError> {
+        let encoded = encoded.trim();
+        let encoded = encoded.strip_prefix(ENCRYPTED_PREFIX)
+            .or_else(|| encoded.strip_prefix(UNENCRYPTED_PREFIX))
+            .unwrap_or(encoded);
+
+        EncryptedPrivateKey::from_base58check(encoded)
+            .map(Self::Encrypted)
+            .or_else(|_| PrivateKey::from_base58check(encoded).map(Self::Unencrypted))
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        matches!(self, Self::Encrypted(_))
+    }
+
+    /// Get the private key, decrypting it if necessary.
+    ///
+    /// `password` is only used for encrypted key.
+    pub fn decrypt(&self, password: &str) -> Result<PrivateKey, DecryptPrivateKeyError> {
+        match self {
+            Self::Encrypted(key) => key.decrypt(password),
+            Self::Unencrypted(key) => Ok(key.clone()),
+        }
+    }
+}
+
+impl Serialize for StoredSecretKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer,
+    {
+        serializer.serialize_str(&match self {
+            Self::Encrypted(key) => format!("{}{}", ENCRYPTED_PREFIX, key.to_base58check()),
+            Self::Unencrypted(key) => format!("{}{}", UNENCRYPTED_PREFIX, key.to_base58check()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for StoredSecretKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>,
+    {
+        let encoded = String::deserialize(deserializer)?;
+
+        Self::parse(&encoded).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Named account in the keystore.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct KeystoreAccount {
+    pub name: String,
+    pub public_key: PublicKey,
+    pub secret_key: StoredSecretKey,
+}
+
+impl KeystoreAccount {
+    pub fn address(&self) -> ImplicitAddress {
+        self.public_key.hash()
+    }
+
+    /// Get the private key, asking for the password if it's encrypted.
+    pub fn unlock(&self, no_prompt: bool) -> Result<PrivateKey, UnlockAccountError> {
+        let password = if !self.secret_key.is_encrypted() {
+            String::new()
+        } else if no_prompt {
+            return Err(UnlockAccountError::MissingPassword(self.name.clone()));
+        } else {
+            password_input(
+                &format!("password for the account \"{}\"", self.name),
+                false,
+            )?
+        };
+
+        Ok(self.secret_key.decrypt(&password)?)
+    }
+}
+
+/// Local storage for the keys, each under a unique name (alias).
+///
+/// Stored as a json file, by default in `~/.tezedge-client/keystore.json`.
+pub struct Keystore {
+    path: PathBuf,
+    accounts: Vec<KeystoreAccount>,
+}
+
+impl Keystore {
+    pub fn default_path() -> Result<PathBuf, KeystoreError> {
+        dirs::home_dir()
+            .map(|home| home.join(KEYSTORE_DIR).join(KEYSTORE_FILE))
+            .ok_or(KeystoreError::HomeDirNotFound)
+    }
+
+    /// Open keystore at the given path. If file doesn't exist,
+    /// keystore will be empty and file will be created on save.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, KeystoreError> {
+        let path = path.as_ref().to_path_buf();
+
+        let accounts = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Self { path, accounts })
+    }
+
+    /// Open keystore at the [Keystore::default_path].
+    pub fn open_default() -> Result<Self, KeystoreError> {
+        Self::open(Self::default_path()?)
+    }
+
+    pub fn accounts(&self) -> &[KeystoreAccount] {
+        &self.accounts
+    }
+
+    /// Find account by it's name or address.
+    pub fn find(&self, name_or_address: &str) -> Option<&KeystoreAccount> {
+        self.accounts.iter()
+            .find(|account| account.name == name_or_address)
+            .or_else(|| {
+                self.accounts.iter().find(|account| {
+                    account.address().to_base58check() == name_or_address
+                })
+            })
+    }
+
+    /// Add new account.
+    ///
+    /// If `force` is `true`, existing account with the same name
+    /// will be replaced, otherwise error will be returned.
+    pub fn add(&mut self, account: KeystoreAccount, force: bool) -> Result<(), KeystoreError> {
+        match self.accounts.iter().position(|x| x.name == account.name) {
+            Some(index) if force => { self.accounts[index] = account; }
+            Some(_) => return Err(KeystoreError::AlreadyExists(account.name)),
+            None => self.accounts.push(account),
+        }
+        Ok(())
+    }
+
+    /// Remove account by it's name.
+    pub fn remove(&mut self, name: &str) -> Result<KeystoreAccount, KeystoreError> {
+        match self.accounts.iter().position(|x| x.name == name) {
+            Some(index) => Ok(self.accounts.remove(index)),
+            None => Err(KeystoreError::NotFound(name.to_string())),
+        }
+    }
+
+    /// Write keystore to the file.
+    ///
+    /// Keystore is written to a temporary file first, which is then
+    /// renamed over the keystore file. On unix, written file is only
+    /// readable and writable by the owner, even if keystore file
+    /// existed before with different permissions.
+    pub fn save(&self) -> Result<(), KeystoreError> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let tmp_path = self.path.with_extension("json.tmp");
+        // leftover from the interrupted save, which may have any permissions.
+        if tmp_path.exists() {
+            fs::remove_file(&tmp_path)?;
+        }
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options.open(&tmp_path)?;
+        file.write_all(&serde_json::to_vec_pretty(&self.accounts)?)?;
+        file.sync_all()?;
+        drop(file);
+
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lib::signer::test_utils::{EDSK, EDPK};
+    use super::*;
+
+    #[test]
+    fn test_keystore_save_and_open() {
+        let path = std::env::temp_dir()
+            .join(format!("tezedge-client-keystore-{}", std::process::id()))
+            .join(KEYSTORE_FILE);
+
+        let private_key = PrivateKey::from_base58check(EDSK).unwrap();
+        let account = KeystoreAccount {
+            name: "alice".to_string(),
+            public_key: PublicKey::from_base58check(EDPK).unwrap(),
+            secret_key: StoredSecretKey::Unencrypted(private_key),
+        };
+        let address = account.address().to_base58check();
+
+        let mut keystore = Keystore::open(&path).unwrap();
+        assert!(keystore.accounts().is_empty());
+        keystore.add(account.clone(), false).unwrap();
+        assert!(matches!(
+            keystore.add(account, false),
+            Err(KeystoreError::AlreadyExists(_)),
+        ));
+        keystore.save().unwrap();
+
+        let mut keystore = Keystore::open(&path).unwrap();
+        assert_eq!(keystore.accounts().len(), 1);
+        assert_eq!(keystore.find("alice").unwrap().address().to_base58check(), address);
+        assert_eq!(keystore.find(&address).unwrap().name, "alice");
+        assert!(keystore.find("bob").is_none());
+
+        keystore.remove("alice").unwrap();
+        assert!(matches!(keystore.remove("alice"), Err(KeystoreError::NotFound(_))));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            // permissions of already existing keystore are restricted too.
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+            keystore.save().unwrap();
+            assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+            assert!(!path.with_extension("json.tmp").exists());
+        }
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_stored_secret_key_parse() {
+        let key = EDSK;
+        for encoded in [key.to_string(), format!("unencrypted:{}", key)].iter() {
+            let parsed = StoredSecretKey::parse(encoded).unwrap();
+            assert!(!parsed.is_encrypted());
+            assert_eq!(parsed.decrypt("").unwrap().to_base58check(), key);
+        }
+
+        let encrypted = EncryptedPrivateKey::encrypt(
+            &PrivateKey::from_base58check(key).unwrap(),
+            "password",
+        );
+        let parsed = StoredSecretKey::parse(
+            &format!("encrypted:{}", encrypted.to_base58check()),
+        ).unwrap();
+        assert!(parsed.is_encrypted());
+        assert_eq!(parsed.decrypt("password").unwrap().to_base58check(), key);
+    }
+}
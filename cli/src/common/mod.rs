@@ -10,4 +10,13 @@ pub use estimate_gas_consumption::*;
 mod estimate_operation_fees;
 pub use estimate_operation_fees::*;
 
+mod password_input;
+pub use password_input::*;
+
+mod keystore;
+pub use keystore::*;
+
+mod offline_operation;
+pub use offline_operation::*;
+
 pub mod operation_command;
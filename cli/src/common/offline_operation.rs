@@ -0,0 +1,275 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use serde::{Serialize, Deserialize};
+
+use lib::{
+    ImplicitAddress, Signature, NewOperationGroup, NewOperationWithKind,
+    Forge, Unforge, UnforgedOperationGroup, UnforgeError, SIGNATURE_LEN,
+};
+use lib::crypto::{hex, ToBase58Check};
+use lib::signer::OperationSignatureInfo;
+
+/// Identifies the file as an operation exported by `prepare` or `sign`.
+pub const OFFLINE_OPERATION_FORMAT: &str = "tezedge-client/operation/v1";
+
+#[derive(thiserror::Error, Debug)]
+pub enum OfflineOperationError {
+    #[error("failed to read/write operation file: {0}")]
+    IO(#[from] std::io::Error),
+    #[error("invalid operation file: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("unsupported operation file format: {0}")]
+    UnsupportedFormat(String),
+    #[error("operation file doesn't contain valid hex")]
+    InvalidHex,
+    #[error("failed to decode operation: {0}")]
+    Unforge(#[from] UnforgeError),
+    #[error("invalid signature: {0}")]
+    InvalidSignature(#[from] lib::FromPrefixedBase58CheckError),
+    #[error("operation isn't signed. Sign it using `sign` command first")]
+    NotSigned,
+}
+
+/// Format of the exported operation file.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum OfflineOperationFileFormat {
+    /// Self describing json, containing both human readable operations
+    /// and forged bytes.
+    Json,
+    /// Hex encoded forged bytes (followed by the signature, if signed).
+    Hex,
+}
+
+impl FromStr for OfflineOperationFileFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "hex" => Ok(Self::Hex),
+            _ => Err(format!("unknown format: {}. Expected \"json\" or \"hex\"", s)),
+        }
+    }
+}
+
+/// Operation group, prepared on the online machine, signed on the
+/// offline one and then broadcasted from the online one again.
+///
+/// Only `forged` bytes are signed and broadcasted, `contents` are
+/// there for the review.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OfflineOperation {
+    pub format: String,
+    pub branch: String,
+    /// Protocol, for which operation was prepared.
+    pub protocol: String,
+    #[serde(default)]
+    pub contents: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub total_fee: u64,
+    /// Hex encoded forged operation group, without the signature.
+    pub forged: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation_hash: Option<String>,
+}
+
+impl OfflineOperation {
+    pub fn new(operation_group: &NewOperationGroup) -> Self {
+        Self {
+            format: OFFLINE_OPERATION_FORMAT.to_string(),
+            branch: operation_group.branch.to_base58check(),
+            protocol: operation_group.next_protocol_hash.clone(),
+            contents: operation_group.operations.iter()
+                .cloned()
+                .map(NewOperationWithKind::from)
+                .filter_map(|op| serde_json::to_value(op).ok())
+                .collect(),
+            total_fee: operation_group.total_fee(),
+            forged: hex::encode(operation_group.forge()),
+            signature: None,
+            operation_hash: None,
+        }
+    }
+
+    pub fn with_signature(mut self, sig_info: &OperationSignatureInfo) -> Self {
+        self.signature = Some(sig_info.signature.clone());
+        self.operation_hash = Some(sig_info.operation_hash.clone());
+        self
+    }
+
+    fn from_json(contents: &str) -> Result<Self, OfflineOperationError> {
+        let operation: Self = serde_json::from_str(contents)?;
+
+        if operation.format != OFFLINE_OPERATION_FORMAT {
+            return Err(OfflineOperationError::UnsupportedFormat(operation.format));
+        }
+        Ok(operation)
+    }
+
+    /// Create from the hex encoded forged operation group, followed
+    /// by the signature if `is_signed`.
+    fn from_hex(contents: &str, is_signed: bool) -> Result<Self, OfflineOperationError> {
+        let bytes = hex::decode(contents.trim())
+            .or(Err(OfflineOperationError::InvalidHex))?;
+
+        let group = if is_signed {
+            UnforgedOperationGroup::unforge_signed(&bytes)?
+        } else {
+            UnforgedOperationGroup::unforge(&bytes)?
+        };
+        let signature = group.signature.map(|sig| {
+            signature_for_source(group.operations.iter().find_map(|op| op.get_source()), sig)
+        });
+
+        let mut operation = Self::new(&NewOperationGroup {
+            branch: group.branch,
+            next_protocol_hash: String::new(),
+            operations: group.operations,
+        });
+        if let Some(signature) = signature {
+            let sig_info = OperationSignatureInfo::new(
+                &bytes[..(bytes.len() - SIGNATURE_LEN)],
+                &signature,
+            );
+            operation = operation.with_signature(&sig_info);
+        }
+        Ok(operation)
+    }
+
+    /// Read operation from the file, exported as json or hex.
+    ///
+    /// Hex file is expected to contain signature if `is_signed`.
+    pub fn read<P>(path: P, is_signed: bool) -> Result<Self, OfflineOperationError>
+        where P: AsRef<Path>,
+    {
+        let contents = fs::read_to_string(path)?;
+
+        if contents.trim_start().starts_with('{') {
+            Self::from_json(&contents)
+        } else {
+            Self::from_hex(&contents, is_signed)
+        }
+    }
+
+    pub fn write<P>(
+        &self,
+        path: P,
+        format: OfflineOperationFileFormat,
+    ) -> Result<(), OfflineOperationError>
+        where P: AsRef<Path>,
+    {
+        let contents = match format {
+            OfflineOperationFileFormat::Json => serde_json::to_string_pretty(self)?,
+            OfflineOperationFileFormat::Hex => match self.signature.as_ref() {
+                Some(signature) => {
+                    let signature = Signature::from_base58check(signature)?;
+                    format!("{}{}", self.forged, hex::encode(signature.as_ref()))
+                }
+                None => self.forged.clone(),
+            },
+        };
+
+        Ok(fs::write(path, contents + "\n")?)
+    }
+
+    pub fn forged_bytes(&self) -> Result<Vec<u8>, OfflineOperationError> {
+        hex::decode(&self.forged).or(Err(OfflineOperationError::InvalidHex))
+    }
+
+    /// Decode operation group from the forged bytes.
+    ///
+    /// Operations are always decoded from the forged bytes, since
+    /// those are the ones that are signed, rather than from `contents`.
+    pub fn operation_group(&self) -> Result<NewOperationGroup, OfflineOperationError> {
+        let group = UnforgedOperationGroup::unforge(&self.forged_bytes()?)?;
+
+        // counters are kept as they are, since forged bytes are signed.
+        Ok(NewOperationGroup {
+            branch: group.branch,
+            next_protocol_hash: self.protocol.clone(),
+            operations: group.operations,
+        })
+    }
+
+    /// Signature info for broadcasting the signed operation.
+    pub fn signature_info(&self) -> Result<OperationSignatureInfo, OfflineOperationError> {
+        let signature = self.signature.as_ref()
+            .ok_or(OfflineOperationError::NotSigned)?;
+
+        Ok(OperationSignatureInfo::new(
+            &self.forged_bytes()?,
+            &Signature::from_base58check(signature)?,
+        ))
+    }
+}
+
+/// Hex file only contains signature bytes, so use the curve of the
+/// operation source to encode it.
+fn signature_for_source(
+    source: Option<&ImplicitAddress>,
+    signature: [u8; SIGNATURE_LEN],
+) -> Signature {
+    match source {
+        Some(ImplicitAddress::tz1(_)) => Signature::edsig(signature),
+        Some(ImplicitAddress::tz2(_)) => Signature::spsig1(signature),
+        Some(ImplicitAddress::tz3(_)) => Signature::p2sig(signature),
+        None => Signature::sig(signature),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lib::{BlockHash, NewTransactionOperation};
+    use lib::signer::test_utils::{local_signer, EDSK};
+    use super::*;
+
+    #[test]
+    fn test_offline_operation_roundtrip() {
+        let signer = local_signer(EDSK);
+        let source = signer.public_key().hash();
+        let group = NewOperationGroup::new(BlockHash::from([1; 32]), "proto".to_string())
+            .with_operation(NewTransactionOperation {
+                source: source.clone(),
+                destination: source.into(),
+                amount: 1000,
+                fee: 500,
+                counter: 7,
+                gas_limit: 10300,
+                storage_limit: 257,
+                parameters: None,
+            });
+
+        let prepared = OfflineOperation::new(&group);
+        assert_eq!(prepared.total_fee, 500);
+        assert_eq!(prepared.contents[0]["kind"], "transaction");
+        assert!(matches!(prepared.signature_info(), Err(OfflineOperationError::NotSigned)));
+
+        let json = serde_json::to_string(&prepared).unwrap();
+        let prepared = OfflineOperation::from_json(&json).unwrap();
+        assert_eq!(prepared.operation_group().unwrap().operations, group.operations);
+
+        let sig_info = signer.sign_forged_operation_bytes(&prepared.forged_bytes().unwrap());
+        let signed = prepared.with_signature(&sig_info);
+        let signed_hex = format!(
+            "{}{}",
+            signed.forged,
+            hex::encode(Signature::from_base58check(&sig_info.signature).unwrap().as_ref()),
+        );
+
+        let from_hex = OfflineOperation::from_hex(&signed_hex, true).unwrap();
+        assert_eq!(from_hex.signature, signed.signature);
+        assert_eq!(from_hex.signature_info().unwrap().operation_hash, sig_info.operation_hash);
+        assert_eq!(
+            from_hex.signature_info().unwrap().operation_with_signature,
+            sig_info.operation_with_signature,
+        );
+
+        assert!(matches!(
+            OfflineOperation::from_json(&json.replace("/v1", "/v2")),
+            Err(OfflineOperationError::UnsupportedFormat(_)),
+        ));
+    }
+}
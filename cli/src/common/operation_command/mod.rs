@@ -1,27 +1,27 @@
+use std::convert::TryInto;
 use std::time::Duration;
 use std::thread;
 use console::{style, Term};
 
 use lib::{
-    Forge, Address, ImplicitAddress, ImplicitOrOriginatedWithManager,
+    Forge, Address, ImplicitAddress, OriginatedAddress, ImplicitOrOriginatedWithManager,
     NewOperationGroup, NewOperation, NewTransactionOperation, NewRevealOperation,
-    NewTransactionOperationBuilder, NewDelegationOperationBuilder,
-    KeyDerivationPath, PrivateKey, PublicKey,
+    NewTransactionOperationBuilder, NewDelegationOperationBuilder, NewTransactionParameters,
+    NewProposalsOperation, NewBallotOperation, Ballot, ProtocolHash,
+    NewOriginationOperation, NewOriginationScript,
+    PublicKey, Signature, SIGNATURE_LEN,
 };
+use lib::crypto::{hex, ToBase58Check};
 
-use lib::signer::{LocalSigner, OperationSignatureInfo};
+use lib::signer::{Signer, OperationSignatureInfo, OPERATION_WATERMARK, verify_signature};
 use lib::explorer_api::TzStats;
-use lib::trezor_api::{Trezor, TezosSignTx};
-use lib::ledger_api::Ledger;
 use lib::api::*;
 
 use cli_spinner::SpinnerBuilder;
-use crate::trezor::trezor_execute;
-use crate::ledger::ledger_execute;
 use crate::common::{
     exit_with_error,
     yes_no_custom_amount_input, YesNoCustomAmount,
-    estimate_gas_consumption, estimate_operation_fees,
+    estimate_gas_consumption, estimate_operation_fees, split_fee,
 };
 
 mod raw_operation_command;
@@ -30,13 +30,128 @@ pub use raw_operation_command::*;
 mod operation_command_api;
 pub use operation_command_api::*;
 
-/// Exit and print error that no wallet type(trezor, ledger, local) was selected.
-fn exit_with_error_no_wallet_type_selected() -> ! {
-    exit_with_error(format!(
-        "{}\n{}",
-        "trezor, ledger, or local wallet needs to be used to create this operation. Neither selected.",
-        "Technically this shouldn't be possible!",
-    ))
+#[derive(thiserror::Error, Debug)]
+pub enum SignedOperationMismatchError {
+    #[error("signed operation is not a valid hex")]
+    InvalidHex,
+    #[error("signed operation doesn't match the operation that was built")]
+    Mismatch,
+    #[error("operation signature is not valid for the public key: {0}")]
+    InvalidSignature(String),
+}
+
+/// Make sure that signed bytes are exactly the operation that we built,
+/// so that forged payload can't be swapped by remote forging or signing,
+/// and that the signature was made by the given `public_key`.
+pub fn verify_signed_operation(
+    operation_group: &NewOperationGroup,
+    public_key: &PublicKey,
+    sig_info: &OperationSignatureInfo,
+) -> Result<(), SignedOperationMismatchError>
+{
+    let signed_bytes = hex::decode(&sig_info.operation_with_signature)
+        .or(Err(SignedOperationMismatchError::InvalidHex))?;
+    if signed_bytes.len() < SIGNATURE_LEN {
+        return Err(SignedOperationMismatchError::Mismatch);
+    }
+    let (operation_bytes, signature_bytes) = signed_bytes
+        .split_at(signed_bytes.len() - SIGNATURE_LEN);
+
+    if operation_bytes != operation_group.forge().as_ref() {
+        return Err(SignedOperationMismatchError::Mismatch);
+    }
+
+    let signature = Signature::sig(signature_bytes.try_into().unwrap());
+    let watermarked_bytes = [&[OPERATION_WATERMARK], operation_bytes].concat();
+    if !verify_signature(public_key, &watermarked_bytes, &signature) {
+        return Err(SignedOperationMismatchError::InvalidSignature(
+            public_key.to_base58check(),
+        ));
+    }
+    Ok(())
+}
+
+fn confirm_operation(
+    api: &mut dyn OperationCommandApi,
+    operation_hash: &str,
+) -> Result<(), Error>
+{
+    let spinner = SpinnerBuilder::new()
+        .with_prefix(style("[4/4]").bold().dim())
+        .with_text("waiting for confirmation")
+        .start();
+
+    for _ in 0..10 {
+        thread::sleep(Duration::from_secs(2));
+
+        let status = api.get_pending_operation_status(operation_hash)?;
+        match status {
+            PendingOperationStatus::Refused => {
+                spinner.finish_fail("operation_refused");
+                return Ok(());
+            }
+            PendingOperationStatus::Applied => {
+            }
+            PendingOperationStatus::Finished => {
+                break;
+            }
+        }
+    }
+
+    spinner.finish_succeed("operation confirmed");
+
+    Ok(())
+}
+
+/// Preapply and inject signed operation, then wait for confirmation.
+///
+/// Outputs operation hash to stdout, if it isn't a terminal.
+pub fn inject_operation(
+    api: &mut dyn OperationCommandApi,
+    operation_group: &NewOperationGroup,
+    sig_info: &OperationSignatureInfo,
+) -> Result<(), Error>
+{
+    let spinner = SpinnerBuilder::new()
+        .with_prefix(style("[3/4]").bold().dim())
+        .with_text("applying and injecting the operation")
+        .start();
+
+    api.preapply_operations(operation_group, &sig_info.signature)?;
+
+    api.inject_operations(&sig_info.operation_with_signature)?;
+
+    spinner.finish_succeed("applied and injected the operation");
+
+    confirm_operation(api, &sig_info.operation_hash)?;
+
+    let version = api.get_version_info()?;
+    let network = version.get_network();
+
+    match TzStats::new(network) {
+        Ok(tzstats) => {
+            eprintln!(
+                "\n  {}View operation at: {}/{}",
+                emojies::FINGER_POINTER_RIGHT,
+                style(tzstats.operation_link_prefix()).cyan(),
+                style(&sig_info.operation_hash).cyan(),
+            );
+        }
+        Err(err) => {
+            eprintln!(
+                "\n{} {}",
+                style("[WARN]").yellow(),
+                err,
+            );
+            eprintln!("\nOperation hash: {}", style(&sig_info.operation_hash).green());
+        }
+    };
+
+    if !console::user_attended() {
+        println!("{}", &sig_info.operation_hash);
+    }
+
+    Ok(())
 }
 
 pub struct OperationOptions {
@@ -59,34 +174,24 @@ impl Default for OperationCommandState {
     }
 }
 
-pub struct TrezorState {
-    pub trezor: Trezor,
-    pub key_path: KeyDerivationPath,
-}
-
-pub struct LedgerState {
-    pub ledger: Ledger,
-    pub key_path: KeyDerivationPath,
-}
+/// Gas limit used for simulating smart contract calls (hard gas limit
+/// per operation), before it's replaced with the estimated one.
+const CONTRACT_CALL_GAS_LIMIT: u64 = 1_040_000;
 
-pub struct LocalWalletState {
-    pub public_key: PublicKey,
-    pub private_key: PrivateKey,
-}
+/// Storage limit used for simulating originations (hard storage
+/// limit per operation), before it's replaced with the estimated one.
+const ORIGINATION_STORAGE_LIMIT: u64 = 60_000;
 
-impl LocalWalletState {
-    pub fn signer(&self) -> LocalSigner {
-        LocalSigner::new(
-            self.public_key.clone(),
-            self.private_key.clone(),
-        )
-    }
-}
+/// Bytes burned for allocating the originated contract, in addition
+/// to the size of its script and storage.
+const ORIGINATION_SIZE: u64 = 257;
 
 #[derive(PartialEq, Debug, Clone)]
 enum OperationType {
     Transaction { to: Address, amount: u64 },
     Delegation { to: Option<ImplicitAddress> },
+    ContractCall { contract: OriginatedAddress, parameters: NewTransactionParameters },
+    Origination { balance: u64, script: NewOriginationScript },
 }
 
 pub struct OperationCommand {
@@ -96,12 +201,9 @@ pub struct OperationCommand {
 
     pub api: Box<dyn OperationCommandApi>,
     pub state: OperationCommandState,
-    /// If `Some`, Trezor will be used to execute an operation.
-    pub trezor_state: Option<TrezorState>,
-    /// If `Some`, Ledger will be used to execute an operation.
-    pub ledger_state: Option<LedgerState>,
-    /// If `Some`, Local wallet will be used to execute an operation.
-    pub local_state: Option<LocalWalletState>,
+    /// Signer used to sign an operation: Trezor, Ledger, local
+    /// wallet, remote signer or any other [Signer] implementation.
+    pub signer: Box<dyn Signer>,
 }
 
 impl OperationCommand {
@@ -157,21 +259,7 @@ impl OperationCommand {
             return Ok(None);
         }
 
-        let public_key = if let Some(trezor_state) = self.trezor_state.as_mut() {
-            PublicKey::from_base58check(
-                &trezor_execute(
-                    trezor_state.trezor.get_public_key(&trezor_state.key_path)
-                ),
-            )?
-        } else if let Some(ledger_state) = self.ledger_state.as_mut() {
-            ledger_execute(
-                ledger_state.ledger.get_public_key(&ledger_state.key_path, false)
-            )
-        } else if let Some(local_state) = self.local_state.as_ref() {
-            local_state.public_key.clone()
-        } else {
-            exit_with_error_no_wallet_type_selected()
-        };
+        let public_key = self.signer.public_key()?;
 
         Ok(Some(NewRevealOperation {
             source,
@@ -217,23 +305,59 @@ impl OperationCommand {
         }.build())
     }
 
-    fn build_operation_group(
+    fn build_contract_call(
         &mut self,
-        op_type: OperationType,
-    ) -> Result<NewOperationGroup, Error>
+        source: ImplicitOrOriginatedWithManager,
+        contract: OriginatedAddress,
+        parameters: NewTransactionParameters,
+    ) -> Result<NewTransactionOperation, Error>
     {
-        let source = match self.from.clone() {
-            Address::Implicit(source) => source.into(),
-            Address::Originated(addr) => {
-                let manager = match self.api.get_contract_manager_address(&addr.clone().into()) {
-                    Ok(x) => x.into(),
-                    // TODO: more instructive error
-                    Err(_) => exit_with_error("transfering funds from contract originated after Babylon protocol change, isn't supported.")
-                };
-                addr.with_manager(manager).into()
+        let source = match source {
+            ImplicitOrOriginatedWithManager::Implicit(source) => source,
+            ImplicitOrOriginatedWithManager::OriginatedWithManager(_) => {
+                return Err("smart contracts can only be called from an implicit account.".into());
+            }
+        };
+
+        Ok(NewTransactionOperation {
+            source,
+            destination: contract.into(),
+            amount: 0,
+            counter: self.get_counter()?,
+            fee: self.fee.unwrap_or(0),
+            gas_limit: CONTRACT_CALL_GAS_LIMIT,
+            storage_limit: 257,
+            parameters: Some(parameters),
+        })
+    }
+
+    fn build_origination(
+        &mut self,
+        source: ImplicitOrOriginatedWithManager,
+        balance: u64,
+        script: NewOriginationScript,
+    ) -> Result<NewOriginationOperation, Error>
+    {
+        let source = match source {
+            ImplicitOrOriginatedWithManager::Implicit(source) => source,
+            ImplicitOrOriginatedWithManager::OriginatedWithManager(_) => {
+                return Err("contracts can only be originated from an implicit account.".into());
             }
         };
 
+        Ok(NewOriginationOperation {
+            source,
+            balance,
+            counter: self.get_counter()?,
+            fee: self.fee.unwrap_or(0),
+            gas_limit: CONTRACT_CALL_GAS_LIMIT,
+            storage_limit: ORIGINATION_STORAGE_LIMIT,
+            script,
+        })
+    }
+
+    /// Create empty operation group for the current head block.
+    fn fetch_operation_group(&mut self) -> Result<NewOperationGroup, Error> {
         let spinner = SpinnerBuilder::new()
             .with_prefix(style("[1/4]").bold().dim())
             .with_text("fetching necessary data from the node")
@@ -243,26 +367,55 @@ impl OperationCommand {
         let protocol_info = self.api.get_protocol_info()?;
         let head_block_hash = self.api.get_head_block_hash()?;
 
-        let mut operation_group = NewOperationGroup::new(
-            head_block_hash.clone(),
+        spinner.finish_succeed("fetched necessary data from the node");
+
+        Ok(NewOperationGroup::new(
+            head_block_hash,
             protocol_info.next_protocol_hash,
-        );
+        ))
+    }
 
-        spinner.finish_succeed("fetched necessary data from the node");
+    fn build_operation_group(
+        &mut self,
+        op_types: Vec<OperationType>,
+    ) -> Result<NewOperationGroup, Error>
+    {
+        let source: ImplicitOrOriginatedWithManager = match self.from.clone() {
+            Address::Implicit(source) => source.into(),
+            Address::Originated(addr) => {
+                let manager = match self.api.get_contract_manager_address(&addr.clone().into()) {
+                    Ok(x) => x.into(),
+                    // TODO: more instructive error
+                    Err(_) => exit_with_error("transfering funds from contract originated after Babylon protocol change, isn't supported.")
+                };
+                addr.with_manager(manager).into()
+            }
+        };
+
+        let mut operation_group = self.fetch_operation_group()?;
 
         if let Some(reveal_op) = self.build_reveal()? {
-            operation_group = operation_group.with_reveal(reveal_op);
+            operation_group = operation_group.with_operation(reveal_op);
         }
 
+        for op_type in op_types {
+            operation_group = operation_group.with_operation(match op_type {
+                OperationType::Transaction { to, amount } => {
+                    self.build_transaction(source.clone(), to, amount)?.into()
+                }
+                OperationType::Delegation { to } => {
+                    self.build_delegation(source.clone(), to)?
+                }
+                OperationType::ContractCall { contract, parameters } => {
+                    self.build_contract_call(source.clone(), contract, parameters)?.into()
+                }
+                OperationType::Origination { balance, script } => {
+                    self.build_origination(source.clone(), balance, script)?.into()
+                }
+            });
+        }
 
-        Ok(operation_group.with_operation(match op_type {
-            OperationType::Transaction { to, amount } => {
-                self.build_transaction(source, to, amount)?.into()
-            }
-            OperationType::Delegation { to } => {
-                self.build_delegation(source, to)?
-            }
-        }))
+        Ok(operation_group)
     }
 
     fn estimate_and_set_fees(
@@ -275,124 +428,149 @@ impl OperationCommand {
             &mut *self.api,
         )?;
 
+        let is_origination = |op: &NewOperation| matches!(op, NewOperation::Origination(_));
+
+        // storage of the originated contracts is burned, so only pay
+        // for as much as the simulation used.
+        let mut burned_storage = 0;
+        for (op, paid_storage) in operation_group.operations.iter_mut()
+            .zip(gas_consumption.paid_storage.iter())
+            .filter(|(op, _)| is_origination(op))
+        {
+            op.set_storage_limit(paid_storage + ORIGINATION_SIZE);
+            burned_storage += op.get_storage_limit();
+        }
+
         let fees = estimate_operation_fees(
             &operation_group,
             &gas_consumption,
         );
 
-        let tx_gas_fee = gas_consumption.transaction
-            .and_then(|gas| fees.transaction.map(|fee| (gas, fee)));
-
-        let del_gas_fee = gas_consumption.delegation
-            .and_then(|gas| fees.delegation.map(|fee| (gas, fee)));
-
-        let op_fee = match (tx_gas_fee.clone(), del_gas_fee.clone()) {
-            // both should always be `Some`, otherwise
-            // `estimate_gas_consumption` would fail.
-            (Some((estimated_gas, estimated_fee)), None)
-                | (None, Some((estimated_gas, estimated_fee)))
-            => {
-                eprintln!();
-
-                if let Some(fee) = manual_fee.filter(|fee| *fee < estimated_fee) {
-                    eprintln!(
-                        "{} Entered fee({} µꜩ ) is lower than the estimated minimum fee ({} µꜩ )!\n",
-                        style("[WARN]").yellow(),
-                        style(fee).red(),
-                        style(estimated_fee).green(),
-                    );
-                }
+        for (op, estimated_gas) in operation_group.operations.iter_mut()
+            .zip(gas_consumption.operations.iter())
+        {
+            op.set_gas_limit(*estimated_gas);
+        }
 
-                let default_input = manual_fee.map(|_| YesNoCustomAmount::No)
-                        .unwrap_or(YesNoCustomAmount::Yes);
-                let input = if self.options.no_prompt {
-                    default_input
-                } else {
-                    yes_no_custom_amount_input(
-                        format!(
-                            "Would you like to use estimated fee({} µꜩ ),\n  or continue with specified fee({} µꜩ )\n",
-                            style(estimated_fee).green(),
-                            style(manual_fee.unwrap_or(0)).yellow(),
-                        ),
-                        default_input,
-                    )
-                };
+        let is_reveal = |op: &NewOperation| matches!(op, NewOperation::Reveal(_));
 
-                let fee = match input {
-                    YesNoCustomAmount::Custom(custom_fee) => custom_fee,
-                    YesNoCustomAmount::Yes => estimated_fee,
-                    YesNoCustomAmount::No => manual_fee.unwrap_or(0),
-                };
+        // estimated fee for all operations except reveal.
+        let estimated_fee = operation_group.operations.iter()
+            .zip(fees.operations.iter())
+            .filter(|(op, _)| !is_reveal(op))
+            .map(|(_, fee)| fee)
+            .sum::<u64>();
 
-                if let Some(tx_op) = tx_gas_fee.and(operation_group.transaction.as_mut()) {
-                    tx_op.gas_limit = estimated_gas;
-                    tx_op.fee = fee;
-                } else if let Some(del_op) = del_gas_fee.and(operation_group.delegation.as_mut()) {
-                    del_op.gas_limit = estimated_gas;
-                    del_op.fee = fee;
-                }
+        eprintln!();
 
-                fee
-            }
-            // TODO: this should be impossible. Show error if it happens.
-            _ => 0,
+        if burned_storage > 0 {
+            let cost_per_byte = self.api.get_constants()?.cost_per_byte;
+            eprintln!(
+                "{} Up to {} µꜩ  will be burned for {} bytes of the storage, in addition to the fee.\n",
+                style("[INFO]").cyan(),
+                style(burned_storage * cost_per_byte).bold(),
+                burned_storage,
+            );
+        }
+
+        if let Some(fee) = manual_fee.filter(|fee| *fee < estimated_fee) {
+            eprintln!(
+                "{} Entered fee({} µꜩ ) is lower than the estimated minimum fee ({} µꜩ )!\n",
+                style("[WARN]").yellow(),
+                style(fee).red(),
+                style(estimated_fee).green(),
+            );
+        }
+
+        let default_input = manual_fee.map(|_| YesNoCustomAmount::No)
+                .unwrap_or(YesNoCustomAmount::Yes);
+        let input = if self.options.no_prompt {
+            default_input
+        } else {
+            yes_no_custom_amount_input(
+                format!(
+                    "Would you like to use estimated fee({} µꜩ ),\n  or continue with specified fee({} µꜩ )\n",
+                    style(estimated_fee).green(),
+                    style(manual_fee.unwrap_or(0)).yellow(),
+                ),
+                default_input,
+            )
         };
 
-        match (operation_group.reveal.as_mut(), gas_consumption.reveal, fees.reveal) {
-            (Some(reveal_op), Some(estimated_gas), Some(estimated_fee)) => {
-                reveal_op.gas_limit = estimated_gas;
+        let op_fee = match input {
+            YesNoCustomAmount::Custom(custom_fee) => custom_fee,
+            YesNoCustomAmount::Yes => estimated_fee,
+            YesNoCustomAmount::No => manual_fee.unwrap_or(0),
+        };
 
+        let estimated_fees = operation_group.operations.iter()
+            .zip(fees.operations.iter())
+            .filter(|(op, _)| !is_reveal(op))
+            .map(|(_, fee)| *fee)
+            .collect::<Vec<_>>();
+
+        // fee is split between operations in proportion to their
+        // estimated fees, which for the estimated fee gives estimates as is.
+        for (op, fee) in operation_group.operations.iter_mut()
+            .filter(|op| !is_reveal(op))
+            .zip(split_fee(op_fee, &estimated_fees))
+        {
+            op.set_fee(fee);
+        }
+
+        let reveal_fee = operation_group.operations.iter()
+            .zip(fees.operations.iter())
+            .find(|(op, _)| is_reveal(op))
+            .map(|(_, fee)| *fee);
+
+        if let Some(estimated_fee) = reveal_fee {
+            eprintln!(
+                "\n{} Account from which you are sending from, hasn't yet been {}!",
+                style("[WARN]").yellow(),
+                style("revealed").bold(),
+            );
+            eprintln!(
+                "\n       Additional fee (estimated {} µꜩ ) is required to reveal the account.",
+                style(estimated_fee).green(),
+            );
+
+            // whether or not entered fee is greater or equal to the total estimated fee.
+            let is_fee_larger = op_fee >= fees.total();
+
+            if is_fee_larger {
                 eprintln!(
-                    "\n{} Account from which you are sending from, hasn't yet been {}!",
-                    style("[WARN]").yellow(),
-                    style("revealed").bold(),
-                );
-                eprintln!(
-                    "\n       Additional fee (estimated {} µꜩ ) is required to reveal the account.",
-                    style(estimated_fee).green(),
+                    "\n       {} current fee({} µꜩ ) should be sufficient.",
+                    style("HOWEVER").bold(),
+                    manual_fee.unwrap_or(0),
                 );
+            }
 
-                // whether or not entered fee is greater or equal to the total estimated fee.
-                let is_fee_larger =
-                    operation_group.transaction.as_ref().map(|op| op.fee)
-                    .or(operation_group.delegation.as_ref().map(|op| op.fee))
-                    .filter(|fee| *fee >= fees.total())
-                    .is_some();
+            let default_input = manual_fee.map(|_| YesNoCustomAmount::No)
+                .unwrap_or(YesNoCustomAmount::Yes);
+
+            let input = if self.options.no_prompt {
+                default_input
+            } else {
+                yes_no_custom_amount_input(
+                    format!(
+                        "Would you like to add an estimated fee({} µꜩ ) resulting in total: {} µꜩ ",
+                        style(estimated_fee).bold(),
+                        style(estimated_fee + op_fee).green(),
+                    ),
+                    default_input,
+                )
+            };
 
+            let reveal_op = operation_group.operations.iter_mut()
+                .find(|op| is_reveal(op));
 
-                if is_fee_larger {
-                    eprintln!(
-                        "\n       {} current fee({} µꜩ ) should be sufficient.",
-                        style("HOWEVER").bold(),
-                        manual_fee.unwrap_or(0),
-                    );
+            match (reveal_op, input) {
+                (Some(reveal_op), YesNoCustomAmount::Custom(custom_fee)) => {
+                    reveal_op.set_fee(custom_fee)
                 }
-
-                let default_input = manual_fee.map(|_| YesNoCustomAmount::No)
-                    .unwrap_or(YesNoCustomAmount::Yes);
-
-                let input = if self.options.no_prompt {
-                    default_input
-                } else {
-                    yes_no_custom_amount_input(
-                        format!(
-                            "Would you like to add an estimated fee({} µꜩ ) resulting in total: {} µꜩ ",
-                            style(estimated_fee).bold(),
-                            style(estimated_fee + op_fee).green(),
-                        ),
-                        default_input,
-                    )
-                };
-
-                match input {
-                    YesNoCustomAmount::Custom(custom_fee) => {
-                        reveal_op.fee = custom_fee
-                    }
-                    YesNoCustomAmount::Yes => reveal_op.fee = estimated_fee,
-                    YesNoCustomAmount::No => {}
-                };
-            },
-            _ => {}
+                (Some(reveal_op), YesNoCustomAmount::Yes) => reveal_op.set_fee(estimated_fee),
+                _ => {}
+            };
         }
 
         Ok(())
@@ -403,44 +581,19 @@ impl OperationCommand {
         operation_group: &NewOperationGroup,
     ) -> Result<OperationSignatureInfo, Error>
     {
-        if let Some(trezor_state) = self.trezor_state.as_mut() {
-            eprintln!(
-                "{} -   {}",
-                style("[2/4]").bold().dim(),
-                "forging and signing operation using Trezor",
-            );
-            let mut tx: TezosSignTx = operation_group.clone().into();
-            tx.set_address_n(trezor_state.key_path.clone().take());
-            let sig_info = OperationSignatureInfo::from(
-                trezor_execute(trezor_state.trezor.sign_tx(tx))
-            );
-
-            Term::stderr().clear_last_lines(1)?;
-            eprintln!(
-                "{} {} {}",
-                style("[2/4]").bold().dim().green(),
-                emojies::TICK,
-                "operation forged and signed",
-            );
+        let forged_operation = operation_group.forge();
 
-            Ok(sig_info)
-        } else if let Some(ledger_state) = self.ledger_state.as_mut() {
+        let sig_info = if let Some(message) = self.signer.confirmation_message() {
             eprintln!(
-                "{} -   {}\n",
+                "{} -   signing operation using {}. {}",
                 style("[2/4]").bold().dim(),
-                "signing operation using Ledger. Please confirm an operation on your ledger.",
+                self.signer.name(),
+                message,
             );
 
-            eprintln!("please confirm an operation on Ledger once you see the dialog on the device.\n");
+            let sig_info = self.signer.sign_forged_operation(forged_operation.as_ref())?;
 
-            let sig_info = ledger_execute(
-                ledger_state.ledger.sign_tx(
-                    &ledger_state.key_path,
-                    operation_group.forge(),
-                )
-            );
-
-            Term::stderr().clear_last_lines(4)?;
+            Term::stderr().clear_last_lines(1)?;
             eprintln!(
                 "{} {} {}",
                 style("[2/4]").bold().dim().green(),
@@ -448,113 +601,214 @@ impl OperationCommand {
                 "operation signed",
             );
 
-            Ok(sig_info)
-        } else if let Some(state) = self.local_state.as_ref() {
+            sig_info
+        } else {
             let spinner = SpinnerBuilder::new()
                 .with_prefix(style("[2/4]").bold().dim())
-                .with_text("forging the operation and signing")
+                .with_text(format!("forging the operation and signing using {}", self.signer.name()))
                 .start();
-            let forged_operation = operation_group.forge();
 
-            let sig_info = state.signer().sign_forged_operation_bytes(
-                forged_operation.as_ref(),
-            );
+            let sig_info = self.signer.sign_forged_operation(forged_operation.as_ref())?;
 
             spinner.finish_succeed("operation forged and signed");
-            Ok(sig_info)
-        } else {
-            exit_with_error_no_wallet_type_selected()
-        }
-    }
+            sig_info
+        };
 
-    fn confirm_operation(&mut self, operation_hash: &str) -> Result<(), Error> {
-        let spinner = SpinnerBuilder::new()
-            .with_prefix(style("[4/4]").bold().dim())
-            .with_text("waiting for confirmation")
-            .start();
+        let public_key = self.signer.public_key()?;
+        verify_signed_operation(operation_group, &public_key, &sig_info)?;
+        Ok(sig_info)
+    }
 
-        for _ in 0..10 {
-            thread::sleep(Duration::from_secs(2));
+    /// Build operation group and estimate fees, without signing it.
+    fn prepare(&mut self, op_types: Vec<OperationType>) -> Result<NewOperationGroup, Error> {
+        let mut operation_group = self.build_operation_group(op_types)?;
+        self.estimate_and_set_fees(&mut operation_group)?;
+        Ok(operation_group)
+    }
 
-            let status = self.api.get_pending_operation_status(&operation_hash)?;
-            match status {
-                PendingOperationStatus::Refused => {
-                    spinner.finish_fail("operation_refused");
-                    return Ok(());
-                }
-                PendingOperationStatus::Applied => {
-                }
-                PendingOperationStatus::Finished => {
-                    break;
-                }
-            }
-        }
+    fn execute(&mut self, op_types: Vec<OperationType>) -> Result<(), Error> {
+        let operation_group = self.prepare(op_types)?;
+        self.sign_and_inject(operation_group)?;
+        Ok(())
+    }
 
-        spinner.finish_succeed("operation confirmed");
+    fn sign_and_inject(
+        &mut self,
+        operation_group: NewOperationGroup,
+    ) -> Result<OperationSignatureInfo, Error>
+    {
+        let sig_info = self.sign_operation(&operation_group)?;
+        inject_operation(&mut *self.api, &operation_group, &sig_info)?;
+        Ok(sig_info)
+    }
 
-        Ok(())
+    /// Prepare multiple transactions in a single operation group,
+    /// to be signed later (see [OperationCommand::batch_transfer]).
+    pub fn prepare_batch_transfer(
+        &mut self,
+        transfers: Vec<(Address, u64)>,
+    ) -> Result<NewOperationGroup, Error>
+    {
+        let op_types = transfers.into_iter()
+            .map(|(to, amount)| OperationType::Transaction { to, amount })
+            .collect();
+        self.prepare(op_types)
     }
 
-    fn execute(&mut self, op_type: OperationType) -> Result<(), Error> {
-        let mut operation_group = self.build_operation_group(op_type)?;
-        self.estimate_and_set_fees(&mut operation_group)?;
-        let OperationSignatureInfo {
-            operation_hash,
-            operation_with_signature,
-            signature,
-        } = self.sign_operation(&operation_group)?;
+    /// Prepare delegation, to be signed later (see [OperationCommand::delegate]).
+    pub fn prepare_delegation(
+        &mut self,
+        to: Option<ImplicitAddress>,
+    ) -> Result<NewOperationGroup, Error>
+    {
+        self.prepare(vec![OperationType::Delegation { to }])
+    }
 
-        let spinner = SpinnerBuilder::new()
-            .with_prefix(style("[3/4]").bold().dim())
-            .with_text("applying and injecting the operation")
-            .start();
+    /// Send multiple transactions in a single operation group.
+    pub fn batch_transfer(&mut self, transfers: Vec<(Address, u64)>) -> Result<(), Error> {
+        let op_types = transfers.into_iter()
+            .map(|(to, amount)| OperationType::Transaction { to, amount })
+            .collect();
+        self.execute(op_types)
+    }
 
-        self.api.preapply_operations(&operation_group, &signature)?;
+    pub fn delegate(&mut self, to: Option<ImplicitAddress>) -> Result<(), Error> {
+        let op_type = OperationType::Delegation { to };
+        self.execute(vec![op_type])
+    }
 
-        self.api.inject_operations(&operation_with_signature)?;
+    /// Call smart contract's entrypoint with the given parameters.
+    pub fn call_contract(
+        &mut self,
+        contract: OriginatedAddress,
+        parameters: NewTransactionParameters,
+    ) -> Result<(), Error>
+    {
+        self.execute(vec![OperationType::ContractCall { contract, parameters }])
+    }
 
-        spinner.finish_succeed("applied and injected the operation");
+    /// Originate smart contract with the given script and initial balance.
+    ///
+    /// Code and initial storage are typechecked by the node first.
+    /// Returns address of the originated contract.
+    pub fn originate(
+        &mut self,
+        balance: u64,
+        script: NewOriginationScript,
+    ) -> Result<OriginatedAddress, Error>
+    {
+        self.api.typecheck_script(&script)?;
 
-        self.confirm_operation(&operation_hash)?;
+        let operation_group = self.prepare(vec![OperationType::Origination { balance, script }])?;
+        let sig_info = self.sign_and_inject(operation_group)?;
 
-        let version = self.get_version()?;
-        let network = version.get_network();
+        // it's the only origination in the operation group.
+        Ok(OriginatedAddress::from_origination_nonce(&sig_info.operation_hash, 0)?)
+    }
 
-        match TzStats::new(network) {
-            Ok(tzstats) => {
-                eprintln!(
-                    "\n  {}View operation at: {}/{}",
-                    emojies::FINGER_POINTER_RIGHT,
-                    style(tzstats.operation_link_prefix()).cyan(),
-                    style(&operation_hash).cyan(),
-                );
-            }
-            Err(err) => {
-                eprintln!(
-                    "\n{} {}",
-                    style("[WARN]").yellow(),
-                    err,
-                );
-                eprintln!("\nOperation hash: {}", style(&operation_hash).green());
+    /// Source of the voting operation. Only delegates, which are
+    /// implicit accounts, can vote.
+    fn voting_source(&self) -> Result<ImplicitAddress, Error> {
+        match &self.from {
+            Address::Implicit(addr) => Ok(addr.clone()),
+            Address::Originated(_) => {
+                Err("voting operations can only be sent from an implicit account (delegate).".into())
             }
-        };
-
-        if !console::user_attended() {
-            println!("{}", &operation_hash);
         }
+    }
 
+    /// Execute voting operation.
+    ///
+    /// Voting operations have no fees, counter or gas limit, so
+    /// neither reveal, nor estimation is needed.
+    fn execute_voting(&mut self, op: NewOperation) -> Result<(), Error> {
+        let operation_group = self.fetch_operation_group()?
+            .with_operation(op);
+        self.sign_and_inject(operation_group)?;
         Ok(())
     }
 
-    pub fn transfer(&mut self, to: Address, amount: u64) -> Result<(), Error> {
-        let op_type = OperationType::Transaction { to, amount };
-        self.execute(op_type)
+    /// Submit or upvote proposals during proposal period.
+    pub fn submit_proposals(
+        &mut self,
+        period: u32,
+        proposals: Vec<ProtocolHash>,
+    ) -> Result<(), Error>
+    {
+        let op = NewProposalsOperation {
+            source: self.voting_source()?,
+            period,
+            proposals,
+        };
+        self.execute_voting(op.into())
     }
 
-    pub fn delegate(&mut self, to: Option<ImplicitAddress>) -> Result<(), Error> {
-        let op_type = OperationType::Delegation { to };
-        self.execute(op_type)
+    /// Vote for the proposal during exploration or promotion period.
+    pub fn submit_ballot(
+        &mut self,
+        period: u32,
+        proposal: ProtocolHash,
+        ballot: Ballot,
+    ) -> Result<(), Error>
+    {
+        let op = NewBallotOperation {
+            source: self.voting_source()?,
+            period,
+            proposal,
+            ballot,
+        };
+        self.execute_voting(op.into())
     }
 }
 
 type Error = Box<dyn std::error::Error>;
+
+#[cfg(test)]
+mod tests {
+    use lib::BlockHash;
+    use lib::micheline::{Micheline, MichelinePrim, PrimType};
+    use lib::signer::test_utils::{local_signer, EDSK, SPSK};
+    use super::*;
+
+    #[test]
+    fn test_verify_signed_operation() {
+        let signer = local_signer(EDSK);
+        let other_signer = local_signer(SPSK);
+
+        // code with empty `args`, which unforges into primitive without args.
+        let code: Micheline = serde_json::from_str(
+            r#"[{"prim":"parameter","args":[{"prim":"unit","args":[]}]},
+                {"prim":"storage","args":[{"prim":"unit"}]},
+                {"prim":"code","args":[[{"prim":"CDR"},
+                    {"prim":"NIL","args":[{"prim":"operation"}]},
+                    {"prim":"PAIR"}]]}]"#,
+        ).unwrap();
+        let group = NewOperationGroup::new(BlockHash::from([1; 32]), "proto".to_string())
+            .with_operation(NewOriginationOperation {
+                source: signer.public_key().hash(),
+                balance: 0,
+                fee: 1000,
+                counter: 7,
+                gas_limit: 10000,
+                storage_limit: 1000,
+                script: NewOriginationScript {
+                    code,
+                    storage: MichelinePrim::new(PrimType::Unit).into(),
+                },
+            });
+
+        let sig_info = signer.sign_forged_operation_bytes(group.forge().as_ref());
+        assert!(verify_signed_operation(&group, signer.public_key(), &sig_info).is_ok());
+        assert!(matches!(
+            verify_signed_operation(&group, other_signer.public_key(), &sig_info),
+            Err(SignedOperationMismatchError::InvalidSignature(_)),
+        ));
+
+        let mut other_group = group.clone();
+        other_group.operations[0].set_fee(1001);
+        assert!(matches!(
+            verify_signed_operation(&other_group, signer.public_key(), &sig_info),
+            Err(SignedOperationMismatchError::Mismatch),
+        ));
+    }
+}
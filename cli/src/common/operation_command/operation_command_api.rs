@@ -11,6 +11,8 @@ pub trait OperationCommandApi:
     + GetPendingOperations
     + GetPendingOperationStatus
     + RunOperation
+    + TypecheckScript
+    + GetConstants
     + PreapplyOperations
     + InjectOperations
 {}
@@ -26,6 +28,8 @@ impl<T> OperationCommandApi for T where T:
     + GetPendingOperations
     + GetPendingOperationStatus
     + RunOperation
+    + TypecheckScript
+    + GetConstants
     + PreapplyOperations
     + InjectOperations
 {}
@@ -1,13 +1,18 @@
 use std::fmt::{self, Display};
+use structopt::StructOpt;
 use console::style;
 use dialoguer::theme::ColorfulTheme;
 
-use lib::{Address, ParseDerivationPathError};
+use lib::{Address, ImplicitAddress, ParseDerivationPathError};
+use lib::signer::{
+    Signer, SignerError, LocalSigner, LocalSignerError, RemoteSigner, RemoteSignerError,
+};
 use lib::http_api::HttpApi;
 use lib::utils::parse_float_amount;
+use crate::common::{Keystore, KeystoreError, UnlockAccountError};
 use crate::common::operation_command::{OperationCommand, OperationOptions, OperationCommandState};
-
-use super::{LedgerState, TrezorState};
+use crate::trezor::TrezorSigner;
+use crate::ledger::LedgerSigner;
 
 pub fn ask_for_key_path() -> Result<String, std::io::Error> {
     eprintln!(
@@ -101,6 +106,13 @@ pub enum ParseOperationCommandError {
 
     InvalidFee(#[from] InvalidFeeError),
 
+    Keystore(#[from] KeystoreError),
+
+    UnlockAccount(#[from] UnlockAccountError),
+    RemoteSigner(#[from] RemoteSignerError),
+    LocalSigner(#[from] LocalSignerError),
+    Signer(#[from] SignerError),
+
     #[error("interactivity is turned off, but `--key-path` wasn't passed in.")]
     MissingKeyPath,
 }
@@ -110,77 +122,194 @@ pub struct RawOptions {
     pub no_prompt: bool,
     pub use_trezor: bool,
     pub use_ledger: bool,
+    /// Url of the remote signer.
+    pub remote_signer: Option<String>,
+    /// Name or address of the account in the keystore, used to
+    /// authenticate requests to the remote signer.
+    pub remote_signer_auth: Option<String>,
+    /// Whether Trezor can prompt for the pin code while signing.
+    ///
+    /// If `false`, Trezor is unlocked once when the signer is created
+    /// and signing fails, instead of prompting, if it gets locked later.
+    pub trezor_pin_prompt: bool,
+}
+
+/// Command line options, which select the signer of the operation.
+///
+/// Flattened into every command, which signs and injects an operation.
+#[derive(StructOpt, Debug, Clone)]
+pub struct SignerOptions {
+    /// Use Trezor device.
+    #[structopt(long = "trezor")]
+    pub use_trezor: bool,
+
+    /// Use Ledger device.
+    #[structopt(long = "ledger", conflicts_with = "use-trezor")]
+    pub use_ledger: bool,
+
+    /// Url of the remote signer (Octez signer HTTP protocol).
+    ///
+    /// When used, --from needs to be an address of the key
+    /// stored in the remote signer.
+    #[structopt(long = "remote-signer", conflicts_with_all = &["use-trezor", "use-ledger"])]
+    pub remote_signer: Option<String>,
+
+    /// Name or address of the account in the local keystore, used to
+    /// authenticate requests to the remote signer.
+    ///
+    /// Needed when remote signer only accepts requests from authorized keys.
+    #[structopt(long = "remote-signer-auth", requires = "remote-signer")]
+    pub remote_signer_auth: Option<String>,
+}
+
+impl SignerOptions {
+    pub fn to_raw_options(&self, no_prompt: bool) -> RawOptions {
+        RawOptions {
+            api_type: "http".to_string(),
+            no_prompt,
+            use_trezor: self.use_trezor,
+            use_ledger: self.use_ledger,
+            remote_signer: self.remote_signer.clone(),
+            remote_signer_auth: self.remote_signer_auth.clone(),
+            trezor_pin_prompt: true,
+        }
+    }
+
+    /// Exit with an argument error, if `--trezor` is passed to the
+    /// command, which can't be signed using Trezor.
+    pub fn reject_trezor(&self, reason: &str) {
+        if self.use_trezor {
+            structopt::clap::Error::with_description(
+                &format!("--trezor can't be used: {}", reason),
+                structopt::clap::ErrorKind::ArgumentConflict,
+            ).exit();
+        }
+    }
+}
+
+/// Create signer from the options and `--from` (and `--key-path`)
+/// arguments, along with the source address of the operation.
+///
+/// - Trezor or Ledger, if `use_trezor` or `use_ledger` is set.
+/// - Remote signer, if `remote_signer` url is set.
+/// - Otherwise local wallet, where `raw_from` is a name or an address
+///   of the account in the keystore.
+pub fn parse_signer(
+    options: &RawOptions,
+    raw_from: &str,
+    raw_key_path: Option<&str>,
+) -> Result<(Box<dyn Signer>, Address), ParseOperationCommandError>
+{
+    let from_is_key_path = raw_from.starts_with("m/");
+
+    let key_path = if options.use_trezor || options.use_ledger {
+        let raw_key_path = if from_is_key_path {
+            raw_from.to_string()
+        } else if let Some(key_path) = raw_key_path {
+            key_path.to_string()
+        } else {
+            if options.no_prompt {
+                return Err(ParseOperationCommandError::MissingKeyPath);
+            }
+            ask_for_key_path()?
+        };
+
+        Some(raw_key_path.parse()?)
+    } else {
+        None
+    };
+
+    let parse_from = || {
+        Address::from_base58check(raw_from)
+            .map_err(|err| ParseAddressError {
+                kind: AddressKind::Source,
+                error: err,
+                address: raw_from.to_string(),
+            })
+    };
+
+    if let Some(key_path) = key_path {
+        let mut signer: Box<dyn Signer> = if options.use_trezor {
+            let trezor = crate::trezor::find_device_and_connect();
+            let mut signer = TrezorSigner::new(trezor, key_path);
+            if !options.trezor_pin_prompt {
+                // unlock the device now, while pin can still be entered.
+                signer.public_key()?;
+                signer = signer.without_pin_prompt();
+            }
+            Box::new(signer)
+        } else {
+            let ledger = crate::ledger::find_device_and_connect();
+            Box::new(LedgerSigner::new(ledger, key_path))
+        };
+
+        let from = if from_is_key_path {
+            signer.public_key()?.hash().into()
+        } else {
+            parse_from()?
+        };
+        Ok((signer, from))
+    } else if let Some(url) = options.remote_signer.as_ref() {
+        // remote signer, when `--remote-signer` url is passed in.
+        let public_key_hash = ImplicitAddress::from_base58check(raw_from)
+            .map_err(|err| ParseAddressError {
+                kind: AddressKind::Source,
+                error: err,
+                address: raw_from.to_string(),
+            })?;
+        let mut remote_signer = RemoteSigner::new(url, public_key_hash.clone())?;
+
+        if let Some(name) = options.remote_signer_auth.as_ref() {
+            let keystore = Keystore::open_default()?;
+            let account = keystore.find(name)
+                .ok_or_else(|| KeystoreError::NotFound(name.clone()))?;
+            let key = LocalSigner::from_private_key(
+                account.unlock(options.no_prompt)?,
+            )?;
+            remote_signer = remote_signer.with_authentication_key(key);
+        }
+        Ok((Box::new(remote_signer), public_key_hash.into()))
+    } else {
+        // local wallet, when `--from` is a name or an address of
+        // the account in the keystore.
+        let keystore = Keystore::open_default()?;
+        let account = keystore.find(raw_from)
+            .ok_or_else(|| KeystoreError::NotFound(raw_from.to_string()))?;
+        let signer = LocalSigner::new(
+            account.public_key.clone(),
+            account.unlock(options.no_prompt)?,
+        )?;
+        let from = signer.public_key().hash().into();
+        Ok((Box::new(signer), from))
+    }
 }
 
 pub trait RawOperationCommand {
-    fn get_raw_options(&self) -> RawOptions;
+    fn get_no_prompt(&self) -> bool;
+    fn get_signer_options(&self) -> &SignerOptions;
     fn get_api_endpoint(&self) -> String;
     fn get_raw_key_path(&self) -> Option<&str>;
     fn get_raw_from(&self) -> &str;
     fn get_raw_fee(&self) -> Option<&String>;
 
+    fn get_raw_options(&self) -> RawOptions {
+        self.get_signer_options().to_raw_options(self.get_no_prompt())
+    }
+
     fn parse(&self) -> Result<OperationCommand, ParseOperationCommandError> {
         let options = self.get_raw_options();
         let state = OperationCommandState::default();
-        let mut trezor_state = None;
-        let mut ledger_state = None;
 
         let api = match options.api_type.as_str() {
             "http" => Box::new(HttpApi::new(self.get_api_endpoint())),
-            _ => Err(InvalidApiTypeError(options.api_type))?,
-        };
-
-        let from_is_key_path = self.get_raw_from().starts_with("m/");
-
-        let key_path = if options.use_trezor || options.use_ledger {
-            let raw_key_path = if from_is_key_path {
-                self.get_raw_from().to_string()
-            } else if let Some(key_path) = self.get_raw_key_path() {
-                key_path.to_string()
-            } else {
-                if self.get_raw_options().no_prompt {
-                    return Err(ParseOperationCommandError::MissingKeyPath);
-                }
-                ask_for_key_path()?
-            };
-
-            Some(raw_key_path.parse()?)
-        } else {
-            None
+            _ => Err(InvalidApiTypeError(options.api_type.clone()))?,
         };
 
-        if let Some(key_path) = key_path.clone() {
-            if options.use_trezor {
-                let trezor = crate::trezor::find_device_and_connect();
-                trezor_state = Some(TrezorState { trezor, key_path });
-            } else if options.use_ledger {
-                let ledger = crate::ledger::find_device_and_connect();
-                ledger_state = Some(LedgerState { ledger, key_path });
-            }
-        }
-
-        let from = match (from_is_key_path, key_path) {
-            (false, _) | (_, None) => {
-                Address::from_base58check(self.get_raw_from())
-                    .map_err(|err| ParseAddressError {
-                        kind: AddressKind::Source,
-                        error: err,
-                        address: self.get_raw_from().to_string(),
-                    })?
-            }
-            (true, Some(key_path)) => {
-                if let Some(trezor_state) = trezor_state.as_mut() {
-                    crate::trezor::get_address(&mut trezor_state.trezor, &key_path)
-                } else if let Some(ledger_state) = ledger_state.as_mut() {
-                    crate::ledger::ledger_execute(
-                        ledger_state.ledger.get_address(&key_path, false),
-                    )
-                } else {
-                    // key_path won't be set if neither `use_ledger` or `use_trezor` is set.
-                    unreachable!()
-                }.into()
-            }
-        };
+        let (signer, from) = parse_signer(
+            &options,
+            self.get_raw_from(),
+            self.get_raw_key_path(),
+        )?;
 
         let fee = if let Some(raw_fee) = self.get_raw_fee() {
             Some(parse_float_amount(raw_fee)
@@ -191,15 +320,13 @@ pub trait RawOperationCommand {
 
         Ok(OperationCommand {
             options: OperationOptions {
-                no_prompt: self.get_raw_options().no_prompt,
+                no_prompt: options.no_prompt,
             },
             from,
             fee,
             api,
             state,
-            trezor_state,
-            ledger_state,
-            local_state: None,
+            signer,
         })
     }
 }
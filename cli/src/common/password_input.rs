@@ -0,0 +1,16 @@
+use dialoguer::theme::ColorfulTheme;
+
+/// Ask for a password without echoing it to the terminal.
+///
+/// If `confirm` is `true`, password will need to be entered twice.
+pub fn password_input(prompt: &str, confirm: bool) -> Result<String, std::io::Error> {
+    let theme = ColorfulTheme::default();
+    let mut input = dialoguer::Password::with_theme(&theme);
+    input.with_prompt(prompt);
+
+    if confirm {
+        input.with_confirmation("repeat password", "passwords don't match");
+    }
+
+    input.interact()
+}
@@ -1,6 +1,6 @@
 use console::style;
 
-use lib::ledger_api::LedgerResponse;
+use lib::ledger_api::{LedgerResponse, LedgerError};
 use cli_spinner::{wait_for_action_spinner, SpinnerBuilder};
 use crate::common::exit_with_error;
 
@@ -10,17 +10,28 @@ use crate::common::exit_with_error;
 /// if no error occurred. Otherwise [crate::common::exit_with_error] will
 /// be called, which will print an error to `stderr` and will exit the process
 /// with code 1: `process.exit(1)`.
-pub fn ledger_execute<'a, T>(mut response: LedgerResponse<'a, T>) -> T
+pub fn ledger_execute<'a, T>(response: LedgerResponse<'a, T>) -> T
+    where T: 'static,
+{
+    match try_ledger_execute(response) {
+        Ok(result) => result,
+        Err(err) => exit_with_error(err),
+    }
+}
+
+/// Execute Ledger command and drive it to completion.
+///
+/// Same as [ledger_execute], but returns an error instead of exiting
+/// the process, if device fails or user rejects the action.
+pub fn try_ledger_execute<'a, T>(mut response: LedgerResponse<'a, T>) -> Result<T, LedgerError>
     where T: 'static,
 {
     let spinner = wait_for_action_spinner();
 
     loop {
         match response {
-            LedgerResponse::Ok(result) => { return result; }
-            LedgerResponse::Err(err) => {
-                exit_with_error(err);
-            }
+            LedgerResponse::Ok(result) => { return Ok(result); }
+            LedgerResponse::Err(err) => { return Err(err); }
             LedgerResponse::RunAppRequest(req) => {
                 let _spinner = spinner.clone()
                     .with_text(format!(
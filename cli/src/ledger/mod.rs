@@ -3,6 +3,9 @@
 mod execute;
 pub use execute::*;
 
+mod signer;
+pub use signer::*;
+
 use lib::ledger_api::Ledger;
 
 use crate::common::exit_with_error;
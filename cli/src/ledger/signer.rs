@@ -0,0 +1,50 @@
+use lib::{KeyDerivationPath, PublicKey, Signature, Forged};
+use lib::signer::{Signer, SignerError, OperationSignatureInfo};
+use lib::ledger_api::Ledger;
+
+use super::try_ledger_execute;
+
+/// Signs using the key derived by Ledger for the `key_path`.
+pub struct LedgerSigner {
+    ledger: Ledger,
+    key_path: KeyDerivationPath,
+}
+
+impl LedgerSigner {
+    pub fn new(ledger: Ledger, key_path: KeyDerivationPath) -> Self {
+        Self { ledger, key_path }
+    }
+}
+
+impl Signer for LedgerSigner {
+    fn name(&self) -> String {
+        "Ledger".to_string()
+    }
+
+    fn confirmation_message(&self) -> Option<String> {
+        Some("please confirm an operation on Ledger once you see the dialog on the device.".to_string())
+    }
+
+    fn public_key(&mut self) -> Result<PublicKey, SignerError> {
+        try_ledger_execute(self.ledger.get_public_key(&self.key_path, false))
+            .map_err(SignerError::other)
+    }
+
+    fn sign_forged_operation(
+        &mut self,
+        forged_operation: &[u8],
+    ) -> Result<OperationSignatureInfo, SignerError>
+    {
+        try_ledger_execute(self.ledger.sign_tx(
+            &self.key_path,
+            Forged::new_unchecked(forged_operation.to_vec()),
+        )).map_err(SignerError::other)
+    }
+
+    /// Bytes are passed to the Tezos app as they are, so Micheline
+    /// expressions (`0x05` magic byte) can be signed too.
+    fn sign_bytes(&mut self, bytes: &[u8]) -> Result<Signature, SignerError> {
+        try_ledger_execute(self.ledger.sign_bytes(&self.key_path, bytes))
+            .map_err(SignerError::other)
+    }
+}
@@ -57,10 +57,21 @@ fn main() {
 
     let result = match command {
         Command::Address(c) => c.execute(),
+        Command::Keys(c) => c.execute(),
         Command::Transfer(c) => c.execute(),
         Command::Delegate(c) => c.execute(),
-        Command::UnsafeTransferLocal(c) => c.execute(),
-        Command::UnsafeDelegateLocal(c) => c.execute(),
+        Command::Activate(c) => c.execute(),
+        Command::Vote(c) => c.execute(),
+        Command::Signer(c) => c.execute(),
+        Command::Prepare(c) => c.execute(),
+        Command::Sign(c) => c.execute(),
+        Command::Broadcast(c) => c.execute(),
+        Command::SignMessage(c) => c.execute(),
+        Command::VerifySignature(c) => c.execute(),
+        Command::Multisig(c) => c.execute(),
+        Command::Token(c) => c.execute(),
+        Command::Fa2(c) => c.execute(),
+        Command::Contract(c) => c.execute(),
         Command::Originate(c) => c.execute(),
     };
 
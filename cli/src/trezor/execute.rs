@@ -1,8 +1,20 @@
-use lib::trezor_api::{Result, TrezorResponse};
+use lib::trezor_api::{self, Result, TrezorResponse};
 use lib::trezor_api::messages::TrezorMessage;
 use cli_spinner::wait_for_action_spinner;
 use crate::common::exit_with_error;
 
+#[derive(thiserror::Error, Debug)]
+pub enum TrezorExecuteError {
+    #[error("failed to communicate with Trezor: {0:?}")]
+    Transport(trezor_api::Error),
+    #[error("Trezor failure: {0}")]
+    Failure(String),
+    #[error("Trezor is locked and pin code can't be entered interactively")]
+    PinRequired,
+    #[error("failed to read Trezor pin code: {0}")]
+    PinInput(#[from] std::io::Error),
+}
+
 /// Execute Trezor command and drive it to completion.
 ///
 /// Handles action requests and returns successful result `T` at the end,
@@ -14,15 +26,32 @@ use crate::common::exit_with_error;
 ///          process crashes or is terminated for some reason, Trezor seems to get stuck.
 // TODO: Explore the issue and fix it or at least print useful error message, when
 //       the case detailed in the 'Warning', happens.
-pub fn trezor_execute<T, R>(mut response: Result<TrezorResponse<T, R>>) -> T
+pub fn trezor_execute<T, R>(response: Result<TrezorResponse<T, R>>) -> T
+    where R: TrezorMessage,
+{
+    match try_trezor_execute(response, true) {
+        Ok(result) => result,
+        Err(err) => exit_with_error(err),
+    }
+}
+
+/// Execute Trezor command and drive it to completion.
+///
+/// Same as [trezor_execute], but returns an error instead of exiting
+/// the process. If `pin_prompt` is `false` and Trezor asks for the pin
+/// code, [TrezorExecuteError::PinRequired] is returned instead of
+/// prompting the user.
+pub fn try_trezor_execute<T, R>(
+    mut response: Result<TrezorResponse<T, R>>,
+    pin_prompt: bool,
+) -> std::result::Result<T, TrezorExecuteError>
     where R: TrezorMessage,
 {
     let spinner = wait_for_action_spinner();
 
     loop {
-        // TODO: handle transport errors
-        match response.unwrap() {
-            TrezorResponse::Ok(result) => { return result; }
+        match response.map_err(TrezorExecuteError::Transport)? {
+            TrezorResponse::Ok(result) => { return Ok(result); }
             TrezorResponse::ButtonRequest(req) => {
                 let _spinner = spinner.clone()
                     .with_text("please confirm an action on Trezor device")
@@ -30,14 +59,16 @@ pub fn trezor_execute<T, R>(mut response: Result<TrezorResponse<T, R>>) -> T
                 response = req.ack();
             }
             TrezorResponse::Failure(failure) => {
-                exit_with_error(failure.get_message());
+                return Err(TrezorExecuteError::Failure(failure.get_message().to_string()));
             }
             TrezorResponse::PinMatrixRequest(req) => {
+                if !pin_prompt {
+                    return Err(TrezorExecuteError::PinRequired);
+                }
                 // TODO: zeroize pin
                 let pin = dialoguer::Password::new()
                     .with_prompt("Trezor pin code")
-                    .interact()
-                    .unwrap();
+                    .interact()?;
                 response = req.ack_pin(pin);
             }
         }
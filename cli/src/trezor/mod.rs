@@ -6,6 +6,9 @@ pub use get_address::*;
 mod execute;
 pub use execute::*;
 
+mod signer;
+pub use signer::*;
+
 use lib::trezor_api;
 use trezor_api::Trezor;
 
@@ -0,0 +1,75 @@
+use std::convert::TryFrom;
+
+use lib::{KeyDerivationPath, PublicKey, NewOperationGroup, UnforgedOperationGroup, Unforge};
+use lib::crypto::hex;
+use lib::signer::{Signer, SignerError, OperationSignatureInfo};
+use lib::trezor_api::{Trezor, TezosSignTx};
+
+use super::try_trezor_execute;
+
+/// Signs using the key derived by Trezor for the `key_path`.
+///
+/// Trezor forges the operation itself, so the forged operation is
+/// unforged first and then the signed bytes are compared to it.
+pub struct TrezorSigner {
+    trezor: Trezor,
+    key_path: KeyDerivationPath,
+    pin_prompt: bool,
+}
+
+impl TrezorSigner {
+    pub fn new(trezor: Trezor, key_path: KeyDerivationPath) -> Self {
+        Self { trezor, key_path, pin_prompt: true }
+    }
+
+    /// Fail with an error, instead of prompting for the pin code,
+    /// if Trezor gets locked.
+    pub fn without_pin_prompt(mut self) -> Self {
+        self.pin_prompt = false;
+        self
+    }
+}
+
+impl Signer for TrezorSigner {
+    fn name(&self) -> String {
+        "Trezor".to_string()
+    }
+
+    fn confirmation_message(&self) -> Option<String> {
+        Some("please confirm an operation on your Trezor.".to_string())
+    }
+
+    fn public_key(&mut self) -> Result<PublicKey, SignerError> {
+        let public_key = try_trezor_execute(
+            self.trezor.get_public_key(&self.key_path),
+            self.pin_prompt,
+        ).map_err(SignerError::other)?;
+        PublicKey::from_base58check(&public_key).map_err(SignerError::other)
+    }
+
+    fn sign_forged_operation(
+        &mut self,
+        forged_operation: &[u8],
+    ) -> Result<OperationSignatureInfo, SignerError>
+    {
+        let unforged = UnforgedOperationGroup::unforge(forged_operation)
+            .map_err(SignerError::other)?;
+        let group = NewOperationGroup {
+            branch: unforged.branch,
+            next_protocol_hash: String::new(),
+            operations: unforged.operations,
+        };
+
+        let mut tx = TezosSignTx::try_from(group).map_err(SignerError::other)?;
+        tx.set_address_n(self.key_path.clone().take());
+        let sig_info = OperationSignatureInfo::from(
+            try_trezor_execute(self.trezor.sign_tx(tx), self.pin_prompt)
+                .map_err(SignerError::other)?,
+        );
+
+        if !sig_info.operation_with_signature.starts_with(&hex::encode(forged_operation)) {
+            return Err(SignerError::OperationMismatch);
+        }
+        Ok(sig_info)
+    }
+}
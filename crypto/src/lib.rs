@@ -6,3 +6,5 @@ pub use base58check::*;
 
 pub mod hex;
 pub mod blake2b;
+pub mod mnemonic;
+pub mod slip10;
@@ -0,0 +1,63 @@
+use bip39::Mnemonic;
+
+/// Number of words in the generated mnemonic.
+pub const MNEMONIC_WORD_COUNT: usize = 24;
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+#[error("invalid mnemonic: {0}")]
+pub struct InvalidMnemonicError(String);
+
+/// Generate BIP39 seed from the mnemonic and optional passphrase.
+///
+/// Mnemonic must consist of English words and have a valid checksum.
+/// Both mnemonic and passphrase are NFKD normalized, as required by BIP39.
+pub fn mnemonic_to_seed(
+    mnemonic: &str,
+    passphrase: &str,
+) -> Result<[u8; 64], InvalidMnemonicError>
+{
+    let words = mnemonic.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    Mnemonic::parse(words)
+        .map(|mnemonic| mnemonic.to_seed(passphrase))
+        .map_err(|err| InvalidMnemonicError(err.to_string()))
+}
+
+/// Generate new random BIP39 mnemonic of [MNEMONIC_WORD_COUNT] English words.
+pub fn generate_mnemonic() -> String {
+    // each 3 words encode 32 bits of entropy.
+    let mut entropy = [0; MNEMONIC_WORD_COUNT / 3 * 4];
+    sodiumoxide::randombytes::randombytes_into(&mut entropy);
+
+    // unwrap is fine since entropy length is valid.
+    Mnemonic::from_entropy(&entropy).unwrap().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mnemonic_to_seed() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = mnemonic_to_seed(mnemonic, "TREZOR").unwrap();
+
+        assert_eq!(
+            crate::hex::encode(&seed[..]),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+        );
+        assert!(mnemonic_to_seed("abandon abandon abandon", "").is_err());
+
+        // composed and decomposed forms of the passphrase give the same seed.
+        assert_eq!(
+            mnemonic_to_seed(mnemonic, "caf\u{e9}").unwrap()[..],
+            mnemonic_to_seed(mnemonic, "cafe\u{301}").unwrap()[..],
+        );
+
+        let generated = generate_mnemonic();
+        assert_eq!(generated.split(' ').count(), MNEMONIC_WORD_COUNT);
+        assert!(mnemonic_to_seed(&generated, "").is_ok());
+        // invalid checksum.
+        assert!(mnemonic_to_seed(&mnemonic.replace("about", "abandon"), "").is_err());
+    }
+}
@@ -6,13 +6,24 @@ pub enum Prefix {
     tz3,
     KT1,
     B,
+    P,
     edpk,
     sppk,
     p2pk,
     edsk64,
     edsk32,
+    spsk,
+    p2sk,
+    edesk,
+    spesk,
+    p2esk,
     edsig,
+    spsig1,
+    p2sig,
+    sig,
     operation,
+    expr,
+    Net,
 }
 
 impl Prefix {
@@ -28,6 +39,8 @@ impl Prefix {
             Some(Prefix::KT1)
         } else if value.starts_with(Prefix::B.as_ref()) {
             Some(Prefix::B)
+        } else if value.starts_with(Prefix::P.as_ref()) {
+            Some(Prefix::P)
         } else if value.starts_with(Prefix::edpk.as_ref()) {
             Some(Prefix::edpk)
         } else if value.starts_with(Prefix::sppk.as_ref()) {
@@ -38,10 +51,30 @@ impl Prefix {
             Some(Prefix::edsk64)
         } else if value.starts_with(Prefix::edsk32.as_ref()) {
             Some(Prefix::edsk32)
+        } else if value.starts_with(Prefix::spsk.as_ref()) {
+            Some(Prefix::spsk)
+        } else if value.starts_with(Prefix::p2sk.as_ref()) {
+            Some(Prefix::p2sk)
+        } else if value.starts_with(Prefix::edesk.as_ref()) {
+            Some(Prefix::edesk)
+        } else if value.starts_with(Prefix::spesk.as_ref()) {
+            Some(Prefix::spesk)
+        } else if value.starts_with(Prefix::p2esk.as_ref()) {
+            Some(Prefix::p2esk)
         } else if value.starts_with(Prefix::edsig.as_ref()) {
             Some(Prefix::edsig)
+        } else if value.starts_with(Prefix::spsig1.as_ref()) {
+            Some(Prefix::spsig1)
+        } else if value.starts_with(Prefix::p2sig.as_ref()) {
+            Some(Prefix::p2sig)
+        } else if value.starts_with(Prefix::sig.as_ref()) {
+            Some(Prefix::sig)
         } else if value.starts_with(Prefix::operation.as_ref()) {
             Some(Prefix::operation)
+        } else if value.starts_with(Prefix::expr.as_ref()) {
+            Some(Prefix::expr)
+        } else if value.starts_with(Prefix::Net.as_ref()) {
+            Some(Prefix::Net)
         } else {
             None
         }
@@ -56,13 +89,24 @@ impl AsRef<[u8]> for Prefix {
             Self::tz3 => &[6, 161, 164],
             Self::KT1 => &[2, 90, 121],
             Self::B => &[1, 52],
+            Self::P => &[2, 170],
             Self::edpk => &[13, 15, 37, 217],
             Self::sppk => &[3, 254, 226, 86],
             Self::p2pk => &[3, 178, 139, 127],
             Self::edsk64 => &[43, 246, 78, 7],
             Self::edsk32 => &[13, 15, 58, 7],
+            Self::spsk => &[17, 162, 224, 201],
+            Self::p2sk => &[16, 81, 238, 189],
+            Self::edesk => &[7, 90, 60, 179, 41],
+            Self::spesk => &[9, 237, 241, 174, 150],
+            Self::p2esk => &[9, 48, 57, 115, 171],
             Self::edsig => &[9, 245, 205, 134, 18],
+            Self::spsig1 => &[13, 115, 101, 19, 63],
+            Self::p2sig => &[54, 240, 44, 52],
+            Self::sig => &[4, 130, 43],
             Self::operation => &[5, 116],
+            Self::expr => &[13, 44, 64, 27],
+            Self::Net => &[87, 82, 0],
         }
     }
 }
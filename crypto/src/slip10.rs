@@ -0,0 +1,82 @@
+//! [SLIP-10] key derivation for ed25519 curve.
+//!
+//! [SLIP-10]: https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+/// Indexes greater or equal to this one are hardened.
+const HARDENED_INDEX: u32 = 0x80000000;
+
+const ED25519_SEED_KEY: &[u8] = b"ed25519 seed";
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone, Copy)]
+#[error("ed25519 only supports hardened key derivation, but index {0} is not hardened")]
+pub struct NonHardenedIndexError(pub u32);
+
+/// Returns (key, chain_code).
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> ([u8; 32], [u8; 32]) {
+    // unwrap is fine since hmac accepts keys of any size.
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).unwrap();
+    for part in data {
+        mac.update(part);
+    }
+    let result = mac.finalize().into_bytes();
+
+    let mut key = [0; 32];
+    let mut chain_code = [0; 32];
+    key.copy_from_slice(&result[..32]);
+    chain_code.copy_from_slice(&result[32..]);
+    (key, chain_code)
+}
+
+/// Derive ed25519 private key (seed) for the given path from the master seed.
+///
+/// Only hardened indexes are supported for ed25519.
+pub fn derive_ed25519(seed: &[u8], path: &[u32]) -> Result<[u8; 32], NonHardenedIndexError> {
+    let (mut key, mut chain_code) = hmac_sha512(ED25519_SEED_KEY, &[seed]);
+
+    for &index in path {
+        if index < HARDENED_INDEX {
+            return Err(NonHardenedIndexError(index));
+        }
+
+        let (child_key, child_chain_code) = hmac_sha512(
+            &chain_code,
+            &[&[0], &key, &index.to_be_bytes()],
+        );
+        key = child_key;
+        chain_code = child_chain_code;
+    }
+
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    #[test]
+    fn test_derive_ed25519() {
+        // test vector 1 from SLIP-10.
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let cases = vec![
+            (vec![], "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"),
+            (vec![0], "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"),
+            (vec![0, 1], "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2"),
+        ];
+
+        for (path, expected) in cases {
+            let path = path.into_iter()
+                .map(|index| index + HARDENED_INDEX)
+                .collect::<Vec<_>>();
+            assert_eq!(hex::encode(&derive_ed25519(&seed, &path).unwrap()[..]), expected);
+        }
+
+        assert_eq!(
+            derive_ed25519(&seed, &[HARDENED_INDEX, 1]),
+            Err(NonHardenedIndexError(1)),
+        );
+    }
+}
@@ -59,9 +59,8 @@ use std::time::Duration;
 use ledger_apdu::{APDUCommand, APDUAnswer, APDUErrorCodes};
 use ledger::{TransportNativeHID, LedgerHIDError};
 
-use types::{PUBLIC_KEY_LEN, Forged, KeyDerivationPath, PublicKey, ImplicitAddress};
-use crypto::{hex, blake2b, ToBase58Check, WithPrefix, Prefix};
-use signer::OperationSignatureInfo;
+use types::{PUBLIC_KEY_LEN, Forged, KeyDerivationPath, PublicKey, ImplicitAddress, Signature};
+use signer::{OperationSignatureInfo, OPERATION_WATERMARK};
 
 mod ledger_error;
 pub use ledger_error::{LedgerError, RunAppError, RunAppErrorKind};
@@ -255,12 +254,12 @@ impl Ledger {
             })
     }
 
-    fn sign_tx_request<'a>(
+    /// Sign bytes, which start with the magic byte (watermark).
+    fn sign_request<'a>(
         &'a mut self,
         path: &KeyDerivationPath,
-        // TODO: replace with ForgedOperation or Forged<NewOperationGroup>.
-        forged_operation: Forged,
-    ) -> LedgerRequest<'a, OperationSignatureInfo>
+        bytes: Vec<u8>,
+    ) -> LedgerRequest<'a, Signature>
     {
         let path_bytes = Self::encode_path(&path);
         let initial_command = APDUCommand {
@@ -271,19 +270,12 @@ impl Ledger {
             data: [vec![path.len() as u8], path_bytes].concat(),
         };
 
-        let operation_bytes = forged_operation.as_ref().to_vec();
-
         LedgerRequest::new(self, initial_command)
             .map(move |ledger, _| {
-                let encoded_op = [0x03].iter()
-                    .chain(forged_operation.as_ref())
-                    .map(|x| *x)
-                    .collect::<Vec<_>>();
-
-                let chunks = encoded_op.chunks(230).collect::<Vec<_>>();
+                let chunks = bytes.chunks(230).collect::<Vec<_>>();
 
                 // TODO: change error type. This can only happen if
-                // forged_operation is an empty array.
+                // bytes is an empty array.
                 let mut result = Err(LedgerError::InvalidDataLength);
 
                 for (index, chunk) in chunks.iter().enumerate() {
@@ -306,35 +298,29 @@ impl Ledger {
 
                 result
             })
-            .map(move |_, bytes| {
+            .map(|_, bytes| {
                 if bytes.len() < 64 {
                     return Err(LedgerError::InvalidDataLength);
                 }
 
-                let signature_bytes = &bytes[0..64];
-
-                let signature = signature_bytes
-                    .with_prefix(Prefix::edsig)
-                    .to_base58check();
-
-                let operation_with_signature_bytes = [
-                    operation_bytes.to_vec(),
-                    signature_bytes.to_vec(),
-                ].concat();
-
-                let operation_with_signature = hex::encode(&operation_with_signature_bytes);
+                // unwrap is fine since we checked the length above.
+                Ok(Signature::edsig(bytes[0..64].try_into().unwrap()))
+            })
+    }
 
-                let operation_hash = blake2b::digest_256(
-                    &operation_with_signature_bytes,
-                )
-                    .with_prefix(Prefix::operation)
-                    .to_base58check();
+    fn sign_tx_request<'a>(
+        &'a mut self,
+        path: &KeyDerivationPath,
+        // TODO: replace with ForgedOperation or Forged<NewOperationGroup>.
+        forged_operation: Forged,
+    ) -> LedgerRequest<'a, OperationSignatureInfo>
+    {
+        let operation_bytes = forged_operation.take();
+        let encoded_op = [vec![OPERATION_WATERMARK], operation_bytes.clone()].concat();
 
-                Ok(OperationSignatureInfo {
-                    signature,
-                    operation_with_signature,
-                    operation_hash,
-                })
+        self.sign_request(path, encoded_op)
+            .map(move |_, signature| {
+                Ok(OperationSignatureInfo::new(&operation_bytes, &signature))
             })
     }
 
@@ -391,4 +377,18 @@ impl Ledger {
     {
         self.sign_tx_request(path, forged_operation).send()
     }
+
+    /// Sign bytes, which start with the magic byte (watermark), like
+    /// `0x05` for Micheline expressions.
+    ///
+    /// Bytes are signed as they are, so it's up to the Tezos app on the
+    /// device to decide, whether or not it supports given magic byte.
+    pub fn sign_bytes<'a>(
+        &'a mut self,
+        path: &KeyDerivationPath,
+        bytes: &[u8],
+    ) -> LedgerResponse<'a, Signature>
+    {
+        self.sign_request(path, bytes.to_vec()).send()
+    }
 }
@@ -0,0 +1,40 @@
+use std::fmt::{self, Display};
+
+use types::micheline::Micheline;
+use crate::api::TransportError;
+
+#[derive(thiserror::Error, Debug)]
+#[error(transparent)]
+pub enum GetBigMapValueErrorKind {
+    Transport(#[from] TransportError),
+    #[error("Unknown! {0}")]
+    Unknown(String),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub struct GetBigMapValueError {
+    pub big_map_id: u64,
+    pub key_hash: String,
+    pub kind: GetBigMapValueErrorKind,
+}
+
+impl Display for GetBigMapValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "getting value \"{}\" from big map {} failed! Reason: {}",
+            self.key_hash,
+            self.big_map_id,
+            self.kind,
+        )
+    }
+}
+
+pub type GetBigMapValueResult = Result<Option<Micheline>, GetBigMapValueError>;
+
+pub trait GetBigMapValue {
+    /// Get value from the big map by the script expression hash of
+    /// the key (see `script_expr_hash`).
+    ///
+    /// Returns `None` if there is no such key in the big map.
+    fn get_big_map_value(&self, big_map_id: u64, key_hash: &str) -> GetBigMapValueResult;
+}
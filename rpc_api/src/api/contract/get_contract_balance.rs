@@ -0,0 +1,36 @@
+use std::fmt::{self, Display};
+
+use types::Address;
+use crypto::ToBase58Check;
+use crate::api::TransportError;
+
+#[derive(thiserror::Error, Debug)]
+#[error(transparent)]
+pub enum GetContractBalanceErrorKind {
+    Transport(#[from] TransportError),
+    #[error("Unknown! {0}")]
+    Unknown(String),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub struct GetContractBalanceError {
+    pub address: Address,
+    pub kind: GetContractBalanceErrorKind,
+}
+
+impl Display for GetContractBalanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "getting balance for address \"{}\" failed! Reason: {}",
+            self.address.to_base58check(),
+            self.kind,
+        )
+    }
+}
+
+pub type GetContractBalanceResult = Result<u64, GetContractBalanceError>;
+
+pub trait GetContractBalance {
+    /// Get balance (in µꜩ) of the contract.
+    fn get_contract_balance(&self, address: &Address) -> GetContractBalanceResult;
+}
@@ -0,0 +1,40 @@
+use std::fmt::{self, Display};
+
+use types::OriginatedAddress;
+use types::micheline::Micheline;
+use crypto::ToBase58Check;
+use crate::api::TransportError;
+
+#[derive(thiserror::Error, Debug)]
+#[error(transparent)]
+pub enum GetContractCodeErrorKind {
+    Transport(#[from] TransportError),
+    #[error("Unknown! {0}")]
+    Unknown(String),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub struct GetContractCodeError {
+    pub address: OriginatedAddress,
+    pub kind: GetContractCodeErrorKind,
+}
+
+impl Display for GetContractCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "getting code of the contract with an address \"{}\" failed! Reason: {}",
+            self.address.to_base58check(),
+            self.kind,
+        )
+    }
+}
+
+pub type GetContractCodeResult = Result<Micheline, GetContractCodeError>;
+
+pub trait GetContractCode {
+    /// Get code (`parameter`, `storage` and `code` sections) of the contract.
+    fn get_contract_code(
+        &self,
+        addr: &OriginatedAddress,
+    ) -> GetContractCodeResult;
+}
@@ -7,5 +7,17 @@ pub use get_contract_delegate::*;
 mod get_contract_storage;
 pub use get_contract_storage::*;
 
+mod get_contract_code;
+pub use get_contract_code::*;
+
+mod get_contract_balance;
+pub use get_contract_balance::*;
+
 mod get_contract_manager_address;
 pub use get_contract_manager_address::*;
+
+mod get_big_map_value;
+pub use get_big_map_value::*;
+
+mod run_view;
+pub use run_view::*;
@@ -0,0 +1,48 @@
+use std::fmt::{self, Display};
+
+use types::OriginatedAddress;
+use types::micheline::Micheline;
+use crypto::ToBase58Check;
+use crate::api::{TransportError, GetChainIDError};
+
+#[derive(thiserror::Error, Debug)]
+#[error(transparent)]
+pub enum RunViewErrorKind {
+    Transport(#[from] TransportError),
+    GetChainID(#[from] GetChainIDError),
+    #[error("Unknown! {0}")]
+    Unknown(String),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub struct RunViewError {
+    pub address: OriginatedAddress,
+    pub entrypoint: String,
+    pub kind: RunViewErrorKind,
+}
+
+impl Display for RunViewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "running view \"{}\" of the contract \"{}\" failed! Reason: {}",
+            self.entrypoint,
+            self.address.to_base58check(),
+            self.kind,
+        )
+    }
+}
+
+pub type RunViewResult = Result<Micheline, RunViewError>;
+
+pub trait RunView {
+    /// Execute view entrypoint (TZIP-4) of the contract off-chain.
+    ///
+    /// `input` is the argument of the view, without the callback
+    /// contract. Returns value, which would be passed to the callback.
+    fn run_view(
+        &self,
+        addr: &OriginatedAddress,
+        entrypoint: &str,
+        input: &Micheline,
+    ) -> RunViewResult;
+}
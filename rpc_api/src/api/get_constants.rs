@@ -1,14 +1,34 @@
+use std::fmt::{self, Display};
 use serde::{Serialize, Deserialize};
 
+use crate::api::TransportError;
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Constants {
     pub hard_gas_limit_per_block: String,
     pub hard_storage_limit_per_operation: String,
+    /// Amount of µꜩ burned for each byte of the used storage.
     #[serde(with = "utils::serde_str")]
     pub cost_per_byte: u64,
 }
 
-pub type GetConstantsResult = Result<Constants, ()>;
+#[derive(thiserror::Error, Debug)]
+pub enum GetConstantsError {
+    Transport(#[from] TransportError),
+    Unknown(String),
+}
+
+impl Display for GetConstantsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "getting protocol constants failed! Reason: ")?;
+        match self {
+            Self::Transport(err) => err.fmt(f),
+            Self::Unknown(err) => write!(f, "Unknown! {}", err)
+        }
+    }
+}
+
+pub type GetConstantsResult = Result<Constants, GetConstantsError>;
 
 pub trait GetConstants {
     fn get_constants(&self) -> GetConstantsResult;
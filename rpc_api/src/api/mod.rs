@@ -7,6 +7,9 @@ pub use operation::*;
 mod contract;
 pub use contract::*;
 
+mod votes;
+pub use votes::*;
+
 mod get_version_info;
 pub use get_version_info::*;
 
@@ -22,6 +25,9 @@ pub use get_head_block_hash::*;
 mod get_chain_id;
 pub use get_chain_id::*;
 
+mod typecheck_script;
+pub use typecheck_script::*;
+
 // TODO: move inside contract/ and rename
 mod get_manager_public_key;
 pub use get_manager_public_key::*;
@@ -39,6 +39,9 @@ pub type RunOperationContents = Vec<RunOperationContent>;
 pub struct RunOperationContent {
     pub kind: String,
     pub consumed_gas: u64,
+    /// Number of bytes of the new storage, which has to be paid for
+    /// (burned) by the source of the operation.
+    pub paid_storage_size_diff: u64,
 }
 
 impl<'de> Deserialize<'de> for RunOperationContent {
@@ -53,20 +56,30 @@ impl<'de> Deserialize<'de> for RunOperationContent {
 
         #[derive(Deserialize)]
         struct Metadata {
-            operation_result: MetadataResult,
+            /// Only present for manager operations.
+            operation_result: Option<MetadataResult>,
         }
 
         #[derive(Deserialize)]
         struct MetadataResult {
             #[serde(with = "utils::serde_str")]
             consumed_gas: u64,
+            #[serde(default, with = "utils::serde_str")]
+            paid_storage_size_diff: u64,
         }
 
         let result = RawOperationResult::deserialize(d)?;
 
+        let operation_result = result.metadata.operation_result;
+
         Ok(Self {
             kind: result.kind,
-            consumed_gas: result.metadata.operation_result.consumed_gas,
+            consumed_gas: operation_result.as_ref()
+                .map(|result| result.consumed_gas)
+                .unwrap_or(0),
+            paid_storage_size_diff: operation_result.as_ref()
+                .map(|result| result.paid_storage_size_diff)
+                .unwrap_or(0),
         })
     }
 }
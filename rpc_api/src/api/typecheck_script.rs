@@ -0,0 +1,34 @@
+use std::fmt::{self, Display};
+
+use types::NewOriginationScript;
+use crate::api::TransportError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum TypecheckScriptError {
+    Transport(#[from] TransportError),
+    /// Script doesn't have `storage` section.
+    MissingStorageType,
+    /// Node rejected the code or the initial storage. Contains errors
+    /// returned by the node.
+    IllTyped(String),
+    Unknown(String),
+}
+
+impl Display for TypecheckScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "typechecking the script failed! Reason: ")?;
+        match self {
+            Self::Transport(err) => err.fmt(f),
+            Self::MissingStorageType => write!(f, "code doesn't contain storage type"),
+            Self::IllTyped(err) => write!(f, "ill-typed script: {}", err),
+            Self::Unknown(err) => write!(f, "Unknown! {}", err)
+        }
+    }
+}
+
+pub type TypecheckScriptResult = Result<(), TypecheckScriptError>;
+
+pub trait TypecheckScript {
+    /// Typecheck contract's code and its initial storage.
+    fn typecheck_script(&self, script: &NewOriginationScript) -> TypecheckScriptResult;
+}
@@ -0,0 +1,29 @@
+use std::fmt::{self, Display};
+
+use types::ProtocolHash;
+use crate::api::TransportError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum GetCurrentProposalError {
+    Transport(#[from] TransportError),
+    Unknown(String),
+}
+
+impl Display for GetCurrentProposalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "getting current proposal failed! Reason: ")?;
+        match self {
+            Self::Transport(err) => err.fmt(f),
+            Self::Unknown(err) => write!(f, "Unknown! {}", err)
+        }
+    }
+}
+
+pub type GetCurrentProposalResult = Result<Option<ProtocolHash>, GetCurrentProposalError>;
+
+pub trait GetCurrentProposal {
+    /// Get proposal, which is being voted on in the current period.
+    ///
+    /// Returns `None` during proposal period.
+    fn get_current_proposal(&self) -> GetCurrentProposalResult;
+}
@@ -0,0 +1,71 @@
+use std::fmt::{self, Display};
+use serde::{Serialize, Deserialize};
+
+use crate::api::TransportError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum GetCurrentVotingPeriodError {
+    Transport(#[from] TransportError),
+    Unknown(String),
+}
+
+impl Display for GetCurrentVotingPeriodError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "getting current voting period failed! Reason: ")?;
+        match self {
+            Self::Transport(err) => err.fmt(f),
+            Self::Unknown(err) => write!(f, "Unknown! {}", err)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum VotingPeriodKind {
+    Proposal,
+    #[serde(alias = "testing_vote")]
+    Exploration,
+    #[serde(alias = "testing")]
+    Cooldown,
+    #[serde(alias = "promotion_vote")]
+    Promotion,
+    Adoption,
+}
+
+impl VotingPeriodKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Proposal => "proposal",
+            Self::Exploration => "exploration",
+            Self::Cooldown => "cooldown",
+            Self::Promotion => "promotion",
+            Self::Adoption => "adoption",
+        }
+    }
+
+    /// Whether ballots can be submitted during this period.
+    pub fn is_ballot_period(&self) -> bool {
+        matches!(self, Self::Exploration | Self::Promotion)
+    }
+}
+
+impl Display for VotingPeriodKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct VotingPeriod {
+    /// Index of the voting period, used in voting operations.
+    pub index: u32,
+    pub kind: VotingPeriodKind,
+    /// Number of blocks remaining till the end of the period.
+    pub remaining: u32,
+}
+
+pub type GetCurrentVotingPeriodResult = Result<VotingPeriod, GetCurrentVotingPeriodError>;
+
+pub trait GetCurrentVotingPeriod {
+    fn get_current_voting_period(&self) -> GetCurrentVotingPeriodResult;
+}
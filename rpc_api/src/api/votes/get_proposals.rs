@@ -0,0 +1,35 @@
+use std::fmt::{self, Display};
+
+use types::ProtocolHash;
+use crate::api::TransportError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum GetProposalsError {
+    Transport(#[from] TransportError),
+    Unknown(String),
+}
+
+impl Display for GetProposalsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "getting proposals failed! Reason: ")?;
+        match self {
+            Self::Transport(err) => err.fmt(f),
+            Self::Unknown(err) => write!(f, "Unknown! {}", err)
+        }
+    }
+}
+
+/// Proposal submitted during the current proposal period.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Proposal {
+    pub protocol_hash: ProtocolHash,
+    /// Total voting power of the delegates, which upvoted the proposal.
+    pub upvotes: u64,
+}
+
+pub type GetProposalsResult = Result<Vec<Proposal>, GetProposalsError>;
+
+pub trait GetProposals {
+    /// Get proposals of the current proposal period.
+    fn get_proposals(&self) -> GetProposalsResult;
+}
@@ -0,0 +1,8 @@
+mod get_current_voting_period;
+pub use get_current_voting_period::*;
+
+mod get_proposals;
+pub use get_proposals::*;
+
+mod get_current_proposal;
+pub use get_current_proposal::*;
@@ -0,0 +1,69 @@
+use crate::api::{
+    GetBigMapValue, GetBigMapValueResult,
+    TransportError, GetBigMapValueError, GetBigMapValueErrorKind,
+};
+use crate::http_api::HttpApi;
+
+fn get_big_map_value_url(base_url: &str, big_map_id: u64, key_hash: &str) -> String {
+    format!(
+        "{}/chains/main/blocks/head/context/big_maps/{}/{}",
+        base_url,
+        big_map_id,
+        key_hash,
+    )
+}
+
+impl From<ureq::Error> for GetBigMapValueErrorKind {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Transport(error) => {
+                Self::Transport(TransportError(Box::new(error)))
+            }
+            ureq::Error::Status(code, resp) => {
+                let status_text = resp.status_text().to_string();
+                Self::Unknown(format!(
+                    "Http status: ({}, {}){}",
+                    code,
+                    status_text,
+                    match resp.into_string() {
+                        Ok(s) => format!(", message: {}", s),
+                        Err(_) => "".to_string(),
+                    },
+                ))
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for GetBigMapValueErrorKind {
+    fn from(error: std::io::Error) -> Self {
+        Self::Transport(TransportError(Box::new(error)))
+    }
+}
+
+#[inline]
+fn build_error<E>(big_map_id: u64, key_hash: &str, kind: E) -> GetBigMapValueError
+    where E: Into<GetBigMapValueErrorKind>,
+{
+    GetBigMapValueError {
+        big_map_id,
+        key_hash: key_hash.to_string(),
+        kind: kind.into(),
+    }
+}
+
+impl GetBigMapValue for HttpApi {
+    fn get_big_map_value(&self, big_map_id: u64, key_hash: &str) -> GetBigMapValueResult {
+        self.client.get(&get_big_map_value_url(&self.base_url, big_map_id, key_hash))
+           .call()
+           .map(|resp| resp.into_json().map(Some))
+           .or_else(|err| {
+               match &err {
+                   // will return 404 status if there is no such key in the big map.
+                   ureq::Error::Status(404, _) => Ok(Ok(None)),
+                   _ => Err(build_error(big_map_id, key_hash, err))
+               }
+           })?
+           .map_err(|err| build_error(big_map_id, key_hash, err))
+    }
+}
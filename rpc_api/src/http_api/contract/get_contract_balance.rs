@@ -0,0 +1,69 @@
+use serde::Deserialize;
+
+use types::Address;
+use crypto::ToBase58Check;
+use crate::api::{
+    GetContractBalance, GetContractBalanceResult,
+    TransportError, GetContractBalanceError, GetContractBalanceErrorKind,
+};
+use crate::http_api::HttpApi;
+
+fn get_contract_balance_url(base_url: &str, addr: &Address) -> String {
+    format!(
+        "{}/chains/main/blocks/head/context/contracts/{}/balance",
+        base_url,
+        addr.to_base58check(),
+    )
+}
+
+impl From<ureq::Error> for GetContractBalanceErrorKind {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Transport(error) => {
+                Self::Transport(TransportError(Box::new(error)))
+            }
+            ureq::Error::Status(code, resp) => {
+                let status_text = resp.status_text().to_string();
+                Self::Unknown(format!(
+                    "Http status: ({}, {}){}",
+                    code,
+                    status_text,
+                    match resp.into_string() {
+                        Ok(s) => format!(", message: {}", s),
+                        Err(_) => "".to_string(),
+                    },
+                ))
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for GetContractBalanceErrorKind {
+    fn from(error: std::io::Error) -> Self {
+        Self::Transport(TransportError(Box::new(error)))
+    }
+}
+
+#[inline]
+fn build_error<E>(address: &Address, kind: E) -> GetContractBalanceError
+    where E: Into<GetContractBalanceErrorKind>,
+{
+    GetContractBalanceError {
+        address: address.clone(),
+        kind: kind.into(),
+    }
+}
+
+#[derive(Deserialize)]
+struct BalanceJson(#[serde(with = "utils::serde_str")] u64);
+
+impl GetContractBalance for HttpApi {
+    fn get_contract_balance(&self, addr: &Address) -> GetContractBalanceResult {
+        Ok(self.client.get(&get_contract_balance_url(&self.base_url, addr))
+           .call()
+           .map_err(|err| build_error(addr, err))?
+           .into_json::<BalanceJson>()
+           .map_err(|err| build_error(addr, err))?
+           .0)
+    }
+}
@@ -0,0 +1,76 @@
+use serde::Deserialize;
+
+use types::OriginatedAddress;
+use types::micheline::Micheline;
+use crypto::ToBase58Check;
+use crate::api::{
+    GetContractCode, GetContractCodeResult,
+    TransportError, GetContractCodeError, GetContractCodeErrorKind,
+};
+use crate::http_api::HttpApi;
+
+fn get_contract_code_url(base_url: &str, addr: &OriginatedAddress) -> String {
+    format!(
+        "{}/chains/main/blocks/head/context/contracts/{}/script",
+        base_url,
+        addr.to_base58check(),
+    )
+}
+
+impl From<ureq::Error> for GetContractCodeErrorKind {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Transport(error) => {
+                Self::Transport(TransportError(Box::new(error)))
+            }
+            ureq::Error::Status(code, resp) => {
+                let status_text = resp.status_text().to_string();
+                Self::Unknown(format!(
+                    "Http status: ({}, {}){}",
+                    code,
+                    status_text,
+                    match resp.into_string() {
+                        Ok(s) => format!(", message: {}", s),
+                        Err(_) => "".to_string(),
+                    },
+                ))
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for GetContractCodeErrorKind {
+    fn from(error: std::io::Error) -> Self {
+        Self::Transport(TransportError(Box::new(error)))
+    }
+}
+
+#[inline]
+fn build_error<E>(address: &OriginatedAddress, kind: E) -> GetContractCodeError
+    where E: Into<GetContractCodeErrorKind>,
+{
+    GetContractCodeError {
+        address: address.clone(),
+        kind: kind.into(),
+    }
+}
+
+#[derive(Deserialize)]
+struct ScriptJson {
+    code: Micheline,
+}
+
+impl GetContractCode for HttpApi {
+    fn get_contract_code(
+        &self,
+        addr: &OriginatedAddress,
+    ) -> GetContractCodeResult
+    {
+        Ok(self.client.get(&get_contract_code_url(&self.base_url, addr))
+           .call()
+           .map_err(|err| build_error(addr, err))?
+           .into_json::<ScriptJson>()
+           .map_err(|err| build_error(addr, err))?
+           .code)
+    }
+}
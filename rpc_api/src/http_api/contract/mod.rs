@@ -6,3 +6,8 @@ pub use get_contract_delegate::*;
 
 mod get_contract_storage;
 pub use get_contract_storage::*;
+
+mod get_contract_code;
+mod get_contract_balance;
+mod get_big_map_value;
+mod run_view;
@@ -0,0 +1,84 @@
+use serde::Deserialize;
+
+use types::OriginatedAddress;
+use types::micheline::Micheline;
+use crypto::ToBase58Check;
+use crate::api::{
+    GetChainID, RunView, RunViewResult,
+    TransportError, RunViewError, RunViewErrorKind,
+};
+use crate::http_api::HttpApi;
+
+fn run_view_url(base_url: &str) -> String {
+    format!("{}/chains/main/blocks/head/helpers/scripts/run_view", base_url)
+}
+
+impl From<ureq::Error> for RunViewErrorKind {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Transport(error) => {
+                Self::Transport(TransportError(Box::new(error)))
+            }
+            ureq::Error::Status(code, resp) => {
+                let status_text = resp.status_text().to_string();
+                Self::Unknown(format!(
+                    "Http status: ({}, {}){}",
+                    code,
+                    status_text,
+                    match resp.into_string() {
+                        Ok(s) => format!(", message: {}", s),
+                        Err(_) => "".to_string(),
+                    },
+                ))
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for RunViewErrorKind {
+    fn from(error: std::io::Error) -> Self {
+        Self::Transport(TransportError(Box::new(error)))
+    }
+}
+
+#[inline]
+fn build_error<E>(address: &OriginatedAddress, entrypoint: &str, kind: E) -> RunViewError
+    where E: Into<RunViewErrorKind>,
+{
+    RunViewError {
+        address: address.clone(),
+        entrypoint: entrypoint.to_string(),
+        kind: kind.into(),
+    }
+}
+
+#[derive(Deserialize)]
+struct RunViewJson {
+    data: Micheline,
+}
+
+impl RunView for HttpApi {
+    fn run_view(
+        &self,
+        addr: &OriginatedAddress,
+        entrypoint: &str,
+        input: &Micheline,
+    ) -> RunViewResult
+    {
+        let chain_id = self.get_chain_id()
+            .map_err(|err| build_error(addr, entrypoint, err))?;
+
+        Ok(self.client.post(&run_view_url(&self.base_url))
+           .send_json(ureq::json!({
+               "contract": addr.to_base58check(),
+               "entrypoint": entrypoint,
+               "input": input,
+               "chain_id": chain_id,
+               "unparsing_mode": "Readable",
+           }))
+           .map_err(|err| build_error(addr, entrypoint, err))?
+           .into_json::<RunViewJson>()
+           .map_err(|err| build_error(addr, entrypoint, err))?
+           .data)
+    }
+}
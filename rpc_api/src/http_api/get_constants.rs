@@ -0,0 +1,42 @@
+use crate::api::{TransportError, GetConstants, GetConstantsResult, GetConstantsError};
+use crate::http_api::HttpApi;
+
+fn get_constants_url(base_url: &str) -> String {
+    format!("{}/chains/main/blocks/head/context/constants", base_url)
+}
+
+impl From<ureq::Error> for GetConstantsError {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Transport(error) => {
+                Self::Transport(TransportError(Box::new(error)))
+            }
+            ureq::Error::Status(code, resp) => {
+                let status_text = resp.status_text().to_string();
+                Self::Unknown(format!(
+                    "Http status: ({}, {}){}",
+                    code,
+                    status_text,
+                    match resp.into_string() {
+                        Ok(s) => format!(", message: {}", s),
+                        Err(_) => "".to_string(),
+                    },
+                ))
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for GetConstantsError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Transport(TransportError(Box::new(error)))
+    }
+}
+
+impl GetConstants for HttpApi {
+    fn get_constants(&self) -> GetConstantsResult {
+        Ok(self.client.get(&get_constants_url(&self.base_url))
+            .call()?
+            .into_json()?)
+    }
+}
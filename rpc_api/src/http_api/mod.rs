@@ -4,6 +4,9 @@ pub use contract::*;
 mod operation;
 pub use operation::*;
 
+// only contains `HttpApi` implementations, nothing to re-export.
+mod votes;
+
 mod get_chain_id;
 pub use get_chain_id::*;
 
@@ -19,6 +22,10 @@ pub use get_head_block_hash::*;
 mod get_manager_public_key;
 pub use get_manager_public_key::*;
 
+mod get_constants;
+
+mod typecheck_script;
+
 pub struct HttpApi {
     base_url: String,
     client: ureq::Agent,
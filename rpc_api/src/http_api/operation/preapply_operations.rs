@@ -49,9 +49,8 @@ impl PreapplyOperations for HttpApi {
                "protocol": &operation_group.next_protocol_hash,
                "branch": &operation_group.branch,
                "signature": signature,
-               "contents": operation_group.to_operations_vec()
-                   .into_iter()
-                   .map(|op| NewOperationWithKind::from(op))
+               "contents": operation_group.operations.iter()
+                   .map(|op| NewOperationWithKind::from(op.clone()))
                    .collect::<Vec<_>>(),
            }]))?
            .into_json()?)
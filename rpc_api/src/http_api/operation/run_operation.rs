@@ -51,9 +51,8 @@ impl RunOperation for HttpApi {
                     // this is necessary to be valid signature for this call
                     // to work, but doesn't need to match the actual operation signature.
                     "signature": "edsigthZLBZKMBUCwHpMCXHkGtBSzwh7wdUxqs7C1LRMk64xpcVU8tyBDnuFuf9CLkdL3urGem1zkHXFV9JbBBabi6k8QnhW4RG",
-                    "contents": operation_group.to_operations_vec()
-                        .into_iter()
-                        .map(|op| NewOperationWithKind::from(op))
+                    "contents": operation_group.operations.iter()
+                        .map(|op| NewOperationWithKind::from(op.clone()))
                         .collect::<Vec<_>>(),
                 },
 
@@ -0,0 +1,67 @@
+use types::NewOriginationScript;
+use crate::api::{
+    TypecheckScript, TypecheckScriptResult,
+    TransportError, TypecheckScriptError,
+};
+use crate::http_api::HttpApi;
+
+fn typecheck_code_url(base_url: &str) -> String {
+    format!("{}/chains/main/blocks/head/helpers/scripts/typecheck_code", base_url)
+}
+
+fn typecheck_data_url(base_url: &str) -> String {
+    format!("{}/chains/main/blocks/head/helpers/scripts/typecheck_data", base_url)
+}
+
+impl From<ureq::Error> for TypecheckScriptError {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Transport(error) => {
+                Self::Transport(TransportError(Box::new(error)))
+            }
+            // node responds with 400 and the list of errors, if
+            // code or data is ill-typed.
+            ureq::Error::Status(400, resp) => {
+                Self::IllTyped(resp.into_string().unwrap_or_default())
+            }
+            ureq::Error::Status(code, resp) => {
+                let status_text = resp.status_text().to_string();
+                Self::Unknown(format!(
+                    "Http status: ({}, {}){}",
+                    code,
+                    status_text,
+                    match resp.into_string() {
+                        Ok(s) => format!(", message: {}", s),
+                        Err(_) => "".to_string(),
+                    },
+                ))
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for TypecheckScriptError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Transport(TransportError(Box::new(error)))
+    }
+}
+
+impl TypecheckScript for HttpApi {
+    fn typecheck_script(&self, script: &NewOriginationScript) -> TypecheckScriptResult {
+        let storage_type = script.storage_type()
+            .ok_or(TypecheckScriptError::MissingStorageType)?;
+
+        self.client.post(&typecheck_code_url(&self.base_url))
+            .send_json(ureq::json!({
+                "program": &script.code,
+            }))?;
+
+        self.client.post(&typecheck_data_url(&self.base_url))
+            .send_json(ureq::json!({
+                "data": &script.storage,
+                "type": storage_type,
+            }))?;
+
+        Ok(())
+    }
+}
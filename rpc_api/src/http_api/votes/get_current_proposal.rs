@@ -0,0 +1,46 @@
+use types::ProtocolHash;
+use crate::api::{
+    GetCurrentProposal, GetCurrentProposalResult,
+    TransportError, GetCurrentProposalError,
+};
+use crate::http_api::HttpApi;
+
+fn get_current_proposal_url(base_url: &str) -> String {
+    format!("{}/chains/main/blocks/head/votes/current_proposal", base_url)
+}
+
+impl From<ureq::Error> for GetCurrentProposalError {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Transport(error) => {
+                Self::Transport(TransportError(Box::new(error)))
+            }
+            ureq::Error::Status(code, resp) => {
+                let status_text = resp.status_text().to_string();
+                Self::Unknown(format!(
+                    "Http status: ({}, {}){}",
+                    code,
+                    status_text,
+                    match resp.into_string() {
+                        Ok(s) => format!(", message: {}", s),
+                        Err(_) => "".to_string(),
+                    },
+                ))
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for GetCurrentProposalError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Transport(TransportError(Box::new(error)))
+    }
+}
+
+impl GetCurrentProposal for HttpApi {
+    fn get_current_proposal(&self) -> GetCurrentProposalResult {
+        Ok(self.client.get(&get_current_proposal_url(&self.base_url))
+            .call()?
+            .into_json::<Option<ProtocolHash>>()?)
+    }
+}
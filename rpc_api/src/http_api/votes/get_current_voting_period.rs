@@ -0,0 +1,70 @@
+use serde::Deserialize;
+
+use crate::api::{
+    GetCurrentVotingPeriod, GetCurrentVotingPeriodResult, VotingPeriod,
+    VotingPeriodKind, TransportError, GetCurrentVotingPeriodError,
+};
+use crate::http_api::HttpApi;
+
+fn get_current_voting_period_url(base_url: &str) -> String {
+    format!("{}/chains/main/blocks/head/votes/current_period", base_url)
+}
+
+impl From<ureq::Error> for GetCurrentVotingPeriodError {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Transport(error) => {
+                Self::Transport(TransportError(Box::new(error)))
+            }
+            ureq::Error::Status(code, resp) => {
+                let status_text = resp.status_text().to_string();
+                Self::Unknown(format!(
+                    "Http status: ({}, {}){}",
+                    code,
+                    status_text,
+                    match resp.into_string() {
+                        Ok(s) => format!(", message: {}", s),
+                        Err(_) => "".to_string(),
+                    },
+                ))
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for GetCurrentVotingPeriodError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Transport(TransportError(Box::new(error)))
+    }
+}
+
+#[derive(Deserialize)]
+struct VotingPeriodInfoJson {
+    index: u32,
+    kind: VotingPeriodKind,
+}
+
+#[derive(Deserialize)]
+struct VotingPeriodJson {
+    voting_period: VotingPeriodInfoJson,
+    remaining: u32,
+}
+
+impl From<VotingPeriodJson> for VotingPeriod {
+    fn from(json: VotingPeriodJson) -> Self {
+        Self {
+            index: json.voting_period.index,
+            kind: json.voting_period.kind,
+            remaining: json.remaining,
+        }
+    }
+}
+
+impl GetCurrentVotingPeriod for HttpApi {
+    fn get_current_voting_period(&self) -> GetCurrentVotingPeriodResult {
+        Ok(self.client.get(&get_current_voting_period_url(&self.base_url))
+            .call()?
+            .into_json::<VotingPeriodJson>()?
+            .into())
+    }
+}
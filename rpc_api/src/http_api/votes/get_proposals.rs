@@ -0,0 +1,73 @@
+use serde::Deserialize;
+
+use types::ProtocolHash;
+use crate::api::{
+    GetProposals, GetProposalsResult, Proposal,
+    TransportError, GetProposalsError,
+};
+use crate::http_api::HttpApi;
+
+fn get_proposals_url(base_url: &str) -> String {
+    format!("{}/chains/main/blocks/head/votes/proposals", base_url)
+}
+
+impl From<ureq::Error> for GetProposalsError {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Transport(error) => {
+                Self::Transport(TransportError(Box::new(error)))
+            }
+            ureq::Error::Status(code, resp) => {
+                let status_text = resp.status_text().to_string();
+                Self::Unknown(format!(
+                    "Http status: ({}, {}){}",
+                    code,
+                    status_text,
+                    match resp.into_string() {
+                        Ok(s) => format!(", message: {}", s),
+                        Err(_) => "".to_string(),
+                    },
+                ))
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for GetProposalsError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Transport(TransportError(Box::new(error)))
+    }
+}
+
+/// Voting power is a number in older protocols and a string in newer ones.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum VotingPowerJson {
+    Number(u64),
+    String(String),
+}
+
+impl VotingPowerJson {
+    fn parse(self) -> Result<u64, GetProposalsError> {
+        match self {
+            Self::Number(num) => Ok(num),
+            Self::String(s) => s.parse().map_err(|_| {
+                GetProposalsError::Unknown(format!("invalid voting power: {}", s))
+            }),
+        }
+    }
+}
+
+impl GetProposals for HttpApi {
+    fn get_proposals(&self) -> GetProposalsResult {
+        self.client.get(&get_proposals_url(&self.base_url))
+            .call()?
+            .into_json::<Vec<(ProtocolHash, VotingPowerJson)>>()?
+            .into_iter()
+            .map(|(protocol_hash, upvotes)| Ok(Proposal {
+                protocol_hash,
+                upvotes: upvotes.parse()?,
+            }))
+            .collect()
+    }
+}
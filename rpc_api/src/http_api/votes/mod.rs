@@ -0,0 +1,3 @@
+mod get_current_voting_period;
+mod get_proposals;
+mod get_current_proposal;
@@ -1,5 +1,20 @@
 mod sign_operation;
 pub use sign_operation::*;
 
+mod signer;
+pub use signer::*;
+
 mod local_signer;
 pub use local_signer::*;
+
+mod remote_signer;
+pub use remote_signer::*;
+
+mod sign_message;
+pub use sign_message::*;
+
+mod verify_signature;
+pub use verify_signature::*;
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
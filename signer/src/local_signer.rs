@@ -1,26 +1,133 @@
-use sodiumoxide::hex;
+use std::convert::TryInto;
 use sodiumoxide::crypto::sign::ed25519;
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
 
-use types::{PublicKey, PrivateKey, CombinedKey};
-use crypto::{blake2b, Prefix, WithPrefix, ToBase58Check};
+use types::{Curve, PublicKey, PrivateKey, PRIVATE_KEY_LEN, Signature};
+use crypto::blake2b;
 
-use crate::{SignOperation, SignOperationResult, OperationSignatureInfo};
+use crate::{OperationSignatureInfo, OPERATION_WATERMARK};
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+pub enum LocalSignerError {
+    #[error("invalid private key")]
+    InvalidPrivateKey,
+    #[error("public key doesn't correspond to the private key")]
+    PublicKeyMismatch,
+}
+
+/// Curve specific key used for signing.
+enum SigningKey {
+    Ed25519(ed25519::SecretKey),
+    Secp256k1(k256::ecdsa::SigningKey),
+    P256(p256::ecdsa::SigningKey),
+}
+
+impl SigningKey {
+    fn from_private_key(priv_key: &PrivateKey) -> Result<Self, LocalSignerError> {
+        Ok(match priv_key {
+            PrivateKey::edsk(seed) => {
+                let (_, secret_key) = ed25519::keypair_from_seed(&ed25519::Seed(*seed));
+                Self::Ed25519(secret_key)
+            }
+            PrivateKey::spsk(key) => Self::Secp256k1(
+                k256::ecdsa::SigningKey::from_bytes(key)
+                    .map_err(|_| LocalSignerError::InvalidPrivateKey)?,
+            ),
+            PrivateKey::p2sk(key) => Self::P256(
+                p256::ecdsa::SigningKey::from_bytes(key)
+                    .map_err(|_| LocalSignerError::InvalidPrivateKey)?,
+            ),
+        })
+    }
+
+    fn public_key(&self) -> PublicKey {
+        // unwraps below are fine since compressed points are always 33 bytes.
+        match self {
+            Self::Ed25519(key) => PublicKey::edpk(key.public_key().0),
+            Self::Secp256k1(key) => PublicKey::sppk(
+                key.verifying_key().to_encoded_point(true).as_bytes().try_into().unwrap(),
+            ),
+            Self::P256(key) => PublicKey::p2pk(
+                key.verifying_key().to_encoded_point(true).as_bytes().try_into().unwrap(),
+            ),
+        }
+    }
+
+    /// Sign 32 byte digest.
+    fn sign_digest(&self, digest: &[u8]) -> Signature {
+        // unwraps below are fine since digest is never empty and
+        // signing with valid key can't fail.
+        match self {
+            Self::Ed25519(key) => {
+                Signature::edsig(ed25519::sign_detached(digest, key).as_ref().try_into().unwrap())
+            }
+            Self::Secp256k1(key) => {
+                let sig: k256::ecdsa::Signature = key.sign_prehash(digest).unwrap();
+                // Tezos only accepts signatures with low `s` value.
+                let sig = sig.normalize_s().unwrap_or(sig);
+                Signature::spsig1(sig.as_ref().try_into().unwrap())
+            }
+            Self::P256(key) => {
+                let sig: p256::ecdsa::Signature = key.sign_prehash(digest).unwrap();
+                Signature::p2sig(sig.as_ref().try_into().unwrap())
+            }
+        }
+    }
+}
+
+/// Generate random private key for the given curve.
+pub fn generate_private_key(curve: Curve) -> PrivateKey {
+    loop {
+        let mut key = [0; PRIVATE_KEY_LEN];
+        sodiumoxide::randombytes::randombytes_into(&mut key);
+        let key = PrivateKey::new(curve, key);
+
+        // secp256k1 and P-256 keys must be non zero and less than
+        // the order of the curve, otherwise try again.
+        if SigningKey::from_private_key(&key).is_ok() {
+            return key;
+        }
+    }
+}
 
 pub struct LocalSigner {
     pub_key: PublicKey,
-    priv_key: PrivateKey,
+    signing_key: SigningKey,
 }
 
 impl LocalSigner {
-    pub fn new(pub_key: PublicKey, priv_key: PrivateKey) -> Self {
-        match pub_key {
-            PublicKey::edpk(_) => {}
-            PublicKey::sppk(_) | PublicKey::p2pk(_) => unimplemented!()
-        }
-        Self {
-            pub_key,
-            priv_key,
+    /// Create signer for the key pair.
+    ///
+    /// Fails if private key is invalid or if public key doesn't
+    /// correspond to the private key.
+    pub fn new(pub_key: PublicKey, priv_key: PrivateKey) -> Result<Self, LocalSignerError> {
+        let signer = Self::from_private_key(priv_key)?;
+
+        if signer.pub_key != pub_key {
+            return Err(LocalSignerError::PublicKeyMismatch);
         }
+
+        Ok(signer)
+    }
+
+    /// Create signer for the private key, deriving public key from it.
+    pub fn from_private_key(priv_key: PrivateKey) -> Result<Self, LocalSignerError> {
+        let signing_key = SigningKey::from_private_key(&priv_key)?;
+
+        Ok(Self {
+            pub_key: signing_key.public_key(),
+            signing_key,
+        })
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.pub_key
+    }
+
+    /// Sign blake2b (256 bit) hash of the given bytes.
+    pub fn sign_bytes(&self, bytes: &[u8]) -> Signature {
+        self.signing_key.sign_digest(&blake2b::digest_256(bytes))
     }
 
     // TODO: make separate newtype for ForgedOperation
@@ -29,41 +136,87 @@ impl LocalSigner {
         operation_bytes: &[u8],
     ) -> OperationSignatureInfo
     {
-        let combined_key = CombinedKey::new(&self.priv_key, &self.pub_key);
-
-        let signature_bytes = ed25519::sign_detached(
-            &blake2b::digest_256(&[vec![3], operation_bytes.to_vec()].concat()),
-            &ed25519::SecretKey(combined_key.as_ref().clone()),
+        let signature = self.sign_bytes(
+            &[vec![OPERATION_WATERMARK], operation_bytes.to_vec()].concat(),
         );
 
-        let signature = signature_bytes.as_ref()
-            .with_prefix(Prefix::edsig)
-            .to_base58check();
+        OperationSignatureInfo::new(operation_bytes, &signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use crypto::ToBase58Check;
+    use crate::test_utils::{local_signer, EDSK, EDPK, SPSK, P2SK};
+    use super::*;
+    use k256::ecdsa::signature::hazmat::PrehashVerifier;
+
+    fn new_signer(pub_key: &str, priv_key: &str) -> LocalSigner {
+        LocalSigner::new(
+            PublicKey::from_base58check(pub_key).unwrap(),
+            PrivateKey::from_base58check(priv_key).unwrap(),
+        ).unwrap()
+    }
 
-        let operation_with_signature_bytes = [
-            operation_bytes.to_vec(),
-            signature_bytes.as_ref().to_vec(),
-        ].concat();
+    #[test]
+    fn test_local_signer_key_pairs() {
+        let pairs = [
+            (EDPK, EDSK),
+            ("sppk7aqSksZan1AGXuKtCz9UBLZZ77e3ZWGpFxR7ig1Z17GneEhSSbH", SPSK),
+            ("p2pk66tTYL5EvahKAXncbtbRPBkAnxo3CszzUho5wPCgWauBMyvybuB", P2SK),
+        ];
+
+        for (pub_key, priv_key) in pairs.iter() {
+            let signer = new_signer(pub_key, priv_key);
+            assert_eq!(&signer.public_key().to_base58check(), pub_key);
+        }
 
-        let operation_with_signature = hex::encode(&operation_with_signature_bytes);
+        assert_eq!(
+            LocalSigner::new(
+                PublicKey::from_base58check(pairs[0].0).unwrap(),
+                PrivateKey::from_base58check(pairs[1].1).unwrap(),
+            ).err(),
+            Some(LocalSignerError::PublicKeyMismatch),
+        );
+    }
 
-        let operation_hash = blake2b::digest_256(
-            &operation_with_signature_bytes,
-        )
-            .with_prefix(Prefix::operation)
-            .to_base58check();
+    #[test]
+    fn test_generate_private_key() {
+        let curves = [
+            (Curve::Ed25519, "edpk"),
+            (Curve::Secp256k1, "sppk"),
+            (Curve::P256, "p2pk"),
+        ];
 
-        OperationSignatureInfo {
-            signature,
-            operation_with_signature,
-            operation_hash,
+        for (curve, prefix) in curves.iter() {
+            let key = generate_private_key(*curve);
+            assert_eq!(key.curve(), *curve);
+            let signer = LocalSigner::from_private_key(key).unwrap();
+            assert!(signer.public_key().to_base58check().starts_with(prefix));
         }
     }
-}
 
-impl SignOperation for LocalSigner {
-    fn sign_operation(&self, forged_operation: String) -> SignOperationResult {
-        let forged_bytes = hex::decode(&forged_operation)?;
-        Ok(self.sign_forged_operation_bytes(&forged_bytes))
+    #[test]
+    fn test_local_signer_signatures() {
+        let bytes = b"operation";
+        let digest = blake2b::digest_256(&[&[OPERATION_WATERMARK], &bytes[..]].concat());
+
+        let signer = local_signer(SPSK);
+        let sig_info = signer.sign_forged_operation_bytes(bytes);
+        assert!(sig_info.signature.starts_with("spsig1"));
+        let signature = Signature::from_base58check(&sig_info.signature).unwrap();
+        let sig = k256::ecdsa::Signature::try_from(&signature.as_ref()[..]).unwrap();
+        assert!(sig.normalize_s().is_none());
+        let key = k256::ecdsa::VerifyingKey::from_sec1_bytes(signer.public_key().as_ref()).unwrap();
+        assert!(key.verify_prehash(&digest, &sig).is_ok());
+
+        let signer = local_signer(P2SK);
+        let sig_info = signer.sign_forged_operation_bytes(bytes);
+        assert!(sig_info.signature.starts_with("p2sig"));
+        let signature = Signature::from_base58check(&sig_info.signature).unwrap();
+        let sig = p256::ecdsa::Signature::try_from(&signature.as_ref()[..]).unwrap();
+        let key = p256::ecdsa::VerifyingKey::from_sec1_bytes(signer.public_key().as_ref()).unwrap();
+        assert!(key.verify_prehash(&digest, &sig).is_ok());
     }
 }
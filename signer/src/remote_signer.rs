@@ -0,0 +1,237 @@
+use serde::Deserialize;
+use sodiumoxide::hex;
+
+use types::{Forge, ImplicitAddress, PublicKey, Signature, FromPrefixedBase58CheckError};
+use crypto::ToBase58Check;
+
+use crate::{OperationSignatureInfo, LocalSigner, OPERATION_WATERMARK};
+
+/// Watermark of the signing request authentication payload.
+const AUTHENTICATION_WATERMARK: u8 = 0x04;
+
+/// Bytes, which need to be signed by one of the authorized keys,
+/// in order to authenticate request to sign `data` using the key
+/// of `public_key_hash`.
+///
+/// Signature is passed as `authentication` query parameter.
+pub fn authentication_bytes(public_key_hash: &ImplicitAddress, data: &[u8]) -> Vec<u8> {
+    [
+        vec![AUTHENTICATION_WATERMARK],
+        public_key_hash.forge().take(),
+        data.to_vec(),
+    ].concat()
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum RemoteSignerError {
+    #[error("remote signer request failed: {0}")]
+    Transport(Box<dyn std::error::Error>),
+    #[error("remote signer responded with status ({code}, {status_text}){message}")]
+    Status {
+        code: u16,
+        status_text: String,
+        /// Response body prefixed with ", message: ", if available.
+        message: String,
+    },
+    #[error("invalid response from remote signer: {0}")]
+    InvalidResponse(String),
+    #[error("public key returned by remote signer doesn't match the address {0}")]
+    PublicKeyMismatch(String),
+}
+
+impl From<ureq::Error> for RemoteSignerError {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Transport(error) => Self::Transport(Box::new(error)),
+            ureq::Error::Status(code, resp) => Self::Status {
+                code,
+                status_text: resp.status_text().to_string(),
+                message: match resp.into_string() {
+                    Ok(s) => format!(", message: {}", s),
+                    Err(_) => "".to_string(),
+                },
+            },
+        }
+    }
+}
+
+impl From<std::io::Error> for RemoteSignerError {
+    fn from(error: std::io::Error) -> Self {
+        Self::InvalidResponse(error.to_string())
+    }
+}
+
+impl From<FromPrefixedBase58CheckError> for RemoteSignerError {
+    fn from(error: FromPrefixedBase58CheckError) -> Self {
+        Self::InvalidResponse(error.to_string())
+    }
+}
+
+#[derive(Deserialize)]
+struct PublicKeyJson {
+    public_key: String,
+}
+
+#[derive(Deserialize)]
+struct SignatureJson {
+    signature: String,
+}
+
+/// Signer, which signs using the key stored in the remote signer,
+/// implementing Octez signer HTTP protocol (`tezos-signer`).
+///
+/// - `GET /keys/<pkh>` returns public key for the public key hash.
+/// - `POST /keys/<pkh>` signs hex encoded bytes, passed as json string.
+pub struct RemoteSigner {
+    base_url: String,
+    public_key_hash: ImplicitAddress,
+    public_key: PublicKey,
+    /// Key used to authenticate signing requests, if remote signer
+    /// only accepts requests from authorized keys.
+    authentication_key: Option<LocalSigner>,
+    client: ureq::Agent,
+}
+
+impl RemoteSigner {
+    /// Connect to the remote signer and fetch the public key
+    /// for the `public_key_hash`.
+    pub fn new<S>(
+        base_url: S,
+        public_key_hash: ImplicitAddress,
+    ) -> Result<Self, RemoteSignerError>
+        where S: AsRef<str>,
+    {
+        let base_url = base_url.as_ref().trim_end_matches('/').to_owned();
+        let client = ureq::agent();
+
+        let url = Self::key_url(&base_url, &public_key_hash);
+        let public_key = PublicKey::from_base58check(
+            &client.get(&url).call()?.into_json::<PublicKeyJson>()?.public_key,
+        )?;
+
+        if public_key.hash() != public_key_hash {
+            return Err(RemoteSignerError::PublicKeyMismatch(
+                public_key_hash.to_base58check(),
+            ));
+        }
+
+        Ok(Self {
+            base_url,
+            public_key_hash,
+            public_key,
+            authentication_key: None,
+            client,
+        })
+    }
+
+    /// Authenticate signing requests using the given key.
+    pub fn with_authentication_key(mut self, key: LocalSigner) -> Self {
+        self.authentication_key = Some(key);
+        self
+    }
+
+    fn key_url(base_url: &str, public_key_hash: &ImplicitAddress) -> String {
+        format!("{}/keys/{}", base_url, public_key_hash.to_base58check())
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn public_key_hash(&self) -> &ImplicitAddress {
+        &self.public_key_hash
+    }
+
+    /// Sign bytes. Bytes need to include the watermark (magic byte),
+    /// since remote signer may filter requests based on it.
+    pub fn sign_bytes(&self, bytes: &[u8]) -> Result<Signature, RemoteSignerError> {
+        let url = Self::key_url(&self.base_url, &self.public_key_hash);
+        let mut request = self.client.post(&url);
+
+        if let Some(key) = self.authentication_key.as_ref() {
+            let authentication = key.sign_bytes(
+                &authentication_bytes(&self.public_key_hash, bytes),
+            );
+            request = request.query("authentication", &authentication.to_base58check());
+        }
+
+        let signature = request
+            .send_json(ureq::json!(hex::encode(bytes)))?
+            .into_json::<SignatureJson>()?
+            .signature;
+
+        Ok(Signature::from_base58check(&signature)?)
+    }
+
+    pub fn sign_forged_operation_bytes(
+        &self,
+        operation_bytes: &[u8],
+    ) -> Result<OperationSignatureInfo, RemoteSignerError>
+    {
+        let signature = self.sign_bytes(
+            &[vec![OPERATION_WATERMARK], operation_bytes.to_vec()].concat(),
+        )?;
+
+        Ok(OperationSignatureInfo::new(operation_bytes, &signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::thread;
+
+    use crate::test_utils::{local_signer, EDSK};
+    use super::*;
+
+    /// Serve `requests` count of Octez signer requests using local signer.
+    fn serve(signer: LocalSigner, requests: usize) -> String {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+
+        thread::spawn(move || {
+            for mut request in server.incoming_requests().take(requests) {
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+
+                let resp = if *request.method() == tiny_http::Method::Get {
+                    format!(r#"{{"public_key":"{}"}}"#, signer.public_key().to_base58check())
+                } else {
+                    // body is a hex encoded json string.
+                    let signature = signer.sign_bytes(&hex::decode(body.trim_matches('"')).unwrap());
+                    format!(r#"{{"signature":"{}"}}"#, signature.to_base58check())
+                };
+                let content_type = tiny_http::Header::from_bytes(
+                    &b"Content-Type"[..],
+                    &b"application/json"[..],
+                ).unwrap();
+                request.respond(
+                    tiny_http::Response::from_string(resp).with_header(content_type),
+                ).unwrap();
+            }
+        });
+
+        url
+    }
+
+    #[test]
+    fn test_remote_signer() {
+        let pkh = local_signer(EDSK).public_key().hash();
+
+        let remote_signer = RemoteSigner::new(serve(local_signer(EDSK), 2), pkh).unwrap();
+        assert_eq!(remote_signer.public_key(), local_signer(EDSK).public_key());
+
+        let bytes = b"operation";
+        let sig_info = remote_signer.sign_forged_operation_bytes(bytes).unwrap();
+        assert_eq!(
+            sig_info.signature,
+            local_signer(EDSK).sign_forged_operation_bytes(bytes).signature,
+        );
+
+        let other_pkh = ImplicitAddress::from_base58check("tz1e6W1pk9kkrjVTRWYZwtVFSjQQgYBmbhFp").unwrap();
+        assert!(matches!(
+            RemoteSigner::new(serve(local_signer(EDSK), 1), other_pkh),
+            Err(RemoteSignerError::PublicKeyMismatch(_)),
+        ));
+    }
+}
@@ -0,0 +1,41 @@
+use types::{Forge, PACK_PREFIX};
+use types::micheline::Micheline;
+
+/// Pack message as Micheline string (`0x05` prefixed), the same way
+/// as Taquito and Beacon (`SigningType.MICHELINE`) do.
+///
+/// Packed message can be signed by the hardware wallets too, unlike
+/// the raw bytes, which don't start with the known magic byte.
+pub fn pack_message(message: &str) -> Vec<u8> {
+    [vec![PACK_PREFIX], Micheline::str(message).forge().take()].concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use sodiumoxide::hex;
+    use crypto::ToBase58Check;
+    use types::{PublicKey, Signature};
+    use crate::verify_signature;
+    use crate::test_utils::{local_signer, EDSK, EDPK};
+    use super::*;
+
+    #[test]
+    fn test_sign_packed_message() {
+        let message = "Tezos Signed Message: example.com 2021-01-01T00:00:00Z hello";
+        let packed = pack_message(message);
+        assert_eq!(
+            hex::encode(&packed[..6]),
+            format!("0501{:08x}", message.len()),
+        );
+        assert_eq!(&packed[6..], message.as_bytes());
+
+        let signer = local_signer(EDSK);
+        let signature = signer.sign_bytes(&packed);
+        assert!(verify_signature(signer.public_key(), &packed, &signature));
+
+        let signature = Signature::from_base58check(&signature.to_base58check()).unwrap();
+        let public_key = PublicKey::from_base58check(EDPK).unwrap();
+        assert!(verify_signature(&public_key, &packed, &signature));
+        assert!(!verify_signature(&public_key, message.as_bytes(), &signature));
+    }
+}
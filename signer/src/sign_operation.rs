@@ -1,16 +1,45 @@
 use sodiumoxide::hex;
 use trezor_api::protos::TezosSignedTx;
 
+use types::Signature;
+use crypto::{blake2b, Prefix, WithPrefix, ToBase58Check};
+
+/// Watermark, prepended to the forged operation bytes before signing.
+pub const OPERATION_WATERMARK: u8 = 0x03;
+
 #[derive(Debug, Clone)]
 pub struct OperationSignatureInfo {
     /// base58check with prefix(`Prefix::operation`) encoded operation hash.
     pub operation_hash: String,
     /// forged operation(hex) concatenated with signature('hex').
     pub operation_with_signature: String,
-    /// operation signature encoded with base58check with prefix (`Prefix::edsig`).
+    /// operation signature encoded with base58check with curve specific
+    /// prefix (`Prefix::edsig`, `Prefix::spsig1` or `Prefix::p2sig`).
     pub signature: String,
 }
 
+impl OperationSignatureInfo {
+    /// Create signature info from forged operation bytes and their signature.
+    pub fn new(operation_bytes: &[u8], signature: &Signature) -> Self {
+        let operation_with_signature_bytes = [
+            operation_bytes,
+            &signature.as_ref()[..],
+        ].concat();
+
+        let operation_hash = blake2b::digest_256(
+            &operation_with_signature_bytes,
+        )
+            .with_prefix(Prefix::operation)
+            .to_base58check();
+
+        Self {
+            signature: signature.to_base58check(),
+            operation_with_signature: hex::encode(&operation_with_signature_bytes),
+            operation_hash,
+        }
+    }
+}
+
 impl From<TezosSignedTx> for OperationSignatureInfo {
     fn from(sig_info: TezosSignedTx) -> Self {
         OperationSignatureInfo {
@@ -22,10 +51,3 @@ impl From<TezosSignedTx> for OperationSignatureInfo {
         }
     }
 }
-
-pub type SignOperationResult = Result<OperationSignatureInfo, ()>;
-
-pub trait SignOperation {
-    // TODO: replace String type with newtype to avoid errors
-    fn sign_operation(&self, forged_operation: String) -> SignOperationResult;
-}
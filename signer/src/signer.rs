@@ -0,0 +1,158 @@
+use types::{PublicKey, Signature, FromPrefixedBase58CheckError};
+
+use crate::{
+    OperationSignatureInfo, OPERATION_WATERMARK,
+    LocalSigner, LocalSignerError, RemoteSigner, RemoteSignerError,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum SignerError {
+    #[error(transparent)]
+    Local(#[from] LocalSignerError),
+    #[error(transparent)]
+    Remote(#[from] RemoteSignerError),
+    #[error("signer can't sign data with magic byte {0:#04x}")]
+    UnsupportedMagicByte(u8),
+    #[error("data to sign is empty")]
+    EmptyData,
+    #[error("invalid signature: {0}")]
+    InvalidSignature(#[from] FromPrefixedBase58CheckError),
+    #[error("signed operation doesn't match the operation, that was requested to be signed")]
+    OperationMismatch,
+    /// Error of the signer, implemented outside of this crate.
+    #[error(transparent)]
+    Other(Box<dyn std::error::Error>),
+}
+
+impl SignerError {
+    pub fn other<E>(error: E) -> Self
+        where E: Into<Box<dyn std::error::Error>>,
+    {
+        Self::Other(error.into())
+    }
+}
+
+/// Backend, which holds the key and signs with it.
+///
+/// Implemented for [LocalSigner] and [RemoteSigner]. Hardware wallets
+/// and custom backends can be plugged in by implementing it as well.
+pub trait Signer {
+    /// Name of the signer shown to the user, like: "Ledger".
+    fn name(&self) -> String;
+
+    /// Message shown to the user before signing, if signing needs to
+    /// be confirmed by the user, for example on the hardware wallet.
+    fn confirmation_message(&self) -> Option<String> {
+        None
+    }
+
+    /// Public key of the key used for signing.
+    fn public_key(&mut self) -> Result<PublicKey, SignerError>;
+
+    /// Sign forged operation bytes, without the watermark.
+    fn sign_forged_operation(
+        &mut self,
+        forged_operation: &[u8],
+    ) -> Result<OperationSignatureInfo, SignerError>;
+
+    /// Sign bytes, including the watermark (magic byte).
+    ///
+    /// By default only operations (`0x03` magic byte) can be signed.
+    fn sign_bytes(&mut self, bytes: &[u8]) -> Result<Signature, SignerError> {
+        match bytes.split_first() {
+            Some((&OPERATION_WATERMARK, operation_bytes)) => {
+                let sig_info = self.sign_forged_operation(operation_bytes)?;
+                Ok(Signature::from_base58check(&sig_info.signature)?)
+            }
+            Some((&magic_byte, _)) => Err(SignerError::UnsupportedMagicByte(magic_byte)),
+            None => Err(SignerError::EmptyData),
+        }
+    }
+}
+
+impl Signer for LocalSigner {
+    fn name(&self) -> String {
+        "local wallet".to_string()
+    }
+
+    fn public_key(&mut self) -> Result<PublicKey, SignerError> {
+        Ok(LocalSigner::public_key(self).clone())
+    }
+
+    fn sign_forged_operation(
+        &mut self,
+        forged_operation: &[u8],
+    ) -> Result<OperationSignatureInfo, SignerError>
+    {
+        Ok(self.sign_forged_operation_bytes(forged_operation))
+    }
+
+    fn sign_bytes(&mut self, bytes: &[u8]) -> Result<Signature, SignerError> {
+        Ok(LocalSigner::sign_bytes(self, bytes))
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn name(&self) -> String {
+        "remote signer".to_string()
+    }
+
+    fn public_key(&mut self) -> Result<PublicKey, SignerError> {
+        Ok(RemoteSigner::public_key(self).clone())
+    }
+
+    fn sign_forged_operation(
+        &mut self,
+        forged_operation: &[u8],
+    ) -> Result<OperationSignatureInfo, SignerError>
+    {
+        Ok(self.sign_forged_operation_bytes(forged_operation)?)
+    }
+
+    fn sign_bytes(&mut self, bytes: &[u8]) -> Result<Signature, SignerError> {
+        Ok(RemoteSigner::sign_bytes(self, bytes)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::verify_signature;
+    use crate::test_utils::{local_signer, EDSK};
+    use super::*;
+
+    /// Signer, which only implements required methods.
+    struct OperationOnlySigner(LocalSigner);
+
+    impl Signer for OperationOnlySigner {
+        fn name(&self) -> String {
+            "test".to_string()
+        }
+
+        fn public_key(&mut self) -> Result<PublicKey, SignerError> {
+            Signer::public_key(&mut self.0)
+        }
+
+        fn sign_forged_operation(
+            &mut self,
+            forged_operation: &[u8],
+        ) -> Result<OperationSignatureInfo, SignerError>
+        {
+            self.0.sign_forged_operation(forged_operation)
+        }
+    }
+
+    #[test]
+    fn test_signer_default_sign_bytes() {
+        let mut signer: Box<dyn Signer> = Box::new(OperationOnlySigner(local_signer(EDSK)));
+        let public_key = signer.public_key().unwrap();
+
+        let signature = signer.sign_bytes(b"\x03operation").unwrap();
+        assert!(verify_signature(&public_key, b"\x03operation", &signature));
+
+        assert!(matches!(
+            signer.sign_bytes(b"\x01block"),
+            Err(SignerError::UnsupportedMagicByte(0x01)),
+        ));
+        assert!(matches!(signer.sign_bytes(b""), Err(SignerError::EmptyData)));
+    }
+}
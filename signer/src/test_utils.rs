@@ -0,0 +1,11 @@
+//! Fixtures shared between tests of this and dependent crates.
+
+pub use types::test_utils::*;
+
+use types::PrivateKey;
+use crate::LocalSigner;
+
+/// Create [LocalSigner] from base58check encoded private key.
+pub fn local_signer(key: &str) -> LocalSigner {
+    LocalSigner::from_private_key(PrivateKey::from_base58check(key).unwrap()).unwrap()
+}
@@ -0,0 +1,78 @@
+use std::convert::TryFrom;
+use sodiumoxide::crypto::sign::ed25519;
+use k256::ecdsa::signature::hazmat::PrehashVerifier;
+
+use types::{PublicKey, Signature};
+use crypto::blake2b;
+
+/// Verify signature of the blake2b (256 bit) hash of the given bytes.
+///
+/// Counterpart of [crate::LocalSigner::sign_bytes]. Generic (`sig`)
+/// signatures are verified using the curve of the public key.
+pub fn verify_signature(public_key: &PublicKey, bytes: &[u8], signature: &Signature) -> bool {
+    let digest = blake2b::digest_256(bytes);
+    let sig_bytes = &signature.as_ref()[..];
+
+    let is_same_curve = matches!(
+        (public_key, signature),
+        (_, Signature::sig(_))
+            | (PublicKey::edpk(_), Signature::edsig(_))
+            | (PublicKey::sppk(_), Signature::spsig1(_))
+            | (PublicKey::p2pk(_), Signature::p2sig(_))
+    );
+    if !is_same_curve {
+        return false;
+    }
+
+    match public_key {
+        PublicKey::edpk(key) => {
+            match ed25519::PublicKey::from_slice(key) {
+                // signed message is signature followed by the message.
+                Some(key) => ed25519::verify(&[sig_bytes, &digest[..]].concat(), &key).is_ok(),
+                None => false,
+            }
+        }
+        PublicKey::sppk(key) => {
+            let key = k256::ecdsa::VerifyingKey::from_sec1_bytes(key);
+            let sig = k256::ecdsa::Signature::try_from(sig_bytes);
+            match (key, sig) {
+                (Ok(key), Ok(sig)) => key.verify_prehash(&digest, &sig).is_ok(),
+                _ => false,
+            }
+        }
+        PublicKey::p2pk(key) => {
+            let key = p256::ecdsa::VerifyingKey::from_sec1_bytes(key);
+            let sig = p256::ecdsa::Signature::try_from(sig_bytes);
+            match (key, sig) {
+                (Ok(key), Ok(sig)) => key.verify_prehash(&digest, &sig).is_ok(),
+                _ => false,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{local_signer, EDSK, SPSK, P2SK};
+    use super::*;
+
+    #[test]
+    fn test_verify_signature() {
+        let signers = [EDSK, SPSK, P2SK].iter()
+            .copied()
+            .map(local_signer)
+            .collect::<Vec<_>>();
+
+        for signer in signers.iter() {
+            let signature = signer.sign_bytes(b"message");
+            assert!(verify_signature(signer.public_key(), b"message", &signature));
+            assert!(!verify_signature(signer.public_key(), b"other message", &signature));
+
+            let generic = Signature::sig(*signature.as_ref());
+            assert!(verify_signature(signer.public_key(), b"message", &generic));
+        }
+
+        let signature = signers[0].sign_bytes(b"message");
+        assert!(!verify_signature(signers[1].public_key(), b"message", &signature));
+    }
+}
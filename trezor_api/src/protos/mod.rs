@@ -10,10 +10,14 @@ pub use messages_tezos::*;
 pub mod messages_management;
 pub use messages_management::*;
 
+use std::fmt;
+use std::convert::TryFrom;
+
 use types::{
     Forge, Address, ImplicitAddress, OriginatedAddress, OriginatedAddressWithManager,
-    NewOperationGroup, NewRevealOperation, NewTransactionOperation, NewDelegationOperation,
-    NewOriginationOperation, NewTransactionParameters,
+    NewOperationGroup, NewOperation, NewRevealOperation, NewTransactionOperation, NewDelegationOperation,
+    NewOriginationOperation, NewTransactionParameters, NewProposalsOperation,
+    NewBallotOperation, Ballot,
 };
 
 impl Into<TezosSignTx_TezosContractID> for Address {
@@ -52,32 +56,92 @@ impl Into<TezosSignTx_TezosContractID> for OriginatedAddressWithManager {
 }
 
 // Operations
-impl Into<TezosSignTx> for NewOperationGroup {
-    /// Creates `TezosSignTx`, protobuf type for Trezor.
-    ///
-    /// **Warning**: make sure to set `address_n` field after, since
-    /// it's required and not added here.
-    fn into(self) -> TezosSignTx {
-        let mut new_tx = TezosSignTx::new();
-        new_tx.set_branch(self.branch.as_ref().to_vec());
 
-        if let Some(op) = self.reveal {
-            new_tx.set_reveal(op.into());
-        }
+/// Operation group can't be signed using Trezor, since it only supports
+/// signing a single operation of each kind at once and only manager
+/// and voting operations.
+#[derive(PartialEq, Debug, Clone)]
+pub enum UnsupportedOperationGroupError {
+    /// Operation of this kind is present more than once in the group.
+    Duplicate { kind: &'static str },
+    /// Trezor doesn't support operations of this kind.
+    UnsupportedKind { kind: &'static str },
+}
 
-        if let Some(op) = self.transaction {
-            new_tx.set_transaction(op.into());
+impl fmt::Display for UnsupportedOperationGroupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Duplicate { kind } => write!(
+                f,
+                "Trezor can't sign operation group with more than one {} operation",
+                kind,
+            ),
+            Self::UnsupportedKind { kind } => write!(
+                f,
+                "Trezor can't sign {} operation",
+                kind,
+            ),
         }
+    }
+}
 
-        if let Some(op) = self.delegation {
-            new_tx.set_delegation(op.into());
-        }
+impl std::error::Error for UnsupportedOperationGroupError {}
+
+impl TryFrom<NewOperationGroup> for TezosSignTx {
+    type Error = UnsupportedOperationGroupError;
 
-        if let Some(op) = self.origination {
-            new_tx.set_origination(op.into());
+    /// Creates `TezosSignTx`, protobuf type for Trezor.
+    ///
+    /// **Warning**: make sure to set `address_n` field after, since
+    /// it's required and not added here.
+    fn try_from(group: NewOperationGroup) -> Result<Self, Self::Error> {
+        let mut new_tx = TezosSignTx::new();
+        new_tx.set_branch(group.branch.as_ref().to_vec());
+
+        for op in group.operations {
+            let kind = op.kind_str();
+            let is_duplicate = match op {
+                NewOperation::Reveal(op) => {
+                    let is_duplicate = new_tx.has_reveal();
+                    new_tx.set_reveal(op.into());
+                    is_duplicate
+                }
+                NewOperation::Transaction(op) => {
+                    let is_duplicate = new_tx.has_transaction();
+                    new_tx.set_transaction(op.into());
+                    is_duplicate
+                }
+                NewOperation::Delegation(op) => {
+                    let is_duplicate = new_tx.has_delegation();
+                    new_tx.set_delegation(op.into());
+                    is_duplicate
+                }
+                NewOperation::Origination(op) => {
+                    let is_duplicate = new_tx.has_origination();
+                    new_tx.set_origination(op.into());
+                    is_duplicate
+                }
+                NewOperation::Proposals(op) => {
+                    let is_duplicate = new_tx.has_proposal();
+                    new_tx.set_proposal(op.into());
+                    is_duplicate
+                }
+                NewOperation::Ballot(op) => {
+                    let is_duplicate = new_tx.has_ballot();
+                    new_tx.set_ballot(op.into());
+                    is_duplicate
+                }
+                NewOperation::Activation(_) => {
+                    return Err(UnsupportedOperationGroupError::UnsupportedKind { kind });
+                }
+            };
+
+            if is_duplicate {
+                return Err(UnsupportedOperationGroupError::Duplicate { kind });
+            }
         }
 
-        new_tx
+        Ok(new_tx)
     }
 }
 
@@ -110,12 +174,13 @@ impl Into<TezosSignTx_TezosTransactionOp> for NewTransactionOperation {
         new_tx.set_gas_limit(self.gas_limit);
         new_tx.set_storage_limit(self.storage_limit);
 
-        match self.parameters {
-            Some(parameters) => {
-                new_tx.set_parameters_manager(parameters.into());
+        if let Some(parameters) = self.parameters {
+            match manager_parameters(&parameters) {
+                Some(manager_params) => new_tx.set_parameters_manager(manager_params),
+                // generic contract calls are passed as forged parameters.
+                None => new_tx.set_parameters(parameters.forge().take()),
             }
-            None => {}
-        };
+        }
 
         new_tx
     }
@@ -157,26 +222,70 @@ impl Into<TezosSignTx_TezosOriginationOp> for NewOriginationOperation {
     }
 }
 
-impl Into<TezosSignTx_TezosTransactionOp_TezosParametersManager> for NewTransactionParameters {
-    /// Creates `TezosSignTx_TezosTransactionOp_TezosParametersManager`, protobuf type for Trezor.
-    fn into(self) -> TezosSignTx_TezosTransactionOp_TezosParametersManager {
-        let mut params = TezosSignTx_TezosTransactionOp_TezosParametersManager::new();
+impl Into<TezosSignTx_TezosProposalOp> for NewProposalsOperation {
+    /// Creates `TezosSignTx_TezosProposalOp`, protobuf type for Trezor.
+    fn into(self) -> TezosSignTx_TezosProposalOp {
+        let mut new_op = TezosSignTx_TezosProposalOp::new();
+
+        new_op.set_source(self.source.forge().take());
+        new_op.set_period(self.period as u64);
+        new_op.set_proposals(
+            self.proposals.iter()
+                .map(|proposal| proposal.as_ref().to_vec())
+                .collect()
+        );
+
+        new_op
+    }
+}
 
+impl Into<TezosSignTx_TezosBallotOp_TezosBallotType> for Ballot {
+    fn into(self) -> TezosSignTx_TezosBallotOp_TezosBallotType {
         match self {
-            Self::SetDelegate(addr) => {
-                params.set_set_delegate(addr.forge().take());
-            }
-            Self::CancelDelegate => {
-                params.set_cancel_delegate(true);
-            }
-            Self::Transfer { to, amount } => {
-                let mut transfer = TezosSignTx_TezosTransactionOp_TezosParametersManager_TezosManagerTransfer::new();
-                transfer.set_destination(to.into());
-                transfer.set_amount(amount);
+            Self::Yay => TezosSignTx_TezosBallotOp_TezosBallotType::Yay,
+            Self::Nay => TezosSignTx_TezosBallotOp_TezosBallotType::Nay,
+            Self::Pass => TezosSignTx_TezosBallotOp_TezosBallotType::Pass,
+        }
+    }
+}
 
-                params.set_transfer(transfer);
-            }
+impl Into<TezosSignTx_TezosBallotOp> for NewBallotOperation {
+    /// Creates `TezosSignTx_TezosBallotOp`, protobuf type for Trezor.
+    fn into(self) -> TezosSignTx_TezosBallotOp {
+        let mut new_op = TezosSignTx_TezosBallotOp::new();
+
+        new_op.set_source(self.source.forge().take());
+        new_op.set_period(self.period as u64);
+        new_op.set_proposal(self.proposal.as_ref().to_vec());
+        new_op.set_ballot(self.ballot.into());
+
+        new_op
+    }
+}
+
+/// Creates `TezosSignTx_TezosTransactionOp_TezosParametersManager`, protobuf type for Trezor.
+///
+/// Returns `None` if parameters aren't a manager.tz `do` entrypoint call.
+fn manager_parameters(
+    parameters: &NewTransactionParameters,
+) -> Option<TezosSignTx_TezosTransactionOp_TezosParametersManager> {
+    let mut params = TezosSignTx_TezosTransactionOp_TezosParametersManager::new();
+
+    match parameters {
+        NewTransactionParameters::SetDelegate(addr) => {
+            params.set_set_delegate(addr.forge().take());
+        }
+        NewTransactionParameters::CancelDelegate => {
+            params.set_cancel_delegate(true);
         }
-        params
+        NewTransactionParameters::Transfer { to, amount } => {
+            let mut transfer = TezosSignTx_TezosTransactionOp_TezosParametersManager_TezosManagerTransfer::new();
+            transfer.set_destination(to.clone().into());
+            transfer.set_amount(*amount);
+
+            params.set_transfer(transfer);
+        }
+        NewTransactionParameters::Call { .. } => return None,
     }
-}
\ No newline at end of file
+    Some(params)
+}
@@ -1,7 +1,7 @@
 use std::convert::TryInto;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crypto::{Prefix, WithPrefix, WithoutPrefix};
+use crypto::{blake2b, Prefix, WithPrefix, WithoutPrefix};
 use crypto::base58check::{FromBase58Check, ToBase58Check};
 use crate::{ImplicitAddress, FromPrefixedBase58CheckError};
 use super::ADDRESS_LEN;
@@ -25,6 +25,28 @@ impl OriginatedAddress {
         Ok(Self(inner))
     }
 
+    /// Address of the contract, originated by the operation.
+    ///
+    /// `operation_hash` is a hash of the signed operation group (`o...`)
+    /// and `index` is the number of the originations preceding this one
+    /// in the group.
+    pub fn from_origination_nonce(
+        operation_hash: &str,
+        index: u32,
+    ) -> Result<Self, FromPrefixedBase58CheckError>
+    {
+        let operation_hash = operation_hash
+            .from_base58check()?
+            .without_prefix(Prefix::operation)?;
+        let nonce = [&operation_hash[..], &index.to_be_bytes()[..]].concat();
+
+        let inner = blake2b::digest_160(&nonce)
+            .try_into()
+            .or(Err(FromPrefixedBase58CheckError::InvalidSize))?;
+
+        Ok(Self(inner))
+    }
+
     pub fn get_prefix(&self) -> Prefix {
         Prefix::KT1
     }
@@ -43,6 +65,12 @@ impl ToBase58Check for OriginatedAddress {
     }
 }
 
+impl From<OriginatedAddressInner> for OriginatedAddress {
+    fn from(inner: OriginatedAddressInner) -> Self {
+        Self(inner)
+    }
+}
+
 impl AsRef<OriginatedAddressInner> for OriginatedAddress {
     fn as_ref(&self) -> &OriginatedAddressInner {
         &self.0
@@ -95,3 +123,24 @@ impl AsRef<[u8]> for OriginatedAddressWithManager {
         self.address.as_ref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_origination_nonce() {
+        let operation_hash = "onuWAJXAAtRZDxLTZbaegzBh4PtwKcc4AsXvorwAHitqeFYbUA6";
+        let address = |index| {
+            OriginatedAddress::from_origination_nonce(operation_hash, index)
+                .map(|address| address.to_base58check())
+        };
+
+        assert_eq!(address(0), Ok("KT1QCXkdPprgZtpPt4Z7yiv3BGtrrwwVXYgp".to_string()));
+        assert_eq!(address(1), Ok("KT1BcoCE1TQRn8xRpH1VQ9RVUyiUTmE7t2aQ".to_string()));
+        assert!(OriginatedAddress::from_origination_nonce(
+            "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn",
+            0,
+        ).is_err());
+    }
+}
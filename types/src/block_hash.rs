@@ -7,7 +7,7 @@ use super::FromPrefixedBase58CheckError;
 
 type BlockHashInner = [u8; 32];
 
-#[derive(Debug, Clone)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct BlockHash(BlockHashInner);
 
 impl BlockHash {
@@ -30,6 +30,12 @@ impl ToBase58Check for BlockHash {
     }
 }
 
+impl From<BlockHashInner> for BlockHash {
+    fn from(inner: BlockHashInner) -> Self {
+        Self(inner)
+    }
+}
+
 impl AsRef<BlockHashInner> for BlockHash {
     fn as_ref(&self) -> &BlockHashInner {
         &self.0
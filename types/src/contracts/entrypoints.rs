@@ -0,0 +1,97 @@
+use crate::micheline::{Micheline, PrimType};
+
+/// Entrypoint of the smart contract.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ContractEntrypoint {
+    pub name: String,
+    /// Type of the entrypoint's parameter.
+    pub parameter_type: Micheline,
+}
+
+/// Entrypoints of the contract with the given parameter type.
+///
+/// Entrypoints are branches of the `or` type, marked with field
+/// annotations (`%name`). Whole parameter is the `default` entrypoint,
+/// unless one of the branches is annotated with `%default`.
+pub fn contract_entrypoints(parameter_type: &Micheline) -> Vec<ContractEntrypoint> {
+    let mut entrypoints = vec![];
+    collect_entrypoints(parameter_type, &mut entrypoints);
+
+    if !entrypoints.iter().any(|entrypoint| entrypoint.name == "default") {
+        entrypoints.insert(0, ContractEntrypoint {
+            name: "default".to_string(),
+            parameter_type: without_field_annots(parameter_type),
+        });
+    }
+    entrypoints
+}
+
+fn collect_entrypoints(ty: &Micheline, entrypoints: &mut Vec<ContractEntrypoint>) {
+    let prim = match ty {
+        Micheline::Prim(prim) => prim,
+        _ => return,
+    };
+
+    if let Some(name) = prim.annots().iter().find_map(|annot| annot.strip_prefix('%')) {
+        entrypoints.push(ContractEntrypoint {
+            name: name.to_string(),
+            parameter_type: without_field_annots(ty),
+        });
+    }
+
+    if prim.prim_type == PrimType::or {
+        for arg in prim.args.iter().flatten() {
+            collect_entrypoints(arg, entrypoints);
+        }
+    }
+}
+
+/// Field annotation only names the entrypoint, it isn't a part of its type.
+fn without_field_annots(ty: &Micheline) -> Micheline {
+    match ty {
+        Micheline::Prim(prim) => {
+            let mut prim = prim.clone();
+            prim.retain_annots(|annot| !annot.starts_with('%'));
+            prim.into()
+        }
+        _ => ty.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entrypoints(parameter_type: &str) -> Vec<(String, String)> {
+        contract_entrypoints(&parameter_type.parse().unwrap()).into_iter()
+            .map(|entrypoint| (entrypoint.name, entrypoint.parameter_type.to_string()))
+            .collect()
+    }
+
+    fn entrypoint(name: &str, ty: &str) -> (String, String) {
+        (name.to_string(), ty.to_string())
+    }
+
+    #[test]
+    fn test_contract_entrypoints() {
+        assert_eq!(entrypoints("nat"), vec![entrypoint("default", "nat")]);
+        assert_eq!(
+            entrypoints("or (pair %transfer address nat) (or (unit %default) (nat %burn))"),
+            vec![
+                entrypoint("transfer", "pair address nat"),
+                entrypoint("default", "unit"),
+                entrypoint("burn", "nat"),
+            ],
+        );
+        assert_eq!(
+            entrypoints("or (nat %add) (or %other (nat %sub) (unit %reset))"),
+            vec![
+                entrypoint("default", "or (nat %add) (or %other (nat %sub) (unit %reset))"),
+                entrypoint("add", "nat"),
+                entrypoint("other", "or (nat %sub) (unit %reset)"),
+                entrypoint("sub", "nat"),
+                entrypoint("reset", "unit"),
+            ],
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{Address, NewTransactionParameters, pack, script_expr_hash};
+use crate::micheline::{Micheline, MichelineEntrypoint, PrimType, BigUint};
+use super::{TokenError, ty, prim, pair, pair_args, nat, address, nat_value, address_value};
+
+/// Call of the FA1.2 (TZIP-7) token contract's entrypoint.
+///
+/// Amounts are in the smallest units of the token (see [parse_token_amount]).
+///
+/// [parse_token_amount]: crate::parse_token_amount
+#[derive(PartialEq, Debug, Clone)]
+pub enum Fa12Call {
+    /// Transfer tokens of `from` to `to`.
+    ///
+    /// If sender isn't `from`, it needs to be approved by `from` first.
+    Transfer {
+        from: Address,
+        to: Address,
+        value: BigUint,
+    },
+    /// Allow `spender` to transfer up to `value` tokens of the sender.
+    ///
+    /// Most contracts require allowance to be set to 0, before
+    /// changing it to another non zero value.
+    Approve {
+        spender: Address,
+        value: BigUint,
+    },
+}
+
+impl From<Fa12Call> for NewTransactionParameters {
+    fn from(call: Fa12Call) -> Self {
+        let (entrypoint, value) = match call {
+            Fa12Call::Transfer { from, to, value } => (
+                "transfer",
+                pair(address_value(&from), pair(address_value(&to), nat_value(&value))),
+            ),
+            Fa12Call::Approve { spender, value } => (
+                "approve",
+                pair(address_value(&spender), nat_value(&value)),
+            ),
+        };
+
+        // unwrap is fine since entrypoint name is short enough.
+        NewTransactionParameters::Call {
+            entrypoint: MichelineEntrypoint::from_name(entrypoint).unwrap(),
+            value,
+        }
+    }
+}
+
+/// View entrypoint of the FA1.2 token contract.
+///
+/// Views return the result to the callback contract, but they can be
+/// executed off-chain as well, without the callback (see `RunView`).
+#[derive(PartialEq, Debug, Clone)]
+pub enum Fa12View {
+    GetBalance {
+        owner: Address,
+    },
+    GetAllowance {
+        owner: Address,
+        spender: Address,
+    },
+    GetTotalSupply,
+}
+
+impl Fa12View {
+    pub fn entrypoint(&self) -> &'static str {
+        match self {
+            Self::GetBalance { .. } => "getBalance",
+            Self::GetAllowance { .. } => "getAllowance",
+            Self::GetTotalSupply => "getTotalSupply",
+        }
+    }
+
+    /// Input of the view, without the callback.
+    pub fn input(&self) -> Micheline {
+        match self {
+            Self::GetBalance { owner } => address_value(owner),
+            Self::GetAllowance { owner, spender } => {
+                pair(address_value(owner), address_value(spender))
+            }
+            Self::GetTotalSupply => prim(PrimType::Unit).into(),
+        }
+    }
+
+    /// Parse amount returned by the view.
+    pub fn parse_result(&self, result: &Micheline) -> Result<BigUint, TokenError> {
+        nat(result).ok_or_else(|| TokenError::UnexpectedValue(result.clone()))
+    }
+}
+
+/// Script expression hash of the `owner`, used as a key of the
+/// ledger big map (`big_map address ...`).
+pub fn fa12_ledger_key(owner: &Address) -> String {
+    let packed = pack(&address_value(owner), &ty("address"))
+        .expect("address is always packed");
+    script_expr_hash(&packed)
+}
+
+/// Entry of the FA1.2 ledger big map for a single owner.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Fa12LedgerEntry {
+    pub balance: BigUint,
+    /// Approved spenders with their allowances.
+    pub allowances: Vec<(Address, BigUint)>,
+}
+
+impl Fa12LedgerEntry {
+    /// Parse the value of the ledger big map.
+    ///
+    /// Common layouts are supported: `nat` (balance only), or balance
+    /// along with the allowances: `pair nat (map address nat)` or
+    /// `pair (map address nat) nat`.
+    pub fn from_micheline(value: &Micheline) -> Result<Self, TokenError> {
+        let unexpected = || TokenError::UnexpectedValue(value.clone());
+
+        let (balance, allowances) = match pair_args(value) {
+            Some((balance @ Micheline::Int(_), allowances))
+            | Some((allowances, balance @ Micheline::Int(_))) => (balance, Some(allowances)),
+            Some(_) => return Err(unexpected()),
+            None => (value, None),
+        };
+
+        let allowances = match allowances {
+            Some(Micheline::Array(items)) => items.iter()
+                .map(|item| match item {
+                    Micheline::Prim(prim) if prim.prim_type == PrimType::Elt => {
+                        match prim.args.as_deref() {
+                            Some([spender, value]) => Some((address(spender)?, nat(value)?)),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                })
+                .collect::<Option<_>>()
+                .ok_or_else(unexpected)?,
+            Some(_) => return Err(unexpected()),
+            None => vec![],
+        };
+
+        Ok(Self {
+            balance: nat(balance).ok_or_else(unexpected)?,
+            allowances,
+        })
+    }
+
+    /// Amount of tokens `spender` is allowed to transfer.
+    pub fn allowance(&self, spender: &Address) -> BigUint {
+        self.allowances.iter()
+            .find(|(address, _)| address == spender)
+            .map(|(_, value)| value.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Forge;
+    use crate::test_utils::address;
+    use super::*;
+
+    #[test]
+    fn test_fa12_call_parameters() {
+        let parameters = NewTransactionParameters::from(Fa12Call::Transfer {
+            from: address("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"),
+            to: address("KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn"),
+            value: BigUint::from(100u32),
+        });
+        assert_eq!(
+            parameters,
+            NewTransactionParameters::Call {
+                entrypoint: MichelineEntrypoint::from_name("transfer").unwrap(),
+                value: r#"Pair "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx" (Pair "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn" 100)"#
+                    .parse().unwrap(),
+            },
+        );
+        // `transfer` isn't one of the predefined entrypoints.
+        assert_eq!(parameters.forge().as_ref()[0], 0xff);
+    }
+
+    #[test]
+    fn test_fa12_ledger_entry() {
+        let spender = address("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx");
+        let entry = |value: &str| Fa12LedgerEntry::from_micheline(&value.parse().unwrap());
+
+        assert_eq!(
+            entry("7"),
+            Ok(Fa12LedgerEntry { balance: BigUint::from(7u32), allowances: vec![] }),
+        );
+        for value in &[
+            r#"Pair 7 { Elt "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx" 3 }"#,
+            r#"Pair { Elt "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx" 3 } 7"#,
+        ] {
+            let entry = entry(value).unwrap();
+            assert_eq!(entry.balance, BigUint::from(7u32));
+            assert_eq!(entry.allowance(&spender), BigUint::from(3u32));
+        }
+        assert!(matches!(entry(r#""foo""#), Err(TokenError::UnexpectedValue(_))));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{Address, NewTransactionParameters};
+use crate::micheline::{Micheline, MichelineEntrypoint, PrimType, BigUint};
+use super::{TokenError, prim, pair, pair_args, nat, address, nat_value, address_value};
+
+/// Single transfer of the FA2 (TZIP-12) `transfer` entrypoint.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Fa2TransferDestination {
+    pub to: Address,
+    pub token_id: BigUint,
+    /// Amount in the smallest units of the token.
+    pub amount: BigUint,
+}
+
+/// Transfers of the tokens owned by `from`.
+///
+/// If sender isn't `from`, it needs to be an operator of `from`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Fa2Transfer {
+    pub from: Address,
+    pub txs: Vec<Fa2TransferDestination>,
+}
+
+/// Operator, which can transfer `owner`'s tokens with `token_id`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Fa2Operator {
+    pub owner: Address,
+    pub operator: Address,
+    pub token_id: BigUint,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum Fa2OperatorUpdate {
+    Add(Fa2Operator),
+    Remove(Fa2Operator),
+}
+
+/// Call of the FA2 token contract's entrypoint.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Fa2Call {
+    /// Batch of transfers, each with several destinations.
+    Transfer(Vec<Fa2Transfer>),
+    UpdateOperators(Vec<Fa2OperatorUpdate>),
+}
+
+impl From<Fa2Call> for NewTransactionParameters {
+    fn from(call: Fa2Call) -> Self {
+        let (entrypoint, items) = match call {
+            Fa2Call::Transfer(transfers) => (
+                "transfer",
+                transfers.iter()
+                    .map(|transfer| pair(
+                        address_value(&transfer.from),
+                        Micheline::Array(transfer.txs.iter()
+                            .map(|tx| pair(
+                                address_value(&tx.to),
+                                pair(nat_value(&tx.token_id), nat_value(&tx.amount)),
+                            ))
+                            .collect()),
+                    ))
+                    .collect(),
+            ),
+            Fa2Call::UpdateOperators(updates) => (
+                "update_operators",
+                updates.iter()
+                    .map(|update| {
+                        let (side, operator) = match update {
+                            Fa2OperatorUpdate::Add(operator) => (PrimType::Left, operator),
+                            Fa2OperatorUpdate::Remove(operator) => (PrimType::Right, operator),
+                        };
+                        prim(side)
+                            .with_arg(pair(
+                                address_value(&operator.owner),
+                                pair(
+                                    address_value(&operator.operator),
+                                    nat_value(&operator.token_id),
+                                ),
+                            ))
+                            .into()
+                    })
+                    .collect(),
+            ),
+        };
+
+        // unwrap is fine since entrypoint name is short enough.
+        NewTransactionParameters::Call {
+            entrypoint: MichelineEntrypoint::from_name(entrypoint).unwrap(),
+            value: Micheline::Array(items),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Fa2BalanceRequest {
+    pub owner: Address,
+    pub token_id: BigUint,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Fa2BalanceResponse {
+    pub request: Fa2BalanceRequest,
+    pub balance: BigUint,
+}
+
+/// `balance_of` view of the FA2 token contract.
+///
+/// Can be executed off-chain, without the callback (see `RunView`).
+#[derive(PartialEq, Debug, Clone)]
+pub struct Fa2BalanceOf {
+    pub requests: Vec<Fa2BalanceRequest>,
+}
+
+impl Fa2BalanceOf {
+    pub fn entrypoint(&self) -> &'static str {
+        "balance_of"
+    }
+
+    /// Input of the view, without the callback.
+    pub fn input(&self) -> Micheline {
+        Micheline::Array(self.requests.iter()
+            .map(|request| pair(address_value(&request.owner), nat_value(&request.token_id)))
+            .collect())
+    }
+
+    /// Parse balances returned by the view.
+    pub fn parse_result(&self, result: &Micheline) -> Result<Vec<Fa2BalanceResponse>, TokenError> {
+        let unexpected = || TokenError::UnexpectedValue(result.clone());
+
+        let items = match result {
+            Micheline::Array(items) => items,
+            _ => return Err(unexpected()),
+        };
+
+        items.iter()
+            .map(|item| {
+                let (request, balance) = pair_args(item)?;
+                let (owner, token_id) = pair_args(request)?;
+                Some(Fa2BalanceResponse {
+                    request: Fa2BalanceRequest {
+                        owner: address(owner)?,
+                        token_id: nat(token_id)?,
+                    },
+                    balance: nat(balance)?,
+                })
+            })
+            .collect::<Option<_>>()
+            .ok_or_else(unexpected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::address;
+    use super::*;
+
+    #[test]
+    fn test_fa2_call_parameters() {
+        let owner = address("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx");
+        let other = address("KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn");
+
+        let transfer = NewTransactionParameters::from(Fa2Call::Transfer(vec![Fa2Transfer {
+            from: owner.clone(),
+            txs: vec![
+                Fa2TransferDestination { to: other.clone(), token_id: 0u32.into(), amount: 10u32.into() },
+                Fa2TransferDestination { to: other.clone(), token_id: 1u32.into(), amount: 1u32.into() },
+            ],
+        }]));
+        assert_eq!(
+            transfer,
+            NewTransactionParameters::Call {
+                entrypoint: MichelineEntrypoint::from_name("transfer").unwrap(),
+                value: r#"{ Pair "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"
+                                 { Pair "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn" (Pair 0 10) ;
+                                   Pair "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn" (Pair 1 1) } }"#
+                    .parse().unwrap(),
+            },
+        );
+
+        let operator = Fa2Operator { owner, operator: other, token_id: 3u32.into() };
+        let update = NewTransactionParameters::from(Fa2Call::UpdateOperators(vec![
+            Fa2OperatorUpdate::Add(operator.clone()),
+            Fa2OperatorUpdate::Remove(operator),
+        ]));
+        assert_eq!(
+            update,
+            NewTransactionParameters::Call {
+                entrypoint: MichelineEntrypoint::from_name("update_operators").unwrap(),
+                value: r#"{ Left (Pair "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx" (Pair "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn" 3)) ;
+                            Right (Pair "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx" (Pair "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn" 3)) }"#
+                    .parse().unwrap(),
+            },
+        );
+    }
+
+    #[test]
+    fn test_fa2_balance_of() {
+        let owner = address("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx");
+        let view = Fa2BalanceOf {
+            requests: vec![Fa2BalanceRequest { owner: owner.clone(), token_id: 5u32.into() }],
+        };
+        assert_eq!(
+            view.input(),
+            r#"{ Pair "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx" 5 }"#.parse().unwrap(),
+        );
+
+        let result = r#"{ Pair (Pair "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx" 5) 42 }"#.parse().unwrap();
+        assert_eq!(
+            view.parse_result(&result),
+            Ok(vec![Fa2BalanceResponse {
+                request: view.requests[0].clone(),
+                balance: 42u32.into(),
+            }]),
+        );
+        assert!(view.parse_result(&Micheline::int(1)).is_err());
+    }
+}
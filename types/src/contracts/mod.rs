@@ -0,0 +1,71 @@
+//! Helpers for interacting with well known smart contracts.
+
+use crypto::ToBase58Check;
+use crate::{Address, Unforge};
+use crate::micheline::{Micheline, MichelinePrim, PrimType, BigInt, BigUint};
+
+mod token;
+pub use token::*;
+
+mod multisig;
+pub use multisig::*;
+
+mod fa12;
+pub use fa12::*;
+
+mod fa2;
+pub use fa2::*;
+
+mod entrypoints;
+pub use entrypoints::*;
+
+fn ty(source: &str) -> Micheline {
+    source.parse().expect("contract types are valid michelson")
+}
+
+fn prim(prim_type: PrimType) -> MichelinePrim {
+    MichelinePrim::new(prim_type)
+}
+
+fn pair(left: Micheline, right: Micheline) -> Micheline {
+    prim(PrimType::Pair).with_args(vec![left, right]).into()
+}
+
+/// Arguments of the `Pair` with exactly two arguments.
+fn pair_args(value: &Micheline) -> Option<(&Micheline, &Micheline)> {
+    match value {
+        Micheline::Prim(prim) if prim.prim_type == PrimType::Pair => {
+            match prim.args.as_deref() {
+                Some([left, right]) => Some((left, right)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Value of the `nat` returned by the contract.
+fn nat(value: &Micheline) -> Option<BigUint> {
+    match value {
+        Micheline::Int(num) => num.to_biguint(),
+        _ => None,
+    }
+}
+
+/// Address, returned by the node either in readable (base58check),
+/// or optimized (binary) form.
+fn address(value: &Micheline) -> Option<Address> {
+    match value {
+        Micheline::String(address) => Address::from_base58check(address).ok(),
+        Micheline::Bytes(bytes) => Address::unforge(bytes).ok(),
+        _ => None,
+    }
+}
+
+fn nat_value(value: &BigUint) -> Micheline {
+    Micheline::Int(BigInt::from(value.clone()))
+}
+
+fn address_value(address: &Address) -> Micheline {
+    Micheline::str(address.to_base58check())
+}
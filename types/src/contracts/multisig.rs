@@ -0,0 +1,312 @@
+use serde::{Serialize, Deserialize};
+use num_traits::ToPrimitive;
+
+use crypto::ToBase58Check;
+use crate::{
+    Address, ImplicitAddress, OriginatedAddress, PublicKey, Signature,
+    NewTransactionParameters, PackError, pack, unpack,
+};
+use crate::micheline::{Micheline, MichelineEntrypoint, PrimType};
+use super::{ty, prim, pair, pair_args};
+
+/// Type of the generic multisig contract's storage: stored counter,
+/// threshold and the keys.
+const STORAGE_TYPE: &str = "pair nat nat (list key)";
+
+/// Type of the data, which needs to be signed by the keys, in order
+/// for the action to be executed.
+const PAYLOAD_TYPE: &str =
+    "pair (pair chain_id address) (pair nat (or (lambda unit (list operation)) (pair nat (list key))))";
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+pub enum MultisigError {
+    #[error("storage doesn't match the generic multisig contract's storage: {0}")]
+    InvalidStorage(PackError),
+    #[error("invalid multisig payload: {0}")]
+    InvalidPayload(PackError),
+}
+
+/// Storage of the generic multisig contract.
+#[derive(PartialEq, Debug, Clone)]
+pub struct MultisigStorage {
+    /// Counter of the executed actions, used to prevent replay attacks.
+    pub counter: u64,
+    /// Number of signatures required to execute an action.
+    pub threshold: u64,
+    pub keys: Vec<PublicKey>,
+}
+
+impl MultisigStorage {
+    /// Parse storage returned by the node (see `GetContractStorage`).
+    pub fn from_micheline(storage: &Micheline) -> Result<Self, MultisigError> {
+        let storage_type = ty(STORAGE_TYPE);
+        let invalid = || MultisigError::InvalidStorage(PackError::TypeMismatch {
+            value: storage.clone(),
+            ty: storage_type.clone(),
+        });
+
+        // round trip normalizes combs into nested pairs and keys into
+        // their readable form, regardless of how node returned them.
+        let normalized = pack(storage, &storage_type)
+            .and_then(|packed| unpack(&packed, &storage_type))
+            .map_err(MultisigError::InvalidStorage)?;
+
+        let nat = |value: &Micheline| match value {
+            Micheline::Int(num) => num.to_u64(),
+            _ => None,
+        };
+
+        let (counter, rest) = pair_args(&normalized).ok_or_else(invalid)?;
+        let (threshold, keys) = pair_args(rest).ok_or_else(invalid)?;
+        let keys = match keys {
+            Micheline::Array(keys) => keys,
+            _ => return Err(invalid()),
+        };
+
+        Ok(Self {
+            counter: nat(counter).ok_or_else(invalid)?,
+            threshold: nat(threshold).ok_or_else(invalid)?,
+            keys: keys.iter()
+                .map(|key| match key {
+                    Micheline::String(key) => PublicKey::from_base58check(key).ok(),
+                    _ => None,
+                })
+                .collect::<Option<_>>()
+                .ok_or_else(invalid)?,
+        })
+    }
+}
+
+/// Action, executed by the generic multisig contract, once enough
+/// signatures are collected.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MultisigAction {
+    /// Transfer funds from the multisig contract.
+    Transfer {
+        to: Address,
+        #[serde(with = "utils::serde_amount")]
+        amount: u64,
+    },
+    /// Set or withdraw (if `None`) delegate of the multisig contract.
+    Delegate {
+        delegate: Option<ImplicitAddress>,
+    },
+    /// Replace keys controlling the multisig contract.
+    ChangeKeys {
+        threshold: u64,
+        keys: Vec<PublicKey>,
+    },
+}
+
+impl MultisigAction {
+    /// Value of the `action` argument of the `main` entrypoint.
+    pub fn to_micheline(&self) -> Micheline {
+        let operation = |parameters: NewTransactionParameters| -> Micheline {
+            prim(PrimType::Left)
+                .with_arg(parameters.manager_lambda().expect("manager lambda for an action"))
+                .into()
+        };
+
+        match self {
+            Self::Transfer { to, amount } => operation(NewTransactionParameters::Transfer {
+                to: to.clone(),
+                amount: *amount,
+            }),
+            Self::Delegate { delegate: Some(delegate) } => {
+                operation(NewTransactionParameters::SetDelegate(delegate.clone()))
+            }
+            Self::Delegate { delegate: None } => operation(NewTransactionParameters::CancelDelegate),
+            Self::ChangeKeys { threshold, keys } => prim(PrimType::Right)
+                .with_arg(pair(
+                    Micheline::int(*threshold),
+                    Micheline::Array(keys.iter()
+                        .map(|key| Micheline::str(key.to_base58check()))
+                        .collect()),
+                ))
+                .into(),
+        }
+    }
+}
+
+/// Action of the specific multisig contract, bound to the chain and
+/// the current counter of the contract, so that signatures can't be
+/// replayed.
+#[derive(PartialEq, Debug, Clone)]
+pub struct MultisigPayload {
+    /// Chain id (`Net...`), as returned by `GetChainID`.
+    pub chain_id: String,
+    pub contract: OriginatedAddress,
+    pub counter: u64,
+    pub action: MultisigAction,
+}
+
+impl MultisigPayload {
+    /// Packed bytes, which need to be signed by the keys of the
+    /// multisig contract (including the `0x05` prefix).
+    pub fn bytes_to_sign(&self) -> Result<Vec<u8>, MultisigError> {
+        let value = pair(
+            pair(
+                Micheline::str(&self.chain_id),
+                Micheline::str(self.contract.to_base58check()),
+            ),
+            pair(Micheline::int(self.counter), self.action.to_micheline()),
+        );
+
+        pack(&value, &ty(PAYLOAD_TYPE)).map_err(MultisigError::InvalidPayload)
+    }
+
+    /// Parameters for calling `main` entrypoint of the multisig contract.
+    ///
+    /// `signatures` must be in the same order as keys in the contract's
+    /// storage, with `None` for keys that didn't sign.
+    pub fn main_parameters(&self, signatures: &[Option<Signature>]) -> NewTransactionParameters {
+        let signatures = signatures.iter()
+            .map(|signature| match signature {
+                Some(signature) => prim(PrimType::Some)
+                    .with_arg(Micheline::str(signature.to_base58check()))
+                    .into(),
+                None => prim(PrimType::None).into(),
+            })
+            .collect();
+
+        // unwrap is fine since entrypoint name is short enough.
+        NewTransactionParameters::Call {
+            entrypoint: MichelineEntrypoint::from_name("main").unwrap(),
+            value: pair(
+                pair(Micheline::int(self.counter), self.action.to_micheline()),
+                Micheline::Array(signatures),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto::hex;
+    use super::*;
+
+    #[test]
+    fn test_multisig_storage_from_micheline() {
+        let key = "edpkvGfYw3LyB1UcCahKQk4rF2tvbMUk8GFiTuMjL75uGXrpvKXhjn";
+        let storage: Micheline = serde_json::from_value(serde_json::json!({
+            "prim": "Pair",
+            "args": [{ "int": "3" }, { "int": "1" }, [{ "string": key }]],
+        })).unwrap();
+
+        assert_eq!(
+            MultisigStorage::from_micheline(&storage),
+            Ok(MultisigStorage {
+                counter: 3,
+                threshold: 1,
+                keys: vec![PublicKey::from_base58check(key).unwrap()],
+            }),
+        );
+        assert!(matches!(
+            MultisigStorage::from_micheline(&Micheline::int(1)),
+            Err(MultisigError::InvalidStorage(_)),
+        ));
+    }
+
+    #[test]
+    fn test_multisig_payload() {
+        let payload = MultisigPayload {
+            chain_id: "NetXdQprcVkpaWU".to_string(),
+            contract: OriginatedAddress::from_base58check(
+                "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn",
+            ).unwrap(),
+            counter: 5,
+            action: MultisigAction::Delegate { delegate: None },
+        };
+
+        let bytes = payload.bytes_to_sign().unwrap();
+        // Pair (Pair chain_id address) (Pair 5 (Left { DROP ; NIL operation ; NONE key_hash ; SET_DELEGATE ; CONS }))
+        assert_eq!(
+            hex::encode(&bytes),
+            concat!(
+                "05070707070a000000047a06a770",
+                "0a0000001601a3d0f58d8964bd1b37fb0a0c197b38cf46608d4900",
+                "070700050505020000000e",
+                "0320053d036d053e035d034e031b",
+            ),
+        );
+
+        match payload.main_parameters(&[None]) {
+            NewTransactionParameters::Call { entrypoint, value } => {
+                assert_eq!(entrypoint.name(), "main");
+                assert_eq!(
+                    value,
+                    "Pair (Pair 5 (Left { DROP ; NIL operation ; NONE key_hash ; SET_DELEGATE ; CONS })) { None }"
+                        .parse().unwrap(),
+                );
+            }
+            params => panic!("unexpected parameters: {:?}", params),
+        }
+    }
+
+    #[test]
+    fn test_multisig_payload_lambdas() {
+        let implicit = ImplicitAddress::from_base58check(
+            "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx",
+        ).unwrap();
+        let originated = OriginatedAddress::from_base58check(
+            "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn",
+        ).unwrap();
+
+        let actions = vec![
+            (
+                // { DROP ; NIL operation ; PUSH key_hash "tz1..." ; SOME ; SET_DELEGATE ; CONS }
+                MultisigAction::Delegate { delegate: Some(implicit.clone()) },
+                concat!(
+                    "020000002a0320053d036d",
+                    "0743035d0a000000150002298c03ed7d454a101eb7022bc95f7e5f41ac78",
+                    "0346034e031b",
+                ),
+            ),
+            (
+                // { DROP ; NIL operation ; PUSH key_hash "tz1..." ; IMPLICIT_ACCOUNT ;
+                //   PUSH mutez 1500000 ; UNIT ; TRANSFER_TOKENS ; CONS }
+                MultisigAction::Transfer { to: implicit.into(), amount: 1_500_000 },
+                concat!(
+                    "02000000350320053d036d",
+                    "0743035d0a000000150002298c03ed7d454a101eb7022bc95f7e5f41ac78031e",
+                    "0743036a00a08db701034f034d031b",
+                ),
+            ),
+            (
+                // { DROP ; NIL operation ; PUSH address "KT1..." ; CONTRACT unit ;
+                //   { IF_NONE { { UNIT ; FAILWITH } } {} } ;
+                //   PUSH mutez 1500000 ; UNIT ; TRANSFER_TOKENS ; CONS }
+                MultisigAction::Transfer { to: originated.clone().into(), amount: 1_500_000 },
+                concat!(
+                    "02000000520320053d036d",
+                    "0743036e0a0000001601a3d0f58d8964bd1b37fb0a0c197b38cf46608d4900",
+                    "0555036c0200000015072f02000000090200000004034f03270200000000",
+                    "0743036a00a08db701034f034d031b",
+                ),
+            ),
+        ];
+
+        for (action, lambda) in actions {
+            let payload = MultisigPayload {
+                chain_id: "NetXdQprcVkpaWU".to_string(),
+                contract: originated.clone(),
+                counter: 5,
+                action,
+            };
+            // Pair (Pair chain_id address) (Pair 5 (Left lambda))
+            assert_eq!(
+                hex::encode(payload.bytes_to_sign().unwrap()),
+                format!(
+                    "{}{}",
+                    concat!(
+                        "05070707070a000000047a06a770",
+                        "0a0000001601a3d0f58d8964bd1b37fb0a0c197b38cf46608d4900",
+                        "0707000505050",
+                    ),
+                    lambda,
+                ),
+            );
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use num_bigint::BigUint;
+use num_traits::{Zero, ToPrimitive};
+
+use crate::{pack, script_expr_hash};
+use crate::micheline::{Micheline, PrimType};
+use super::{ty, prim, pair_args, nat, nat_value};
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+pub enum TokenError {
+    #[error("unexpected value returned by the token contract: {0}")]
+    UnexpectedValue(Micheline),
+}
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+#[error("invalid token amount \"{amount}\" for a token with {decimals} decimals")]
+pub struct ParseTokenAmountError {
+    pub amount: String,
+    pub decimals: u32,
+}
+
+/// Parse token amount in the decimal notation (`1.5`) into the amount
+/// in the smallest units, as stored by the token contract.
+pub fn parse_token_amount(amount: &str, decimals: u32) -> Result<BigUint, ParseTokenAmountError> {
+    let error = || ParseTokenAmountError { amount: amount.to_string(), decimals };
+
+    let (whole, fraction) = match amount.find('.') {
+        Some(index) => (&amount[..index], &amount[(index + 1)..]),
+        None => (amount, ""),
+    };
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+
+    if (whole.is_empty() && fraction.is_empty())
+        || !is_digits(whole)
+        || !is_digits(fraction)
+        || fraction.len() > decimals as usize
+    {
+        return Err(error());
+    }
+
+    let digits = format!("{}{:0<width$}", whole, fraction, width = decimals as usize);
+    BigUint::parse_bytes(digits.as_bytes(), 10).ok_or_else(error)
+}
+
+/// Format token amount in the smallest units using decimal notation,
+/// without trailing zeros: `1500000` with 6 decimals is `1.5`.
+pub fn format_token_amount(amount: &BigUint, decimals: u32) -> String {
+    if decimals == 0 || amount.is_zero() {
+        return amount.to_string();
+    }
+
+    let digits = format!("{:0>width$}", amount.to_string(), width = decimals as usize + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals as usize);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, fraction)
+    }
+}
+
+/// Split the pair (or its type) into the first element and the rest.
+///
+/// Pairs with more than two elements (`Pair a b c`, or `{ a ; b ; c }`
+/// in the optimized form) are combs, so the rest is a pair itself.
+fn split_comb(value: &Micheline) -> Option<(&Micheline, Micheline)> {
+    let (pair_type, items) = match value {
+        Micheline::Prim(prim) if prim.prim_type == PrimType::Pair => {
+            (PrimType::Pair, prim.args.as_deref()?)
+        }
+        Micheline::Prim(prim) if prim.prim_type == PrimType::pair => {
+            (PrimType::pair, prim.args.as_deref()?)
+        }
+        Micheline::Array(items) => (PrimType::Pair, &items[..]),
+        _ => return None,
+    };
+
+    match items {
+        [first, second] => Some((first, second.clone())),
+        [first, rest @ ..] if rest.len() >= 2 => {
+            Some((first, prim(pair_type).with_args(rest.to_vec()).into()))
+        }
+        _ => None,
+    }
+}
+
+/// Id of the `token_metadata` big map (TZIP-12, TZIP-21), found by its
+/// field annotation in the storage type of the contract.
+pub fn token_metadata_big_map(storage_type: &Micheline, storage: &Micheline) -> Option<u64> {
+    let ty_prim = match storage_type {
+        Micheline::Prim(prim) => prim,
+        _ => return None,
+    };
+
+    match ty_prim.prim_type {
+        PrimType::big_map if ty_prim.annots().iter().any(|annot| annot == "%token_metadata") => {
+            nat(storage)?.to_u64()
+        }
+        PrimType::pair => {
+            let (left_type, right_type) = split_comb(storage_type)?;
+            let (left, right) = split_comb(storage)?;
+            token_metadata_big_map(left_type, left)
+                .or_else(|| token_metadata_big_map(&right_type, &right))
+        }
+        _ => None,
+    }
+}
+
+/// Script expression hash of the `token_metadata` big map key.
+pub fn token_metadata_key(token_id: &BigUint) -> String {
+    let packed = pack(&nat_value(token_id), &ty("nat"))
+        .expect("nat is always packed");
+    script_expr_hash(&packed)
+}
+
+/// Number of decimals from the value of the `token_metadata` big map:
+/// `pair nat (map string bytes)`, where `decimals` is a UTF-8 encoded
+/// number.
+pub fn token_metadata_decimals(value: &Micheline) -> Option<u32> {
+    let items = match pair_args(value)?.1 {
+        Micheline::Array(items) => items,
+        _ => return None,
+    };
+
+    items.iter().find_map(|item| match item {
+        Micheline::Prim(prim) if prim.prim_type == PrimType::Elt => {
+            match prim.args.as_deref()? {
+                [Micheline::String(key), Micheline::Bytes(value)] if key == "decimals" => {
+                    std::str::from_utf8(value).ok()?.parse().ok()
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_amount() {
+        let amount = |amount: u64| BigUint::from(amount);
+
+        assert_eq!(parse_token_amount("1.5", 6), Ok(amount(1_500_000)));
+        assert_eq!(parse_token_amount(".05", 2), Ok(amount(5)));
+        assert_eq!(parse_token_amount("42", 0), Ok(amount(42)));
+        assert!(parse_token_amount("0.001", 2).is_err());
+        assert!(parse_token_amount("1.", 0).is_ok());
+        assert!(parse_token_amount(".", 2).is_err());
+        assert!(parse_token_amount("-1", 2).is_err());
+        assert!(parse_token_amount("1e5", 2).is_err());
+
+        assert_eq!(format_token_amount(&amount(1_500_000), 6), "1.5");
+        assert_eq!(format_token_amount(&amount(5), 2), "0.05");
+        assert_eq!(format_token_amount(&amount(100), 2), "1");
+        assert_eq!(format_token_amount(&amount(0), 8), "0");
+        assert_eq!(format_token_amount(&amount(42), 0), "42");
+    }
+
+    #[test]
+    fn test_token_metadata_big_map() {
+        let storage_type = ty("pair (big_map %ledger address nat) (pair (big_map %token_metadata nat (pair nat (map string bytes))) nat)");
+
+        for storage in &["Pair 5 (Pair 7 100)", "Pair 5 7 100", "{ 5 ; 7 ; 100 }"] {
+            assert_eq!(token_metadata_big_map(&storage_type, &ty(storage)), Some(7));
+        }
+        assert_eq!(
+            token_metadata_big_map(&ty("pair (big_map nat bytes) nat"), &ty("Pair 5 100")),
+            None,
+        );
+        assert_eq!(token_metadata_big_map(&storage_type, &ty("Pair 5 7")), None);
+    }
+
+    #[test]
+    fn test_token_metadata_decimals() {
+        assert_eq!(
+            token_metadata_key(&BigUint::from(0u32)),
+            "exprtZBwZUeYYYfUs9B9Rg2ywHezVHnCCnmF9WsDQVrs582dSK63dC",
+        );
+        assert_eq!(
+            token_metadata_decimals(&ty(r#"Pair 0 { Elt "decimals" 0x3132 ; Elt "symbol" 0x545a }"#)),
+            Some(12),
+        );
+        assert_eq!(token_metadata_decimals(&ty(r#"Pair 0 { Elt "symbol" 0x545a }"#)), None);
+        assert_eq!(token_metadata_decimals(&ty(r#"Pair 0 { Elt "decimals" 0x7878 }"#)), None);
+    }
+}
@@ -0,0 +1,197 @@
+use std::convert::TryInto;
+use sodiumoxide::randombytes::randombytes_into;
+use sodiumoxide::crypto::secretbox::xsalsa20poly1305 as secretbox;
+
+use crypto::{Prefix, WithPrefix, WithoutPrefix, NotMatchingPrefixError};
+use crypto::base58check::{FromBase58Check, ToBase58Check};
+use super::{FromPrefixedBase58CheckError, PrivateKey, PRIVATE_KEY_LEN};
+
+const SALT_LEN: usize = 8;
+const PBKDF2_ROUNDS: u32 = 32768;
+pub const ENCRYPTED_PRIVATE_KEY_LEN: usize = SALT_LEN + secretbox::MACBYTES + PRIVATE_KEY_LEN;
+
+type EncryptedPrivateKeyInner = [u8; ENCRYPTED_PRIVATE_KEY_LEN];
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+#[error("failed to decrypt private key. Invalid password")]
+pub struct DecryptPrivateKeyError;
+
+/// Password encrypted [PrivateKey], compatible with Octez (`tezos-client`).
+///
+/// Key is encrypted with `xsalsa20poly1305` (zero nonce), using key derived
+/// from the password with `PBKDF2-HMAC-SHA512` and random 8 byte salt.
+/// Bytes are salt followed by encrypted private key.
+#[allow(non_camel_case_types)]
+#[derive(PartialEq, Debug, Clone)]
+pub enum EncryptedPrivateKey {
+    edesk(EncryptedPrivateKeyInner),
+    spesk(EncryptedPrivateKeyInner),
+    p2esk(EncryptedPrivateKeyInner),
+}
+
+fn derive_key(password: &str, salt: &[u8]) -> secretbox::Key {
+    let mut key = [0; secretbox::KEYBYTES];
+    pbkdf2::pbkdf2::<hmac::Hmac<sha2::Sha512>>(
+        password.as_bytes(),
+        salt,
+        PBKDF2_ROUNDS,
+        &mut key,
+    );
+    secretbox::Key(key)
+}
+
+impl EncryptedPrivateKey {
+    fn try_without_prefix(
+        bytes: &[u8],
+        prefix: Prefix,
+    ) -> Result<(Prefix, Vec<u8>), NotMatchingPrefixError>
+    {
+        bytes
+            .without_prefix(prefix)
+            .map(|bytes| (prefix, bytes))
+    }
+
+    pub fn from_base58check(encoded: &str) -> Result<Self, FromPrefixedBase58CheckError> {
+        let bytes = encoded.from_base58check()?;
+
+        let (prefix, bytes_vec) = Self::try_without_prefix(&bytes, Prefix::edesk)
+            .or_else(|_| Self::try_without_prefix(&bytes, Prefix::spesk))
+            .or_else(|_| Self::try_without_prefix(&bytes, Prefix::p2esk))?;
+
+        let inner = bytes_vec.try_into()
+            .or(Err(FromPrefixedBase58CheckError::InvalidSize))?;
+
+        match prefix {
+            Prefix::edesk => Ok(Self::edesk(inner)),
+            Prefix::spesk => Ok(Self::spesk(inner)),
+            Prefix::p2esk => Ok(Self::p2esk(inner)),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn get_prefix(&self) -> Prefix {
+        match self {
+            Self::edesk(_) => Prefix::edesk,
+            Self::spesk(_) => Prefix::spesk,
+            Self::p2esk(_) => Prefix::p2esk,
+        }
+    }
+
+    /// Encrypt private key with the password.
+    pub fn encrypt(key: &PrivateKey, password: &str) -> Self {
+        let mut salt = [0; SALT_LEN];
+        randombytes_into(&mut salt);
+
+        let encrypted = secretbox::seal(
+            key.as_ref(),
+            &secretbox::Nonce([0; secretbox::NONCEBYTES]),
+            &derive_key(password, &salt),
+        );
+
+        let inner = [&salt[..], &encrypted].concat()
+            .try_into()
+            // unwrap is fine since encrypted private key always has
+            // the same size.
+            .unwrap();
+
+        match key {
+            PrivateKey::edsk(_) => Self::edesk(inner),
+            PrivateKey::spsk(_) => Self::spesk(inner),
+            PrivateKey::p2sk(_) => Self::p2esk(inner),
+        }
+    }
+
+    /// Decrypt private key with the password.
+    pub fn decrypt(&self, password: &str) -> Result<PrivateKey, DecryptPrivateKeyError> {
+        let (salt, encrypted) = self.as_ref().split_at(SALT_LEN);
+
+        let inner = secretbox::open(
+            encrypted,
+            &secretbox::Nonce([0; secretbox::NONCEBYTES]),
+            &derive_key(password, salt),
+        )
+            .or(Err(DecryptPrivateKeyError))?
+            .try_into()
+            .or(Err(DecryptPrivateKeyError))?;
+
+        Ok(match self {
+            Self::edesk(_) => PrivateKey::edsk(inner),
+            Self::spesk(_) => PrivateKey::spsk(inner),
+            Self::p2esk(_) => PrivateKey::p2sk(inner),
+        })
+    }
+}
+
+impl ToBase58Check for EncryptedPrivateKey {
+    fn to_base58check(&self) -> String {
+        self.as_ref()
+            .with_prefix(self.get_prefix())
+            .to_base58check()
+    }
+}
+
+impl AsRef<EncryptedPrivateKeyInner> for EncryptedPrivateKey {
+    fn as_ref(&self) -> &EncryptedPrivateKeyInner {
+        match self {
+            Self::edesk(k) => k,
+            Self::spesk(k) => k,
+            Self::p2esk(k) => k,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{EDSK, SPSK, P2SK};
+    use super::*;
+
+    #[test]
+    fn test_encrypted_private_key() {
+        for key in [EDSK, SPSK, P2SK].iter() {
+            let private_key = PrivateKey::from_base58check(key).unwrap();
+            let encrypted = EncryptedPrivateKey::encrypt(&private_key, "password");
+            let encoded = encrypted.to_base58check();
+            assert_eq!(&encoded[..5], &key.replace("sk", "esk")[..5]);
+
+            let decoded = EncryptedPrivateKey::from_base58check(&encoded).unwrap();
+            assert_eq!(decoded, encrypted);
+            assert_eq!(&decoded.decrypt("password").unwrap().to_base58check(), key);
+        }
+
+        let private_key = PrivateKey::from_base58check(EDSK).unwrap();
+        let encrypted = EncryptedPrivateKey::encrypt(&private_key, "password");
+        assert_eq!(encrypted.decrypt("wrong").err(), Some(DecryptPrivateKeyError));
+    }
+
+    /// Keys encrypted by `octez-client`, with their passwords.
+    #[test]
+    fn test_decrypt_octez_encrypted_private_key() {
+        let keys = [
+            (
+                "edesk1GXwWmGjXiLHBKxGBxwmNvG21vKBh6FBxc4CyJ8adQQE2avP5vBB57ZUZ93Anm7i4k8RmsHaPzVAvpnHkFF",
+                "test",
+                "edsk38pyaS8b6K1ygB77FjJF3GLosbPnetNShxVXXgoDPsEYWTUMst",
+            ),
+            (
+                "spesk24UQkAiJk8X6AufNtRv1WWPp2BAssEgmijCTQPMgUXweSKPmLdbyAjPmCG1pR2dC9P5UZZVeZcb7zVodUHZ",
+                "test",
+                "spsk2MXVuq9SXAfvfcwmTkUxoa5efPcGZbshJRsGWp7ox36jSrsoS8",
+            ),
+            (
+                "p2esk2TFqgNcoT4u99ut5doGTUFNwo9x4nNvkpM6YMLqXrt4SbFdQnqLM3hoAXLMB2uZYazj6LZGvcoYzk16H6Et",
+                "test1234",
+                "p2sk2mJNRYqs3UXJzzF44Ym6jk38RVDPVSuLCfNd5ShE5zyVdu8Au9",
+            ),
+        ];
+
+        for (encrypted, password, expected) in keys.iter() {
+            let encrypted_key = EncryptedPrivateKey::from_base58check(encrypted).unwrap();
+            assert_eq!(&encrypted_key.to_base58check(), encrypted);
+
+            let private_key = encrypted_key.decrypt(password).unwrap();
+            assert_eq!(&private_key.to_base58check(), expected);
+
+            assert_eq!(encrypted_key.decrypt("wrong").err(), Some(DecryptPrivateKeyError));
+        }
+    }
+}
@@ -3,6 +3,8 @@ use crate::{
     NewOperationGroup, NewOperation, PublicKey,
     NewOriginationOperation, NewOriginationScript,
     NewDelegationOperation, NewTransactionOperation, NewRevealOperation,
+    NewActivationOperation, NewProposalsOperation, NewBallotOperation,
+    ProtocolHash,
 };
 use super::{Forge, ForgeNat, Forged};
 
@@ -173,6 +175,52 @@ impl Forge for NewOriginationOperation {
     }
 }
 
+impl Forge for NewActivationOperation {
+    /// Address is forged without the curve tag, since only `tz1`
+    /// addresses can be activated.
+    fn forge(&self) -> Forged {
+        Forged([
+            OperationTag::Activation.forge_nat().take(),
+            self.pkh.as_ref().to_vec(),
+            self.secret.to_vec(),
+        ].concat())
+    }
+}
+
+impl Forge for ProtocolHash {
+    fn forge(&self) -> Forged {
+        Forged(self.as_ref().to_vec())
+    }
+}
+
+impl Forge for NewProposalsOperation {
+    fn forge(&self) -> Forged {
+        let proposals = self.proposals.iter()
+            .flat_map(|proposal| proposal.forge().take())
+            .collect::<Vec<_>>();
+
+        Forged([
+            OperationTag::Proposals.forge_nat().take(),
+            self.source.forge().take(),
+            self.period.to_be_bytes().to_vec(),
+            (proposals.len() as u32).to_be_bytes().to_vec(),
+            proposals,
+        ].concat())
+    }
+}
+
+impl Forge for NewBallotOperation {
+    fn forge(&self) -> Forged {
+        Forged([
+            OperationTag::Ballot.forge_nat().take(),
+            self.source.forge().take(),
+            self.period.to_be_bytes().to_vec(),
+            self.proposal.forge().take(),
+            vec![self.ballot as u8],
+        ].concat())
+    }
+}
+
 impl Forge for NewOperation {
     fn forge(&self) -> Forged {
         match self {
@@ -180,6 +228,9 @@ impl Forge for NewOperation {
             NewOperation::Transaction(op) => op.forge(),
             NewOperation::Delegation(op) => op.forge(),
             NewOperation::Origination(op) => op.forge(),
+            NewOperation::Activation(op) => op.forge(),
+            NewOperation::Proposals(op) => op.forge(),
+            NewOperation::Ballot(op) => op.forge(),
         }
     }
 }
@@ -188,7 +239,7 @@ impl Forge for NewOperationGroup {
     fn forge(&self) -> Forged {
         Forged([
             self.branch.forge().take(),
-            self.to_operations_vec().into_iter()
+            self.operations.iter()
                 .flat_map(|op| op.forge().take())
                 .collect(),
         ].concat())
@@ -1,22 +1,35 @@
-use crate::{Address, NewTransactionParameters};
-use super::{Forge, Forged};
+use num_traits::ToPrimitive;
+
+use crate::{Address, ImplicitAddress, NewTransactionParameters};
+use super::{Forge, Forged, Unforge, UnforgeReader, UnforgeResult};
 use super::micheline::{Micheline, MichelineEntrypoint, MichelinePrim, PrimType};
 
 fn prim(prim_type: PrimType) -> MichelinePrim {
     MichelinePrim::new(prim_type)
 }
 
-impl Forge for NewTransactionParameters {
-    fn forge(&self) -> Forged {
-        let mut res = MichelineEntrypoint::Do.forge().take();
-
-        let mut value: Vec<Micheline> = vec![
-            prim(PrimType::DROP).into(),
-            prim(PrimType::NIL)
-                .with_arg(prim(PrimType::operation).into())
-                .into(),
-        ];
-        value.extend(match self {
+/// Lambda for manager.tz `do` entrypoint, which executes `instructions`
+/// and returns created operation.
+fn manager_lambda(instructions: Vec<Micheline>) -> Micheline {
+    let mut value: Vec<Micheline> = vec![
+        prim(PrimType::DROP).into(),
+        prim(PrimType::NIL)
+            .with_arg(prim(PrimType::operation).into())
+            .into(),
+    ];
+    value.extend(instructions);
+    value.push(prim(PrimType::CONS).into());
+
+    Micheline::Array(value)
+}
+
+impl NewTransactionParameters {
+    /// Lambda for manager.tz `do` entrypoint, which performs this action.
+    ///
+    /// Returns `None` for [NewTransactionParameters::Call].
+    pub fn manager_lambda(&self) -> Option<Micheline> {
+        let instructions = match self {
+            Self::Call { .. } => return None,
             Self::SetDelegate(addr) => {
                 let delegate = addr.forge().take();
                 vec![
@@ -70,7 +83,7 @@ impl Forge for NewTransactionParameters {
                 values.extend(vec![
                     prim(PrimType::PUSH).with_args(vec![
                         prim(PrimType::mutez).into(),
-                        Micheline::Int(*amount),
+                        Micheline::int(*amount),
                     ]).into(),
                     prim(PrimType::UNIT).into(),
                     prim(PrimType::TRANSFER_TOKENS).into(),
@@ -78,12 +91,103 @@ impl Forge for NewTransactionParameters {
 
                 values
             }
-        });
-        value.push(prim(PrimType::CONS).into());
+        };
+
+        Some(manager_lambda(instructions))
+    }
+}
+
+impl Forge for NewTransactionParameters {
+    fn forge(&self) -> Forged {
+        let (entrypoint, value) = match self {
+            Self::Call { entrypoint, value } => (entrypoint.clone(), value.clone()),
+            _ => (
+                MichelineEntrypoint::Do,
+                self.manager_lambda().expect("only `Call` has no manager lambda"),
+            ),
+        };
+
+        Forged([
+            entrypoint.forge().take(),
+            value.forge().take().forge().take(),
+        ].concat())
+    }
+}
 
-        let value_bytes = Micheline::Array(value).forge().take();
-        res.extend(value_bytes.forge());
+/// Value of the `PUSH` instruction.
+fn push_value(value: &Micheline) -> Option<&Micheline> {
+    match value {
+        Micheline::Prim(prim) if prim.prim_type == PrimType::PUSH => {
+            prim.args.as_ref()?.get(1)
+        }
+        _ => None,
+    }
+}
+
+fn push_bytes(value: &Micheline) -> Option<&[u8]> {
+    match push_value(value)? {
+        Micheline::Bytes(bytes) => Some(bytes),
+        _ => None,
+    }
+}
+
+fn push_int(value: &Micheline) -> Option<u64> {
+    match push_value(value)? {
+        Micheline::Int(num) => num.to_u64(),
+        _ => None,
+    }
+}
 
-        Forged(res)
+/// Extract parameters from the value passed to manager.tz `do` entrypoint.
+///
+/// Only the values are extracted here, so the result must be forged and
+/// compared with the input to make sure it's the same.
+fn parameters_from_lambda(value: &Micheline) -> Option<NewTransactionParameters> {
+    let instructions = match value {
+        Micheline::Array(instructions) => instructions,
+        _ => return None,
+    };
+
+    Some(match instructions.len() {
+        5 => NewTransactionParameters::CancelDelegate,
+        6 => NewTransactionParameters::SetDelegate(
+            ImplicitAddress::unforge(push_bytes(&instructions[2])?).ok()?,
+        ),
+        8 => NewTransactionParameters::Transfer {
+            to: ImplicitAddress::unforge(push_bytes(&instructions[2])?).ok()?.into(),
+            amount: push_int(&instructions[4])?,
+        },
+        9 => NewTransactionParameters::Transfer {
+            to: Address::unforge(push_bytes(&instructions[2])?).ok()?,
+            amount: push_int(&instructions[5])?,
+        },
+        _ => return None,
+    })
+}
+
+impl Unforge for NewTransactionParameters {
+    /// Inverse of `NewTransactionParameters::forge()`.
+    ///
+    /// Calls to `do` entrypoint, created by manager.tz specific variants
+    /// are recognized, otherwise [NewTransactionParameters::Call] is returned.
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        let position = reader.position();
+
+        let entrypoint = MichelineEntrypoint::unforge_from(reader)?;
+        let mut value_reader = reader.dynamic_reader()?;
+        let value = Micheline::unforge_from(&mut value_reader)?;
+        value_reader.finish()?;
+
+        let manager_parameters = match entrypoint {
+            MichelineEntrypoint::Do => parameters_from_lambda(&value),
+            _ => None,
+        };
+
+        Ok(match manager_parameters {
+            Some(parameters) if parameters.forge().as_ref() == reader.bytes_since(position) => {
+                parameters
+            }
+            _ => Self::Call { entrypoint, value },
+        })
     }
 }
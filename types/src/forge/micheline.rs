@@ -1,30 +1,65 @@
-pub use super::prim_type::PrimType;
-use super::{Forge, Forged};
+use std::fmt;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::ser::SerializeMap;
+use serde::de::{self, Visitor, SeqAccess, MapAccess, DeserializeSeed};
+use sodiumoxide::hex;
+use num_traits::{Signed, Zero, ToPrimitive};
+use num_bigint::Sign;
 
-//TODO: make sure logic is correct. Sample: https://github.com/baking-bad/netezos/blob/de88439f10bbdfc2c7942c00849756c4be864a81/Netezos/Forging/Local/LocalForge.Forgers.cs#L131
-fn forge_micheline_uint(mut num: u64) -> Forged {
-    let mut res = vec![(num & 0x3f) as u8];
+pub use num_bigint::{BigInt, BigUint};
 
-    num >>= 6;
+pub use super::prim_type::{PrimType, UnknownPrimTypeError};
+use super::{Forge, Forged, Unforge, UnforgeReader, UnforgeResult, UnforgeError};
 
-    while num > 0 {
-        match res.last_mut() {
-            Some(last) => *last |= 0x80,
-            None => {}
-        };
-        res.push((num & 0x7F) as u8);
-        num >>= 7;
+/// Forge integer using Zarith encoding.
+///
+/// First byte holds sign bit (0x40) and 6 bits of the absolute value,
+/// following bytes hold 7 bits each. Highest bit (0x80) of each byte is
+/// set if there are more bytes to follow.
+fn forge_micheline_int(num: &BigInt) -> Forged {
+    let mut abs = num.magnitude().clone();
+    let sign_bit = if num.is_negative() { 0x40 } else { 0 };
+
+    let mut res = vec![(&abs & BigUint::from(0x3fu8)).to_u8().unwrap() | sign_bit];
+    abs >>= 6;
+
+    while !abs.is_zero() {
+        *res.last_mut().unwrap() |= 0x80;
+        res.push((&abs & BigUint::from(0x7fu8)).to_u8().unwrap());
+        abs >>= 7;
     }
 
     Forged(res)
 }
 
+/// Inverse of [forge_micheline_int].
+fn unforge_micheline_int(reader: &mut UnforgeReader) -> UnforgeResult<BigInt> {
+    let first = reader.read_byte()?;
+
+    let mut abs = BigUint::from(first & 0x3f);
+    let mut shift = 6;
+    let mut byte = first;
+
+    while byte & 0x80 != 0 {
+        byte = reader.read_byte()?;
+        abs |= BigUint::from(byte & 0x7f) << shift;
+        shift += 7;
+    }
+
+    let sign = if first & 0x40 != 0 { Sign::Minus } else { Sign::Plus };
+    Ok(BigInt::from_biguint(sign, abs))
+}
+
+/// Maximum nesting depth of the decoded Micheline expressions.
+///
+/// Decoders are recursive, so deeper inputs are rejected, instead
+/// of overflowing the stack.
+pub const MAX_MICHELINE_DEPTH: usize = 256;
+
 #[derive(PartialEq, Debug, Clone)]
 pub enum Micheline {
-    /// technically this `Int` can be boundless integer (bigint).
-    /// Also it can be negative, but for now only unsigned u64 is used,
-    /// so forging bigger numbers or negative ones aren't implemented.
-    Int(u64),
+    /// Arbitrary-precision, possibly negative, integer.
+    Int(BigInt),
     Bytes(Vec<u8>),
     String(String),
     Array(Vec<Micheline>),
@@ -35,12 +70,16 @@ impl Micheline {
     pub fn str<S: AsRef<str>>(value: S) -> Self {
         Self::String(value.as_ref().to_string())
     }
+
+    pub fn int<T: Into<BigInt>>(value: T) -> Self {
+        Self::Int(value.into())
+    }
 }
 
 impl Forge for Micheline {
     fn forge(&self) -> Forged {
         Forged(match self {
-            Self::Int(num) => [vec![0], forge_micheline_uint(*num).take()].concat(),
+            Self::Int(num) => [vec![0], forge_micheline_int(num).take()].concat(),
             Self::Bytes(bytes) => [vec![10], bytes.forge().take()].concat(),
             Self::String(s) => [vec![1], s.forge().take()].concat(),
             Self::Array(arr) => [vec![2], arr.forge().take()].concat(),
@@ -49,6 +88,55 @@ impl Forge for Micheline {
     }
 }
 
+impl Unforge for Micheline {
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        reader.nested(MAX_MICHELINE_DEPTH, Self::unforge_nested)
+    }
+}
+
+impl Micheline {
+    fn unforge_nested(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        let position = reader.position();
+
+        Ok(match reader.read_byte()? {
+            0 => Self::Int(unforge_micheline_int(reader)?),
+            1 => Self::String(reader.read_string()?),
+            2 => {
+                let mut arr_reader = reader.dynamic_reader()?;
+                let mut arr = vec![];
+
+                while !arr_reader.is_empty() {
+                    arr.push(Self::unforge_from(&mut arr_reader)?);
+                }
+                Self::Array(arr)
+            }
+            tag @ 3..=9 => Self::Prim(MichelinePrim::unforge_after_tag(tag, reader)?),
+            10 => Self::Bytes(reader.read_dynamic_bytes()?.to_vec()),
+            tag => {
+                return Err(UnforgeError::UnknownMichelineTag { position, tag });
+            }
+        })
+    }
+}
+
+/// Maximum length of the entrypoint name in bytes, allowed by the protocol.
+pub const MAX_ENTRYPOINT_NAME_LEN: usize = 31;
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+#[error("entrypoint name \"{0}\" is longer than {} bytes", MAX_ENTRYPOINT_NAME_LEN)]
+pub struct EntrypointNameTooLongError(pub String);
+
+/// Name of the custom entrypoint, at most [MAX_ENTRYPOINT_NAME_LEN]
+/// bytes long, so that its length fits in a single byte when forged.
+#[derive(PartialEq, Debug, Clone)]
+pub struct CustomEntrypoint(String);
+
+impl AsRef<str> for CustomEntrypoint {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub enum MichelineEntrypoint {
     Default,
@@ -56,10 +144,55 @@ pub enum MichelineEntrypoint {
     Do,
     SetDelegate,
     RemoveDelegate,
-    // TODO: replace with newtype (not pub) to reject strings longer than
-    // 255 to avoid integer overflow during forging when casting length
-    // of this string to `u8`.
-    Custom(String),
+    Custom(CustomEntrypoint),
+}
+
+impl MichelineEntrypoint {
+    /// Entrypoint name as used in RPC and Michelson annotations.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Default => "default",
+            Self::Root => "root",
+            Self::Do => "do",
+            Self::SetDelegate => "set_delegate",
+            Self::RemoveDelegate => "remove_delegate",
+            Self::Custom(name) => name.as_ref(),
+        }
+    }
+
+    /// Get entrypoint by its name.
+    ///
+    /// Names without dedicated variant become [MichelineEntrypoint::Custom].
+    pub fn from_name<S: AsRef<str>>(name: S) -> Result<Self, EntrypointNameTooLongError> {
+        Ok(match name.as_ref() {
+            "default" => Self::Default,
+            "root" => Self::Root,
+            "do" => Self::Do,
+            "set_delegate" => Self::SetDelegate,
+            "remove_delegate" => Self::RemoveDelegate,
+            name if name.len() > MAX_ENTRYPOINT_NAME_LEN => {
+                return Err(EntrypointNameTooLongError(name.to_string()));
+            }
+            name => Self::Custom(CustomEntrypoint(name.to_string())),
+        })
+    }
+}
+
+impl Serialize for MichelineEntrypoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer,
+    {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for MichelineEntrypoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>,
+    {
+        Self::from_name(String::deserialize(deserializer)?)
+            .map_err(de::Error::custom)
+    }
 }
 
 impl Forge for MichelineEntrypoint {
@@ -70,12 +203,11 @@ impl Forge for MichelineEntrypoint {
             Self::Do => vec![2],
             Self::SetDelegate => vec![3],
             Self::RemoveDelegate => vec![4],
-            Self::Custom(custom) => {
-                debug_assert!(custom.len() <= u8::MAX as usize);
+            Self::Custom(CustomEntrypoint(custom)) => {
+                // length always fits in `u8`, as it's checked in `from_name`.
                 [
                     vec![255],
-                    (custom.len() as u8).to_be_bytes().to_vec(),
-                    vec![0, 0, 0],
+                    vec![custom.len() as u8],
                     custom.as_bytes().to_vec(),
                 ].concat()
             }
@@ -83,11 +215,64 @@ impl Forge for MichelineEntrypoint {
     }
 }
 
+impl Unforge for MichelineEntrypoint {
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        let position = reader.position();
+
+        Ok(match reader.read_byte()? {
+            0 => Self::Default,
+            1 => Self::Root,
+            2 => Self::Do,
+            3 => Self::SetDelegate,
+            4 => Self::RemoveDelegate,
+            255 => {
+                let name_position = reader.position();
+                let len = reader.read_byte()? as usize;
+                if len > MAX_ENTRYPOINT_NAME_LEN {
+                    return Err(UnforgeError::EntrypointNameTooLong { position: name_position });
+                }
+                let name = reader.read_bytes(len)?.to_vec();
+
+                let name = String::from_utf8(name)
+                    .map_err(|_| UnforgeError::InvalidString { position: name_position })?;
+                Self::Custom(CustomEntrypoint(name))
+            }
+            tag => {
+                return Err(UnforgeError::UnknownEntrypointTag { position, tag });
+            }
+        })
+    }
+}
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+#[error("invalid annotation \"{0}\"")]
+pub struct InvalidAnnotError(pub String);
+
+/// Check that annotation starts with one of the prefixes (`%`, `:`
+/// or `@`) and the rest consists of `_`, `.`, `%`, `@` and ASCII
+/// alphanumeric characters, as in Michelson.
+fn validate_annot(annot: &str) -> Result<(), InvalidAnnotError> {
+    let valid = annot.starts_with(&['%', ':', '@'][..])
+        && annot[1..].chars().all(|ch| {
+            ch.is_ascii_alphanumeric() || ch == '_' || ch == '.' || ch == '%' || ch == '@'
+        });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(InvalidAnnotError(annot.to_string()))
+    }
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct MichelinePrim {
     pub prim_type: PrimType,
     pub args: Option<Vec<Micheline>>,
-    // TODO: implement annotations
+    /// Field(`%`), type(`:`) and variable(`@`) annotations, including
+    /// their prefix. For example: `%from`, `:amount`.
+    ///
+    /// Private, so that only validated annotations can be added.
+    annots: Vec<String>,
 }
 
 impl MichelinePrim {
@@ -95,6 +280,7 @@ impl MichelinePrim {
         Self {
             prim_type,
             args: None,
+            annots: vec![],
         }
     }
 
@@ -110,6 +296,26 @@ impl MichelinePrim {
             .push(arg);
         self
     }
+
+    /// Adds annotation to the `annots` list.
+    ///
+    /// Annotation must include its prefix (`%`, `:` or `@`).
+    pub fn with_annot<S: AsRef<str>>(mut self, annot: S) -> Result<Self, InvalidAnnotError> {
+        validate_annot(annot.as_ref())?;
+        self.annots.push(annot.as_ref().to_string());
+        Ok(self)
+    }
+
+    pub fn annots(&self) -> &[String] {
+        &self.annots
+    }
+
+    /// Keep only annotations for which `f` returns `true`.
+    pub fn retain_annots<F>(&mut self, f: F)
+        where F: FnMut(&String) -> bool,
+    {
+        self.annots.retain(f);
+    }
 }
 
 impl Forge for MichelinePrim {
@@ -117,9 +323,13 @@ impl Forge for MichelinePrim {
         let mut res = vec![];
 
         let args_len = self.args.as_ref().map(Vec::len).unwrap_or(0);
-        let annotations_len = 0;
+        let has_annots = !self.annots.is_empty();
 
-        let tag = 9.min(args_len * 2 + 3 + annotations_len);
+        let tag = if args_len < 3 {
+            args_len * 2 + 3 + has_annots as usize
+        } else {
+            9
+        };
 
         res.push(tag as u8);
         res.push(self.prim_type.into());
@@ -139,16 +349,556 @@ impl Forge for MichelinePrim {
             _ => {}
         }
 
-        if args_len >= 3 {
-            res.extend(&[0, 0, 0, 0]);
+        // generic prim (9) always has annotations, even if empty.
+        if has_annots || args_len >= 3 {
+            res.extend(self.annots.join(" ").forge());
         }
 
         Forged(res)
     }
 }
 
+impl MichelinePrim {
+    /// Decode the rest of the prim, after its tag was already read.
+    fn unforge_after_tag(tag: u8, reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        let prim_type = PrimType::unforge_from(reader)?;
+
+        let args = match tag {
+            3 | 4 => vec![],
+            5 | 6 => vec![Micheline::unforge_from(reader)?],
+            7 | 8 => vec![
+                Micheline::unforge_from(reader)?,
+                Micheline::unforge_from(reader)?,
+            ],
+            _ => {
+                let mut args_reader = reader.dynamic_reader()?;
+                let mut args = vec![];
+
+                while !args_reader.is_empty() {
+                    args.push(Micheline::unforge_from(&mut args_reader)?);
+                }
+                args
+            }
+        };
+
+        // tags 4, 6, 8 and generic prim (9) are followed by annotations.
+        let annots = match tag {
+            4 | 6 | 8 | 9 => {
+                let position = reader.position();
+                let annots = reader.read_string()?
+                    .split(' ')
+                    .filter(|annot| !annot.is_empty())
+                    .map(|annot| annot.to_string())
+                    .collect::<Vec<_>>();
+
+                if annots.iter().any(|annot| validate_annot(annot).is_err()) {
+                    return Err(UnforgeError::InvalidAnnotation { position });
+                }
+                annots
+            }
+            _ => vec![],
+        };
+
+        Ok(Self {
+            prim_type,
+            args: if args.is_empty() { None } else { Some(args) },
+            annots,
+        })
+    }
+}
+
+impl Unforge for MichelinePrim {
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        let position = reader.position();
+
+        match reader.read_byte()? {
+            tag @ 3..=9 => Self::unforge_after_tag(tag, reader),
+            tag => Err(UnforgeError::UnknownMichelineTag { position, tag }),
+        }
+    }
+}
+
 impl From<MichelinePrim> for Micheline {
     fn from(prim: MichelinePrim) -> Self {
         Micheline::Prim(prim)
     }
 }
+
+impl Serialize for Micheline {
+    /// Serialize into the Micheline JSON format used by the node.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer,
+    {
+        match self {
+            Self::Int(num) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("int", &num.to_string())?;
+                map.end()
+            }
+            Self::Bytes(bytes) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("bytes", &hex::encode(bytes))?;
+                map.end()
+            }
+            Self::String(s) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("string", s)?;
+                map.end()
+            }
+            Self::Array(arr) => arr.serialize(serializer),
+            Self::Prim(prim) => prim.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for MichelinePrim {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer,
+    {
+        let args = self.args.as_ref().filter(|args| !args.is_empty());
+
+        let has_annots = !self.annots.is_empty();
+
+        let mut map = serializer.serialize_map(Some(
+            1 + args.is_some() as usize + has_annots as usize,
+        ))?;
+        map.serialize_entry("prim", &self.prim_type)?;
+        if let Some(args) = args {
+            map.serialize_entry("args", args)?;
+        }
+        if has_annots {
+            map.serialize_entry("annots", &self.annots)?;
+        }
+        map.end()
+    }
+}
+
+/// Deserializes Micheline expression nested in the expression with
+/// the given `depth`, failing if it's deeper than [MAX_MICHELINE_DEPTH].
+struct MichelineSeed {
+    depth: usize,
+}
+
+impl<'de> DeserializeSeed<'de> for MichelineSeed {
+    type Value = Micheline;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where D: Deserializer<'de>,
+    {
+        if self.depth >= MAX_MICHELINE_DEPTH {
+            return Err(de::Error::custom(format!(
+                "Micheline expression is nested deeper than {} levels",
+                MAX_MICHELINE_DEPTH,
+            )));
+        }
+        deserializer.deserialize_any(MichelineVisitor { depth: self.depth + 1 })
+    }
+}
+
+/// Deserializes `args` of the prim with the given `depth`.
+struct ArgsSeed {
+    depth: usize,
+}
+
+impl<'de> DeserializeSeed<'de> for ArgsSeed {
+    type Value = Vec<Micheline>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ArgsSeed {
+    type Value = Vec<Micheline>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("array of Micheline JSON expressions")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where A: SeqAccess<'de>,
+    {
+        let mut args = vec![];
+
+        while let Some(arg) = seq.next_element_seed(MichelineSeed { depth: self.depth })? {
+            args.push(arg);
+        }
+
+        Ok(args)
+    }
+}
+
+struct MichelineVisitor {
+    /// Nesting depth of the visited expression, starting from 1.
+    depth: usize,
+}
+
+impl<'de> Visitor<'de> for MichelineVisitor {
+    type Value = Micheline;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Micheline JSON expression")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where A: SeqAccess<'de>,
+    {
+        let mut arr = vec![];
+
+        while let Some(item) = seq.next_element_seed(MichelineSeed { depth: self.depth })? {
+            arr.push(item);
+        }
+
+        Ok(Micheline::Array(arr))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where A: MapAccess<'de>,
+    {
+        let mut value = None;
+        let mut prim_type = None;
+        let mut args = None;
+        let mut annots = vec![];
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "int" => {
+                    let num: String = map.next_value()?;
+                    value = Some(Micheline::Int(
+                        num.parse().map_err(|_| {
+                            de::Error::invalid_value(de::Unexpected::Str(&num), &"integer")
+                        })?,
+                    ));
+                }
+                "string" => {
+                    value = Some(Micheline::String(map.next_value()?));
+                }
+                "bytes" => {
+                    let bytes: String = map.next_value()?;
+                    value = Some(Micheline::Bytes(
+                        hex::decode(&bytes).map_err(|_| {
+                            de::Error::invalid_value(de::Unexpected::Str(&bytes), &"hex encoded bytes")
+                        })?,
+                    ));
+                }
+                "prim" => {
+                    prim_type = Some(map.next_value::<PrimType>()?);
+                }
+                "args" => {
+                    args = Some(map.next_value_seed(ArgsSeed { depth: self.depth })?);
+                }
+                "annots" => {
+                    annots = map.next_value::<Vec<String>>()?;
+                    for annot in annots.iter() {
+                        validate_annot(annot).map_err(|_| {
+                            de::Error::invalid_value(de::Unexpected::Str(annot), &"annotation")
+                        })?;
+                    }
+                }
+                // unknown fields are skipped.
+                _ => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+
+        match (value, prim_type) {
+            (Some(value), None) => Ok(value),
+            (None, Some(prim_type)) => Ok(Micheline::Prim(MichelinePrim {
+                prim_type,
+                args,
+                annots,
+            })),
+            (None, None) => Err(de::Error::custom(
+                "expected one of the fields: `prim`, `int`, `string`, `bytes`",
+            )),
+            (Some(_), Some(_)) => Err(de::Error::custom(
+                "`prim` can't be combined with `int`, `string` or `bytes` field",
+            )),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Micheline {
+    /// Deserialize from the Micheline JSON format used by the node.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>,
+    {
+        MichelineSeed { depth: 0 }.deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_micheline_json_roundtrip() {
+        let value = json!([
+            { "prim": "DROP" },
+            { "prim": "NIL", "args": [{ "prim": "operation" }] },
+            { "prim": "PUSH", "args": [
+                { "prim": "mutez" },
+                { "int": "1000" },
+            ] },
+            { "prim": "PUSH", "args": [
+                { "prim": "string" },
+                { "string": "tezos" },
+            ] },
+            { "prim": "PUSH", "args": [
+                { "prim": "bytes" },
+                { "bytes": "0a0b0c" },
+            ] },
+        ]);
+
+        let micheline: Micheline = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(micheline, Micheline::Array(vec![
+            MichelinePrim::new(PrimType::DROP).into(),
+            MichelinePrim::new(PrimType::NIL)
+                .with_arg(MichelinePrim::new(PrimType::operation).into())
+                .into(),
+            MichelinePrim::new(PrimType::PUSH)
+                .with_arg(MichelinePrim::new(PrimType::mutez).into())
+                .with_arg(Micheline::int(1000))
+                .into(),
+            MichelinePrim::new(PrimType::PUSH)
+                .with_arg(MichelinePrim::new(PrimType::string).into())
+                .with_arg(Micheline::str("tezos"))
+                .into(),
+            MichelinePrim::new(PrimType::PUSH)
+                .with_arg(MichelinePrim::new(PrimType::bytes).into())
+                .with_arg(Micheline::Bytes(vec![10, 11, 12]))
+                .into(),
+        ]));
+        assert_eq!(serde_json::to_value(&micheline).unwrap(), value);
+    }
+
+    #[test]
+    fn test_micheline_json_invalid() {
+        assert!(serde_json::from_value::<Micheline>(json!({ "prim": "UNKNOWN" })).is_err());
+        assert!(serde_json::from_value::<Micheline>(json!({ "int": "abc" })).is_err());
+        assert!(serde_json::from_value::<Micheline>(json!({ "bytes": "zz" })).is_err());
+        assert!(serde_json::from_value::<Micheline>(json!({})).is_err());
+
+        // `Some (Some (... Unit))`
+        let nested = |depth: usize| (1..depth).fold(
+            json!({ "prim": "Unit" }),
+            |value, _| json!({ "prim": "Some", "args": [value] }),
+        );
+        assert!(serde_json::from_value::<Micheline>(nested(MAX_MICHELINE_DEPTH)).is_ok());
+        assert!(serde_json::from_value::<Micheline>(nested(MAX_MICHELINE_DEPTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_micheline_int_forging() {
+        let cases = vec![
+            (Micheline::int(0), "0000"),
+            (Micheline::int(-1), "0041"),
+            (Micheline::int(64), "008001"),
+            (Micheline::int(-64), "00c001"),
+            (Micheline::int(1_000_000), "0080897a"),
+            (Micheline::int(-1_000_000), "00c0897a"),
+        ];
+
+        for (value, forged) in cases {
+            assert_eq!(hex::encode(value.forge()), forged);
+        }
+
+        let big = "-123456789012345678901234567890";
+        assert_eq!(
+            serde_json::from_value::<Micheline>(json!({ "int": big })).unwrap(),
+            Micheline::Int(big.parse().unwrap()),
+        );
+        assert_eq!(
+            serde_json::to_value(Micheline::Int(big.parse().unwrap())).unwrap(),
+            json!({ "int": big }),
+        );
+    }
+
+    #[test]
+    fn test_micheline_unforge_roundtrip() {
+        let values: Vec<Micheline> = vec![
+            Micheline::int(0),
+            Micheline::int(63),
+            Micheline::int(64),
+            Micheline::int(u64::MAX),
+            Micheline::int(-1),
+            Micheline::int(-64),
+            Micheline::Int("-1000000000000000000000000000000".parse().unwrap()),
+            Micheline::str("tezos"),
+            Micheline::Bytes(vec![0, 1, 255]),
+            Micheline::Array(vec![]),
+            MichelinePrim::new(PrimType::UNIT).into(),
+            MichelinePrim::new(PrimType::option)
+                .with_arg(MichelinePrim::new(PrimType::key_hash).into())
+                .into(),
+            MichelinePrim::new(PrimType::PUSH)
+                .with_arg(MichelinePrim::new(PrimType::mutez).into())
+                .with_arg(Micheline::int(1000))
+                .into(),
+            MichelinePrim::new(PrimType::Elt)
+                .with_arg(Micheline::int(1))
+                .with_arg(Micheline::int(2))
+                .with_arg(Micheline::int(3))
+                .into(),
+        ];
+
+        for value in values {
+            assert_eq!(Micheline::unforge(&value.forge().take()), Ok(value.clone()));
+        }
+
+        let array = Micheline::Array(vec![
+            MichelinePrim::new(PrimType::DROP).into(),
+            MichelinePrim::new(PrimType::NIL)
+                .with_arg(MichelinePrim::new(PrimType::operation).into())
+                .into(),
+        ]);
+        assert_eq!(Micheline::unforge(&array.forge().take()), Ok(array));
+    }
+
+    #[test]
+    fn test_micheline_annotations() {
+        let values: Vec<Micheline> = vec![
+            MichelinePrim::new(PrimType::unit).with_annot("%a").unwrap().into(),
+            MichelinePrim::new(PrimType::option)
+                .with_arg(MichelinePrim::new(PrimType::key_hash).into())
+                .with_annot(":delegate").unwrap()
+                .into(),
+            MichelinePrim::new(PrimType::pair)
+                .with_arg(MichelinePrim::new(PrimType::address).with_annot("%from").unwrap().into())
+                .with_arg(MichelinePrim::new(PrimType::nat).with_annot("%value").unwrap().into())
+                .with_annot("%transfer").unwrap()
+                .into(),
+            MichelinePrim::new(PrimType::Elt)
+                .with_arg(Micheline::int(1))
+                .with_arg(Micheline::int(2))
+                .with_arg(Micheline::int(3))
+                .with_annot("@x").unwrap()
+                .into(),
+        ];
+
+        for value in values {
+            assert_eq!(Micheline::unforge(&value.forge().take()), Ok(value.clone()));
+        }
+
+        // `pair %a %b`
+        let pair: Micheline = MichelinePrim::new(PrimType::pair)
+            .with_annot("%a").unwrap()
+            .with_annot("%b").unwrap()
+            .into();
+        assert_eq!(hex::encode(pair.forge()), "0465000000052561202562");
+        assert_eq!(
+            serde_json::to_value(&pair).unwrap(),
+            json!({ "prim": "pair", "annots": ["%a", "%b"] }),
+        );
+        assert_eq!(
+            serde_json::from_value::<Micheline>(json!({ "prim": "pair", "annots": ["%a", "%b"] })).unwrap(),
+            pair,
+        );
+
+        for invalid in &["a", "", "%a b", "%a\"", ":\u{e9}"] {
+            assert_eq!(
+                MichelinePrim::new(PrimType::unit).with_annot(invalid),
+                Err(InvalidAnnotError(invalid.to_string())),
+            );
+            assert!(serde_json::from_value::<Micheline>(json!({ "prim": "unit", "annots": [invalid] })).is_err());
+        }
+        // `unit a`
+        assert_eq!(
+            Micheline::unforge(&[4, 0x6c, 0, 0, 0, 1, b'a']),
+            Err(UnforgeError::InvalidAnnotation { position: 2 }),
+        );
+    }
+
+    #[test]
+    fn test_manager_contract_code_forging() {
+        assert_eq!(
+            hex::encode(crate::manager_contract_code().forge()),
+            crate::MANAGER_CONTRACT_CODE,
+        );
+    }
+
+    #[test]
+    fn test_micheline_unforge_errors() {
+        assert_eq!(
+            Micheline::unforge(&[1, 0, 0, 0, 5, b'a']),
+            Err(UnforgeError::UnexpectedEnd { position: 5, expected: 4 }),
+        );
+        assert_eq!(
+            Micheline::unforge(&[5, 0x4f]),
+            Err(UnforgeError::UnexpectedEnd { position: 2, expected: 1 }),
+        );
+        assert_eq!(
+            Micheline::unforge(&[3, 0xff]),
+            Err(UnforgeError::UnknownPrimTag { position: 1, tag: 0xff }),
+        );
+        assert_eq!(
+            Micheline::unforge(&[0x0b]),
+            Err(UnforgeError::UnknownMichelineTag { position: 0, tag: 0x0b }),
+        );
+        assert_eq!(
+            Micheline::unforge(&[0, 1, 0]),
+            Err(UnforgeError::TrailingBytes { position: 2 }),
+        );
+        // array length bigger than the rest of the input.
+        assert_eq!(
+            Micheline::unforge(&[2, 0, 0, 0, 4, 3, 0x20]),
+            Err(UnforgeError::UnexpectedEnd { position: 5, expected: 2 }),
+        );
+
+        // `Some (Some (... Unit))`
+        let nested = |depth: usize| [[5, 0x09].repeat(depth - 1), vec![3, 0x0b]].concat();
+        assert!(Micheline::unforge(&nested(MAX_MICHELINE_DEPTH)).is_ok());
+        assert_eq!(
+            Micheline::unforge(&nested(MAX_MICHELINE_DEPTH + 1)),
+            Err(UnforgeError::TooDeep {
+                position: MAX_MICHELINE_DEPTH * 2,
+                max_depth: MAX_MICHELINE_DEPTH,
+            }),
+        );
+    }
+
+    #[test]
+    fn test_micheline_entrypoint_unforge() {
+        let entrypoints = vec![
+            MichelineEntrypoint::Default,
+            MichelineEntrypoint::Root,
+            MichelineEntrypoint::Do,
+            MichelineEntrypoint::SetDelegate,
+            MichelineEntrypoint::RemoveDelegate,
+            MichelineEntrypoint::from_name("mint").unwrap(),
+        ];
+
+        for entrypoint in entrypoints {
+            assert_eq!(
+                MichelineEntrypoint::unforge(&entrypoint.forge().take()),
+                Ok(entrypoint.clone()),
+            );
+        }
+
+        assert_eq!(
+            MichelineEntrypoint::from_name("mint").unwrap().forge().take(),
+            vec![255, 4, b'm', b'i', b'n', b't'],
+        );
+        assert_eq!(
+            MichelineEntrypoint::unforge(&[5]),
+            Err(UnforgeError::UnknownEntrypointTag { position: 0, tag: 5 }),
+        );
+
+        let longest = "a".repeat(MAX_ENTRYPOINT_NAME_LEN);
+        assert_eq!(MichelineEntrypoint::from_name(&longest).unwrap().name(), longest);
+        let too_long = "a".repeat(MAX_ENTRYPOINT_NAME_LEN + 1);
+        assert_eq!(
+            MichelineEntrypoint::from_name(&too_long),
+            Err(EntrypointNameTooLongError(too_long.clone())),
+        );
+        assert!(serde_json::from_value::<MichelineEntrypoint>(json!(too_long)).is_err());
+        assert_eq!(
+            MichelineEntrypoint::unforge(&[&[255, 32][..], too_long.as_bytes()].concat()),
+            Err(UnforgeError::EntrypointNameTooLong { position: 1 }),
+        );
+    }
+}
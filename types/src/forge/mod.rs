@@ -4,6 +4,15 @@ mod forge_primitives;
 mod forge_transaction_parameters;
 mod forge_operations;
 
+mod unforge;
+pub use unforge::*;
+
+mod unforge_operations;
+pub use unforge_operations::*;
+
+mod pack;
+pub use pack::*;
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct Forged(Vec<u8>);
 
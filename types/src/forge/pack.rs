@@ -0,0 +1,396 @@
+use std::str;
+use sodiumoxide::hex;
+
+use crypto::{blake2b, Prefix, WithPrefix, WithoutPrefix};
+use crypto::base58check::{FromBase58Check, ToBase58Check};
+use crate::{Address, ImplicitAddress, PublicKey, Signature};
+use super::micheline::{Micheline, MichelinePrim, PrimType};
+use super::{Forge, Unforge, UnforgeError};
+
+/// Prefix of the packed data.
+pub const PACK_PREFIX: u8 = 0x05;
+
+/// Length of the forged [Address], without the entrypoint.
+const ADDRESS_BYTES_LEN: usize = 22;
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+pub enum PackError {
+    #[error("value `{value}` doesn't match type `{ty}`")]
+    TypeMismatch {
+        value: Micheline,
+        ty: Micheline,
+    },
+    #[error("invalid {kind}: \"{value}\"")]
+    InvalidValue {
+        kind: &'static str,
+        value: String,
+    },
+    #[error("packed data must start with 0x05 byte")]
+    MissingPackPrefix,
+    #[error("failed to decode packed data: {0}")]
+    Unforge(#[from] UnforgeError),
+}
+
+pub type PackResult<T> = Result<T, PackError>;
+
+fn type_mismatch<T>(value: &Micheline, ty: &Micheline) -> PackResult<T> {
+    Err(PackError::TypeMismatch {
+        value: value.clone(),
+        ty: ty.clone(),
+    })
+}
+
+fn prim_args(prim: &MichelinePrim) -> &[Micheline] {
+    prim.args.as_deref().unwrap_or(&[])
+}
+
+/// Split right comb pair (`Pair a b c`, `{ a ; b ; c }`, `pair a b c`)
+/// into its first element and the rest: (`a`, `Pair b c`).
+fn split_comb(items: &[Micheline], prim_type: PrimType) -> Option<(&Micheline, Micheline)> {
+    match items {
+        [first, second] => Some((first, second.clone())),
+        [first, rest @ ..] if rest.len() >= 2 => {
+            Some((first, MichelinePrim::new(prim_type).with_args(rest.to_vec()).into()))
+        }
+        _ => None,
+    }
+}
+
+/// Convert value of the given type, using `convert_leaf` for values of
+/// non composite types (`address`, `int`, `string`, etc...).
+fn convert<F>(value: &Micheline, ty: &Micheline, convert_leaf: &F) -> PackResult<Micheline>
+    where F: Fn(PrimType, &Micheline) -> PackResult<Micheline>,
+{
+    let ty_prim = match ty {
+        Micheline::Prim(prim) => prim,
+        _ => return type_mismatch(value, ty),
+    };
+    let ty_args = prim_args(ty_prim);
+
+    let value_prim = match value {
+        Micheline::Prim(prim) => Some((prim.prim_type, prim_args(prim))),
+        _ => None,
+    };
+
+    Ok(match (ty_prim.prim_type, value_prim) {
+        (PrimType::pair, _) => {
+            let value_items = match value {
+                Micheline::Array(items) => items.as_slice(),
+                Micheline::Prim(prim) if prim.prim_type == PrimType::Pair => prim_args(prim),
+                _ => return type_mismatch(value, ty),
+            };
+            let (left, right) = split_comb(value_items, PrimType::Pair)
+                .ok_or_else(|| PackError::TypeMismatch { value: value.clone(), ty: ty.clone() })?;
+            let (left_ty, right_ty) = split_comb(ty_args, PrimType::pair)
+                .ok_or_else(|| PackError::TypeMismatch { value: value.clone(), ty: ty.clone() })?;
+
+            MichelinePrim::new(PrimType::Pair)
+                .with_arg(convert(left, left_ty, convert_leaf)?)
+                .with_arg(convert(&right, &right_ty, convert_leaf)?)
+                .into()
+        }
+        (PrimType::option, Some((PrimType::None, []))) => value.clone(),
+        (PrimType::option, Some((PrimType::Some, [inner]))) if ty_args.len() == 1 => {
+            MichelinePrim::new(PrimType::Some)
+                .with_arg(convert(inner, &ty_args[0], convert_leaf)?)
+                .into()
+        }
+        (PrimType::or, Some((side @ PrimType::Left, [inner])))
+        | (PrimType::or, Some((side @ PrimType::Right, [inner]))) if ty_args.len() == 2 => {
+            let inner_ty = if side == PrimType::Left { &ty_args[0] } else { &ty_args[1] };
+            MichelinePrim::new(side)
+                .with_arg(convert(inner, inner_ty, convert_leaf)?)
+                .into()
+        }
+        (PrimType::list, _) | (PrimType::set, _) if ty_args.len() == 1 => {
+            let items = match value {
+                Micheline::Array(items) => items,
+                _ => return type_mismatch(value, ty),
+            };
+            Micheline::Array(items.iter()
+                .map(|item| convert(item, &ty_args[0], convert_leaf))
+                .collect::<PackResult<_>>()?)
+        }
+        (PrimType::map, _) | (PrimType::big_map, _) if ty_args.len() == 2 => {
+            let items = match value {
+                Micheline::Array(items) => items,
+                _ => return type_mismatch(value, ty),
+            };
+            Micheline::Array(items.iter()
+                .map(|item| match item {
+                    Micheline::Prim(prim) if prim.prim_type == PrimType::Elt => {
+                        match prim_args(prim) {
+                            [key, value] => Ok(MichelinePrim::new(PrimType::Elt)
+                                .with_arg(convert(key, &ty_args[0], convert_leaf)?)
+                                .with_arg(convert(value, &ty_args[1], convert_leaf)?)
+                                .into()),
+                            _ => type_mismatch(item, ty),
+                        }
+                    }
+                    _ => type_mismatch(item, ty),
+                })
+                .collect::<PackResult<_>>()?)
+        }
+        (PrimType::option, _)
+        | (PrimType::or, _)
+        | (PrimType::list, _)
+        | (PrimType::set, _)
+        | (PrimType::map, _)
+        | (PrimType::big_map, _) => return type_mismatch(value, ty),
+        (prim_type, _) => convert_leaf(prim_type, value)?,
+    })
+}
+
+fn invalid_value(kind: &'static str, value: &str) -> PackError {
+    PackError::InvalidValue { kind, value: value.to_string() }
+}
+
+fn decode_prefixed(encoded: &str, prefix: Prefix, kind: &'static str) -> PackResult<Vec<u8>> {
+    encoded.from_base58check().ok()
+        .and_then(|bytes| bytes.without_prefix(prefix).ok())
+        .ok_or_else(|| invalid_value(kind, encoded))
+}
+
+/// Days since unix epoch for the given date.
+///
+/// See: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Parse RFC3339 timestamp (`2021-06-01T12:00:00Z`) into unix seconds.
+fn parse_timestamp(timestamp: &str) -> Option<i64> {
+    let num = |range: std::ops::Range<usize>| -> Option<i64> {
+        let part = timestamp.get(range)?;
+        if part.bytes().all(|b| b.is_ascii_digit()) { part.parse().ok() } else { None }
+    };
+    let bytes = timestamp.as_bytes();
+    if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-'
+        || !(bytes[10] == b'T' || bytes[10] == b't' || bytes[10] == b' ')
+        || bytes[13] != b':' || bytes[16] != b':'
+    {
+        return None;
+    }
+
+    let (month, day) = (num(5..7)?, num(8..10)?);
+    let (hours, minutes, seconds) = (num(11..13)?, num(14..16)?, num(17..19)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day)
+        || hours > 23 || minutes > 59 || seconds > 60
+    {
+        return None;
+    }
+
+    // skip fractions of the second.
+    let mut rest = &timestamp[19..];
+    if rest.starts_with('.') {
+        let frac_len = rest[1..].bytes().take_while(u8::is_ascii_digit).count();
+        rest = &rest[(1 + frac_len)..];
+    }
+
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ if rest.len() == 6 && rest.as_bytes()[3] == b':' => {
+            let sign = match rest.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let offset_hours: i64 = rest[1..3].parse().ok()?;
+            let offset_minutes: i64 = rest[4..6].parse().ok()?;
+            sign * (offset_hours * 3600 + offset_minutes * 60)
+        }
+        _ => return None,
+    };
+
+    Some(days_from_civil(num(0..4)?, month, day) * 86400
+        + hours * 3600 + minutes * 60 + seconds
+        - offset)
+}
+
+/// Forge address optionally followed by an entrypoint: `KT1...%transfer`.
+fn forge_address(encoded: &str) -> PackResult<Vec<u8>> {
+    let (address, entrypoint) = match encoded.find('%') {
+        Some(index) => (&encoded[..index], &encoded[(index + 1)..]),
+        None => (encoded, ""),
+    };
+    let address = Address::from_base58check(address)
+        .map_err(|_| invalid_value("address", encoded))?;
+
+    Ok([address.forge().take(), entrypoint.as_bytes().to_vec()].concat())
+}
+
+/// Convert values to their optimized binary representation.
+fn pack_leaf(ty: PrimType, value: &Micheline) -> PackResult<Micheline> {
+    let s = match value {
+        Micheline::String(s) => s,
+        _ => return Ok(value.clone()),
+    };
+
+    Ok(match ty {
+        PrimType::address | PrimType::contract => Micheline::Bytes(forge_address(s)?),
+        PrimType::key_hash => Micheline::Bytes(
+            ImplicitAddress::from_base58check(s)
+                .map_err(|_| invalid_value("key_hash", s))?
+                .forge().take(),
+        ),
+        PrimType::key => Micheline::Bytes(
+            PublicKey::from_base58check(s)
+                .map_err(|_| invalid_value("key", s))?
+                .forge().take(),
+        ),
+        PrimType::signature => Micheline::Bytes(
+            Signature::from_base58check(s)
+                .map_err(|_| invalid_value("signature", s))?
+                .as_ref().to_vec(),
+        ),
+        PrimType::chain_id => Micheline::Bytes(decode_prefixed(s, Prefix::Net, "chain_id")?),
+        PrimType::timestamp => Micheline::int(
+            parse_timestamp(s).ok_or_else(|| invalid_value("timestamp", s))?,
+        ),
+        _ => value.clone(),
+    })
+}
+
+/// Convert optimized binary values back to their readable representation.
+fn unpack_leaf(ty: PrimType, value: &Micheline) -> PackResult<Micheline> {
+    let bytes = match value {
+        Micheline::Bytes(bytes) => bytes,
+        _ => return Ok(value.clone()),
+    };
+
+    Ok(match ty {
+        PrimType::address | PrimType::contract => {
+            if bytes.len() < ADDRESS_BYTES_LEN {
+                return Err(invalid_value("address", &hex::encode(bytes)));
+            }
+            let (address, entrypoint) = bytes.split_at(ADDRESS_BYTES_LEN);
+            let address = Address::unforge(address)?.to_base58check();
+            match str::from_utf8(entrypoint) {
+                Ok("") => Micheline::String(address),
+                Ok(entrypoint) => Micheline::String(format!("{}%{}", address, entrypoint)),
+                Err(_) => return Err(invalid_value("entrypoint", &format!("{:?}", entrypoint))),
+            }
+        }
+        PrimType::key_hash => Micheline::String(ImplicitAddress::unforge(bytes)?.to_base58check()),
+        PrimType::key => Micheline::String(PublicKey::unforge(bytes)?.to_base58check()),
+        // curve of the signature is unknown, so generic `sig` is used.
+        PrimType::signature => Micheline::String(bytes.with_prefix(Prefix::sig).to_base58check()),
+        PrimType::chain_id => Micheline::String(bytes.with_prefix(Prefix::Net).to_base58check()),
+        _ => value.clone(),
+    })
+}
+
+/// Pack data of the given type, same way as `PACK` instruction does.
+///
+/// Values like addresses and keys are converted into their optimized
+/// (binary) form, so that result matches the one produced by the node.
+pub fn pack(value: &Micheline, ty: &Micheline) -> PackResult<Vec<u8>> {
+    let packed = convert(value, ty, &pack_leaf)?;
+    Ok([vec![PACK_PREFIX], packed.forge().take()].concat())
+}
+
+/// Inverse of [pack].
+///
+/// Values like addresses and keys are converted back into their
+/// readable (base58check) form.
+pub fn unpack(bytes: &[u8], ty: &Micheline) -> PackResult<Micheline> {
+    match bytes.split_first() {
+        Some((&PACK_PREFIX, data)) => convert(&Micheline::unforge(data)?, ty, &unpack_leaf),
+        _ => Err(PackError::MissingPackPrefix),
+    }
+}
+
+/// Script expression hash (`expr...`) of the packed data.
+///
+/// Used as a key for querying big maps.
+pub fn script_expr_hash(packed: &[u8]) -> String {
+    blake2b::digest_256(packed)
+        .with_prefix(Prefix::expr)
+        .to_base58check()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ty(source: &str) -> Micheline {
+        source.parse().unwrap()
+    }
+
+    #[test]
+    fn test_pack() {
+        let cases = vec![
+            ("1", "nat", "050001"),
+            ("-1", "int", "050041"),
+            (r#""foo""#, "string", "050100000003666f6f"),
+            (
+                r#""tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx""#,
+                "address",
+                "050a00000016000002298c03ed7d454a101eb7022bc95f7e5f41ac78",
+            ),
+            (
+                r#""2019-09-26T10:59:51Z""#,
+                "timestamp",
+                "0500a7e8e4d80b",
+            ),
+            ("Pair 1 2 3", "pair nat nat nat", "0507070001070700020003"),
+        ];
+
+        for (value, value_ty, expected) in cases {
+            let packed = pack(&value.parse().unwrap(), &ty(value_ty)).unwrap();
+            assert_eq!(hex::encode(&packed), expected, "packing {}", value);
+        }
+    }
+
+    #[test]
+    fn test_unpack() {
+        let value_ty = ty("pair (address %owner) (map key_hash nat)");
+        let value: Micheline = r#"
+            Pair "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn%transfer"
+                 { Elt "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx" 5 }
+        "#.parse().unwrap();
+
+        let packed = pack(&value, &value_ty).unwrap();
+        assert_eq!(unpack(&packed, &value_ty), Ok(value));
+        assert_eq!(unpack(&packed[1..], &value_ty), Err(PackError::MissingPackPrefix));
+    }
+
+    #[test]
+    fn test_unpack_invalid_address() {
+        // 0x0a - bytes, followed by the 4 byte length and the payload.
+        assert_eq!(
+            unpack(&[PACK_PREFIX, 0x0a, 0, 0, 0, 2, 0x00, 0x00], &ty("address")),
+            Err(PackError::InvalidValue { kind: "address", value: "0000".to_string() }),
+        );
+        assert!(matches!(
+            unpack(&[&[PACK_PREFIX, 0x0a, 0, 0, 0, 22, 0x02][..], &[0; 21]].concat(), &ty("address")),
+            Err(PackError::Unforge(UnforgeError::UnknownAddressTag { .. })),
+        ));
+    }
+
+    #[test]
+    fn test_pack_errors() {
+        assert!(matches!(
+            pack(&Micheline::int(1), &ty("list nat")),
+            Err(PackError::TypeMismatch { .. }),
+        ));
+        assert_eq!(
+            pack(&Micheline::str("tz1"), &ty("key_hash")),
+            Err(PackError::InvalidValue { kind: "key_hash", value: "tz1".to_string() }),
+        );
+    }
+
+    #[test]
+    fn test_script_expr_hash() {
+        let packed = pack(&Micheline::str("foo"), &ty("string")).unwrap();
+        assert_eq!(
+            script_expr_hash(&packed),
+            "expruTFUPVsqkuD5iwLMJuzoyGSFABnxLo7CZrgnS1czt1WbTwpVrJ",
+        );
+    }
+}
@@ -1,8 +1,55 @@
-#[allow(non_camel_case_types)]
-#[repr(u8)]
-#[derive(PartialEq, Debug, Clone, Copy)]
-pub enum PrimType {
-    parameter = 0,
+use std::fmt;
+use std::str::FromStr;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+
+use super::{Unforge, UnforgeReader, UnforgeResult, UnforgeError};
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+#[error("unknown Micheline primitive: \"{0}\"")]
+pub struct UnknownPrimTypeError(pub String);
+
+macro_rules! prim_types {
+    ($($name:ident),* $(,)?) => {
+        #[allow(non_camel_case_types)]
+        #[repr(u8)]
+        #[derive(PartialEq, Debug, Clone, Copy)]
+        pub enum PrimType {
+            $($name),*
+        }
+
+        impl PrimType {
+            const VARIANTS: &'static [PrimType] = &[$(Self::$name),*];
+
+            /// Get primitive by it's binary tag.
+            pub fn from_tag(tag: u8) -> Option<Self> {
+                // variants have sequential tags starting from 0.
+                Self::VARIANTS.get(tag as usize).copied()
+            }
+
+            /// Name of the primitive as it appears in Michelson and
+            /// Micheline JSON.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$name => stringify!($name)),*
+                }
+            }
+        }
+
+        impl FromStr for PrimType {
+            type Err = UnknownPrimTypeError;
+
+            fn from_str(name: &str) -> Result<Self, Self::Err> {
+                match name {
+                    $(stringify!($name) => Ok(Self::$name),)*
+                    _ => Err(UnknownPrimTypeError(name.to_string())),
+                }
+            }
+        }
+    };
+}
+
+prim_types! {
+    parameter,
     storage,
     code,
     False,
@@ -142,7 +189,7 @@ pub enum PrimType {
     READ_TICKET,
     SPLIT_TICKET,
     JOIN_TICKETS,
-    GET_AND_UPDATE
+    GET_AND_UPDATE,
 }
 
 impl Into<u8> for PrimType {
@@ -150,3 +197,37 @@ impl Into<u8> for PrimType {
         self as u8
     }
 }
+
+impl Unforge for PrimType {
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        let position = reader.position();
+        let tag = reader.read_byte()?;
+
+        Self::from_tag(tag)
+            .ok_or(UnforgeError::UnknownPrimTag { position, tag })
+    }
+}
+
+impl fmt::Display for PrimType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl Serialize for PrimType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for PrimType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+
+        Self::from_str(&name).map_err(serde::de::Error::custom)
+    }
+}
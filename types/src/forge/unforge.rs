@@ -0,0 +1,250 @@
+use std::convert::TryInto;
+
+/// Error while decoding forged bytes.
+///
+/// `position` is the byte offset in the input at which decoding failed.
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+pub enum UnforgeError {
+    #[error("unexpected end of input at position {position}. Expected {expected} more byte(s)")]
+    UnexpectedEnd {
+        position: usize,
+        expected: usize,
+    },
+    #[error("unexpected trailing bytes at position {position}")]
+    TrailingBytes {
+        position: usize,
+    },
+    #[error("invalid boolean byte {value:#04x} at position {position}")]
+    InvalidBool {
+        position: usize,
+        value: u8,
+    },
+    #[error("number at position {position} is too big")]
+    NumberOverflow {
+        position: usize,
+    },
+    #[error("invalid utf-8 string at position {position}")]
+    InvalidString {
+        position: usize,
+    },
+    #[error("unknown Micheline expression tag {tag:#04x} at position {position}")]
+    UnknownMichelineTag {
+        position: usize,
+        tag: u8,
+    },
+    #[error("Micheline expression at position {position} is nested deeper than {max_depth} levels")]
+    TooDeep {
+        position: usize,
+        max_depth: usize,
+    },
+    #[error("invalid annotation at position {position}")]
+    InvalidAnnotation {
+        position: usize,
+    },
+    #[error("unknown Micheline primitive tag {tag:#04x} at position {position}")]
+    UnknownPrimTag {
+        position: usize,
+        tag: u8,
+    },
+    #[error("unknown entrypoint tag {tag:#04x} at position {position}")]
+    UnknownEntrypointTag {
+        position: usize,
+        tag: u8,
+    },
+    #[error("entrypoint name at position {position} is too long")]
+    EntrypointNameTooLong {
+        position: usize,
+    },
+    #[error("unknown address tag {tag:#04x} at position {position}")]
+    UnknownAddressTag {
+        position: usize,
+        tag: u8,
+    },
+    #[error("unknown public key tag {tag:#04x} at position {position}")]
+    UnknownPublicKeyTag {
+        position: usize,
+        tag: u8,
+    },
+    #[error("unknown operation tag {tag} at position {position}")]
+    UnknownOperationTag {
+        position: usize,
+        tag: u64,
+    },
+    #[error("unknown ballot tag {tag:#04x} at position {position}")]
+    UnknownBallotTag {
+        position: usize,
+        tag: u8,
+    },
+    #[error("unsupported origination delegate at position {position}")]
+    UnsupportedOriginationDelegate {
+        position: usize,
+    },
+}
+
+pub type UnforgeResult<T> = Result<T, UnforgeError>;
+
+/// Cursor over forged bytes, used for decoding them.
+#[derive(Debug, Clone)]
+pub struct UnforgeReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    /// Nesting depth of the value being decoded (see [UnforgeReader::nested]).
+    depth: usize,
+}
+
+impl<'a> UnforgeReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0, depth: 0 }
+    }
+
+    /// Decode nested value using `f`, failing if it's nested deeper
+    /// than `max_depth` levels, instead of overflowing the stack.
+    pub fn nested<T, F>(&mut self, max_depth: usize, f: F) -> UnforgeResult<T>
+        where F: FnOnce(&mut Self) -> UnforgeResult<T>,
+    {
+        if self.depth >= max_depth {
+            return Err(UnforgeError::TooDeep { position: self.position, max_depth });
+        }
+
+        self.depth += 1;
+        let result = f(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Offset of the next byte to be read.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Number of bytes left to read.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Make sure that whole input was consumed.
+    pub fn finish(&self) -> UnforgeResult<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(UnforgeError::TrailingBytes { position: self.position })
+        }
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> UnforgeResult<&'a [u8]> {
+        if len > self.remaining() {
+            return Err(UnforgeError::UnexpectedEnd {
+                position: self.position,
+                expected: len - self.remaining(),
+            });
+        }
+
+        let bytes = &self.bytes[self.position..(self.position + len)];
+        self.position += len;
+        Ok(bytes)
+    }
+
+    /// Read fixed size byte array.
+    pub fn read_array<const N: usize>(&mut self) -> UnforgeResult<[u8; N]> {
+        // unwrap is fine since we read exactly `N` bytes.
+        Ok(self.read_bytes(N)?.try_into().unwrap())
+    }
+
+    /// Bytes that were read, starting from the `position`.
+    pub fn bytes_since(&self, position: usize) -> &'a [u8] {
+        &self.bytes[position..self.position]
+    }
+
+    pub fn read_byte(&mut self) -> UnforgeResult<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub fn read_bool(&mut self) -> UnforgeResult<bool> {
+        let position = self.position;
+        match self.read_byte()? {
+            0 => Ok(false),
+            255 => Ok(true),
+            value => Err(UnforgeError::InvalidBool { position, value }),
+        }
+    }
+
+    pub fn read_u32(&mut self) -> UnforgeResult<u32> {
+        // unwrap is fine since we read exactly 4 bytes.
+        Ok(u32::from_be_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+
+    /// Read a number encoded using LEB128 encoding (Zarith).
+    ///
+    /// Inverse of [ForgeNat](super::ForgeNat).
+    pub fn read_nat(&mut self) -> UnforgeResult<u64> {
+        let position = self.position;
+        let mut num: u64 = 0;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.read_byte()?;
+            let bits = (byte & 0x7F) as u64;
+
+            if shift >= 64 || (bits << shift) >> shift != bits {
+                return Err(UnforgeError::NumberOverflow { position });
+            }
+            num |= bits << shift;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                return Ok(num);
+            }
+        }
+    }
+
+    /// Read bytes, prefixed with their length as `u32`.
+    pub fn read_dynamic_bytes(&mut self) -> UnforgeResult<&'a [u8]> {
+        let len = self.read_u32()? as usize;
+        self.read_bytes(len)
+    }
+
+    /// Read utf-8 string, prefixed with its length as `u32`.
+    pub fn read_string(&mut self) -> UnforgeResult<String> {
+        let position = self.position;
+        let bytes = self.read_dynamic_bytes()?;
+
+        String::from_utf8(bytes.to_vec())
+            .map_err(|_| UnforgeError::InvalidString { position })
+    }
+
+    /// Get a reader for the next `u32` length prefixed bytes.
+    ///
+    /// Returned reader keeps positions relative to the whole input,
+    /// so that errors point at the correct offset.
+    pub fn dynamic_reader(&mut self) -> UnforgeResult<UnforgeReader<'a>> {
+        let len = self.read_u32()? as usize;
+        let start = self.position;
+        self.read_bytes(len)?;
+
+        Ok(UnforgeReader {
+            bytes: &self.bytes[..(start + len)],
+            position: start,
+            depth: self.depth,
+        })
+    }
+}
+
+/// Inverse of [Forge](super::Forge).
+pub trait Unforge: Sized {
+    /// Decode value from the reader, advancing it past the decoded bytes.
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self>;
+
+    /// Decode value from forged bytes.
+    ///
+    /// Fails if `bytes` contain anything besides the value.
+    fn unforge(bytes: &[u8]) -> UnforgeResult<Self> {
+        let mut reader = UnforgeReader::new(bytes);
+        let value = Self::unforge_from(&mut reader)?;
+        reader.finish()?;
+        Ok(value)
+    }
+}
@@ -0,0 +1,490 @@
+use crate::{
+    Address, ImplicitAddress, OriginatedAddress, BlockHash,
+    NewOperation, PublicKey,
+    NewOriginationOperation, NewOriginationScript, NewTransactionParameters,
+    NewDelegationOperation, NewTransactionOperation, NewRevealOperation,
+    NewActivationOperation, NewProposalsOperation, NewBallotOperation,
+    Ballot, ProtocolHash, SIGNATURE_LEN,
+};
+use super::micheline::Micheline;
+use super::forge_operations::OperationTag;
+use super::{Unforge, UnforgeReader, UnforgeResult, UnforgeError};
+
+impl Unforge for ImplicitAddress {
+    /// Inverse of `ImplicitAddress::forge()`.
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        let position = reader.position();
+
+        Ok(match reader.read_byte()? {
+            0 => ImplicitAddress::tz1(reader.read_array()?),
+            1 => ImplicitAddress::tz2(reader.read_array()?),
+            2 => ImplicitAddress::tz3(reader.read_array()?),
+            tag => {
+                return Err(UnforgeError::UnknownAddressTag { position, tag });
+            }
+        })
+    }
+}
+
+impl Unforge for OriginatedAddress {
+    /// Inverse of `OriginatedAddress::forge()`.
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        let address = OriginatedAddress::from(reader.read_array()?);
+
+        // padding byte.
+        let position = reader.position();
+        match reader.read_byte()? {
+            0 => Ok(address),
+            tag => Err(UnforgeError::UnknownAddressTag { position, tag }),
+        }
+    }
+}
+
+impl Unforge for Address {
+    /// Inverse of `Address::forge()`.
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        let position = reader.position();
+
+        Ok(match reader.read_byte()? {
+            0 => ImplicitAddress::unforge_from(reader)?.into(),
+            1 => OriginatedAddress::unforge_from(reader)?.into(),
+            tag => {
+                return Err(UnforgeError::UnknownAddressTag { position, tag });
+            }
+        })
+    }
+}
+
+impl Unforge for PublicKey {
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        let position = reader.position();
+
+        Ok(match reader.read_byte()? {
+            0 => PublicKey::edpk(reader.read_array()?),
+            1 => PublicKey::sppk(reader.read_array()?),
+            2 => PublicKey::p2pk(reader.read_array()?),
+            tag => {
+                return Err(UnforgeError::UnknownPublicKeyTag { position, tag });
+            }
+        })
+    }
+}
+
+impl Unforge for BlockHash {
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        Ok(BlockHash::from(reader.read_array()?))
+    }
+}
+
+impl Unforge for NewOriginationScript {
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        Ok(Self {
+            code: unforge_dynamic_micheline(reader)?,
+            storage: unforge_dynamic_micheline(reader)?,
+        })
+    }
+}
+
+/// Unforge micheline, prefixed with its length.
+fn unforge_dynamic_micheline(reader: &mut UnforgeReader) -> UnforgeResult<Micheline> {
+    let mut micheline_reader = reader.dynamic_reader()?;
+    let micheline = Micheline::unforge_from(&mut micheline_reader)?;
+    micheline_reader.finish()?;
+    Ok(micheline)
+}
+
+fn unforge_reveal(reader: &mut UnforgeReader) -> UnforgeResult<NewRevealOperation> {
+    Ok(NewRevealOperation {
+        source: ImplicitAddress::unforge_from(reader)?,
+        fee: reader.read_nat()?,
+        counter: reader.read_nat()?,
+        gas_limit: reader.read_nat()?,
+        storage_limit: reader.read_nat()?,
+        public_key: PublicKey::unforge_from(reader)?,
+    })
+}
+
+fn unforge_transaction(reader: &mut UnforgeReader) -> UnforgeResult<NewTransactionOperation> {
+    Ok(NewTransactionOperation {
+        source: ImplicitAddress::unforge_from(reader)?,
+        fee: reader.read_nat()?,
+        counter: reader.read_nat()?,
+        gas_limit: reader.read_nat()?,
+        storage_limit: reader.read_nat()?,
+        amount: reader.read_nat()?,
+        destination: Address::unforge_from(reader)?,
+        parameters: if reader.read_bool()? {
+            Some(NewTransactionParameters::unforge_from(reader)?)
+        } else {
+            None
+        },
+    })
+}
+
+fn unforge_delegation(reader: &mut UnforgeReader) -> UnforgeResult<NewDelegationOperation> {
+    Ok(NewDelegationOperation {
+        source: ImplicitAddress::unforge_from(reader)?,
+        fee: reader.read_nat()?,
+        counter: reader.read_nat()?,
+        gas_limit: reader.read_nat()?,
+        storage_limit: reader.read_nat()?,
+        delegate_to: if reader.read_bool()? {
+            Some(ImplicitAddress::unforge_from(reader)?)
+        } else {
+            None
+        },
+    })
+}
+
+fn unforge_origination(reader: &mut UnforgeReader) -> UnforgeResult<NewOriginationOperation> {
+    let source = ImplicitAddress::unforge_from(reader)?;
+    let fee = reader.read_nat()?;
+    let counter = reader.read_nat()?;
+    let gas_limit = reader.read_nat()?;
+    let storage_limit = reader.read_nat()?;
+    let balance = reader.read_nat()?;
+
+    // `NewOriginationOperation` doesn't support setting delegate.
+    let position = reader.position();
+    if reader.read_bool()? {
+        return Err(UnforgeError::UnsupportedOriginationDelegate { position });
+    }
+
+    Ok(NewOriginationOperation {
+        source,
+        fee,
+        counter,
+        gas_limit,
+        storage_limit,
+        balance,
+        script: NewOriginationScript::unforge_from(reader)?,
+    })
+}
+
+fn unforge_activation(reader: &mut UnforgeReader) -> UnforgeResult<NewActivationOperation> {
+    Ok(NewActivationOperation {
+        pkh: ImplicitAddress::tz1(reader.read_array()?),
+        secret: reader.read_array()?,
+    })
+}
+
+impl Unforge for ProtocolHash {
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        Ok(ProtocolHash::from(reader.read_array()?))
+    }
+}
+
+impl Unforge for Ballot {
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        let position = reader.position();
+
+        Ok(match reader.read_byte()? {
+            0 => Ballot::Yay,
+            1 => Ballot::Nay,
+            2 => Ballot::Pass,
+            tag => {
+                return Err(UnforgeError::UnknownBallotTag { position, tag });
+            }
+        })
+    }
+}
+
+fn unforge_proposals(reader: &mut UnforgeReader) -> UnforgeResult<NewProposalsOperation> {
+    let source = ImplicitAddress::unforge_from(reader)?;
+    let period = reader.read_u32()?;
+
+    let mut proposals_reader = reader.dynamic_reader()?;
+    let mut proposals = vec![];
+    while !proposals_reader.is_empty() {
+        proposals.push(ProtocolHash::unforge_from(&mut proposals_reader)?);
+    }
+
+    Ok(NewProposalsOperation { source, period, proposals })
+}
+
+fn unforge_ballot(reader: &mut UnforgeReader) -> UnforgeResult<NewBallotOperation> {
+    Ok(NewBallotOperation {
+        source: ImplicitAddress::unforge_from(reader)?,
+        period: reader.read_u32()?,
+        proposal: ProtocolHash::unforge_from(reader)?,
+        ballot: Ballot::unforge_from(reader)?,
+    })
+}
+
+impl Unforge for NewOperation {
+    /// Inverse of `NewOperation::forge()`.
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        let position = reader.position();
+        let tag = reader.read_nat()?;
+
+        Ok(match tag {
+            t if t == OperationTag::Reveal as u64 => unforge_reveal(reader)?.into(),
+            t if t == OperationTag::Transaction as u64 => unforge_transaction(reader)?.into(),
+            t if t == OperationTag::Delegation as u64 => unforge_delegation(reader)?.into(),
+            t if t == OperationTag::Origination as u64 => unforge_origination(reader)?.into(),
+            t if t == OperationTag::Activation as u64 => unforge_activation(reader)?.into(),
+            t if t == OperationTag::Proposals as u64 => unforge_proposals(reader)?.into(),
+            t if t == OperationTag::Ballot as u64 => unforge_ballot(reader)?.into(),
+            tag => {
+                return Err(UnforgeError::UnknownOperationTag { position, tag });
+            }
+        })
+    }
+}
+
+/// Operation group decoded from forged bytes.
+#[derive(PartialEq, Debug, Clone)]
+pub struct UnforgedOperationGroup {
+    pub branch: BlockHash,
+    pub operations: Vec<NewOperation>,
+    /// Signature bytes, if signed operation was unforged.
+    pub signature: Option<[u8; SIGNATURE_LEN]>,
+}
+
+impl UnforgedOperationGroup {
+    /// Unforge operation group, followed by the signature.
+    pub fn unforge_signed(bytes: &[u8]) -> UnforgeResult<Self> {
+        if bytes.len() < SIGNATURE_LEN {
+            return Err(UnforgeError::UnexpectedEnd {
+                position: bytes.len(),
+                expected: SIGNATURE_LEN - bytes.len(),
+            });
+        }
+        let (operation_bytes, signature_bytes) = bytes.split_at(bytes.len() - SIGNATURE_LEN);
+
+        let mut group = Self::unforge(operation_bytes)?;
+        group.signature = Some(UnforgeReader::new(signature_bytes).read_array()?);
+        Ok(group)
+    }
+}
+
+impl Unforge for UnforgedOperationGroup {
+    /// Inverse of `NewOperationGroup::forge()`.
+    ///
+    /// Input must not contain signature. For signed operation
+    /// use [UnforgedOperationGroup::unforge_signed].
+    fn unforge_from(reader: &mut UnforgeReader) -> UnforgeResult<Self> {
+        let branch = BlockHash::unforge_from(reader)?;
+        let mut operations = vec![];
+
+        while !reader.is_empty() {
+            operations.push(NewOperation::unforge_from(reader)?);
+        }
+
+        Ok(Self {
+            branch,
+            operations,
+            signature: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Forge, NewOperationGroup, manager_contract_code};
+    use crate::micheline::{MichelineEntrypoint, MichelinePrim, PrimType};
+
+    fn source() -> ImplicitAddress {
+        ImplicitAddress::from_base58check("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx").unwrap()
+    }
+
+    fn operation_group() -> NewOperationGroup {
+        let branch = BlockHash::from_base58check(
+            "BLockGenesisGenesisGenesisGenesisGenesisf79b5d1CoW2",
+        ).unwrap();
+        let contract = OriginatedAddress::from_base58check(
+            "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn",
+        ).unwrap();
+
+        NewOperationGroup::new(branch, "".to_string())
+            .with_operation(NewRevealOperation {
+                source: source(),
+                public_key: PublicKey::from_base58check(
+                    "edpkuBknW28nW72KG6RoHtYW7p12T6GKc7nAbwYX5m8Wd9sDVC9yav",
+                ).unwrap(),
+                fee: 1270,
+                counter: 1,
+                gas_limit: 10300,
+                storage_limit: 257,
+            })
+            .with_operation(NewTransactionOperation {
+                source: source(),
+                destination: contract.clone().into(),
+                amount: 0,
+                fee: 3000,
+                counter: 2,
+                gas_limit: 10300,
+                storage_limit: 257,
+                parameters: Some(NewTransactionParameters::Transfer {
+                    to: contract.into(),
+                    amount: 1_000_000,
+                }),
+            })
+            .with_operation(NewDelegationOperation {
+                source: source(),
+                delegate_to: Some(source()),
+                fee: 1000,
+                counter: 3,
+                gas_limit: 10300,
+                storage_limit: 0,
+            })
+            .with_operation(NewOriginationOperation {
+                source: source(),
+                balance: 0,
+                fee: 5000,
+                counter: 4,
+                gas_limit: 10000,
+                storage_limit: 10000,
+                script: NewOriginationScript {
+                    code: manager_contract_code(),
+                    storage: Micheline::str("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx"),
+                },
+            })
+    }
+
+    #[test]
+    fn test_unforge_operation_group() {
+        let group = operation_group();
+        let unforged = UnforgedOperationGroup::unforge(group.forge().as_ref()).unwrap();
+
+        assert_eq!(unforged.signature, None);
+        assert_eq!(unforged.operations.len(), 4);
+        assert_eq!(unforged.branch, group.branch);
+        assert_eq!(unforged.operations, group.operations);
+    }
+
+    #[test]
+    fn test_unforge_signed_operation_group() {
+        let group = operation_group();
+        let signed = [group.forge().take(), vec![7; SIGNATURE_LEN]].concat();
+        let unforged = UnforgedOperationGroup::unforge_signed(&signed).unwrap();
+
+        assert_eq!(unforged.signature, Some([7; SIGNATURE_LEN]));
+        assert_eq!(unforged.operations, group.operations);
+    }
+
+    #[test]
+    fn test_operation_group_batch_counters() {
+        let transaction = |amount| NewTransactionOperation {
+            source: source(),
+            destination: source().into(),
+            amount,
+            fee: 1000,
+            counter: 0,
+            gas_limit: 10300,
+            storage_limit: 257,
+            parameters: None,
+        };
+        let branch = BlockHash::from([0; 32]);
+        let group = NewOperationGroup::new(branch, "".to_string())
+            .with_operations((1..=10).map(transaction))
+            .with_counter(5);
+
+        let counters = group.operations.iter()
+            .map(|op| op.get_counter())
+            .collect::<Vec<_>>();
+        assert_eq!(counters, (5..15).collect::<Vec<_>>());
+        assert_eq!(group.total_fee(), 10_000);
+
+        let unforged = UnforgedOperationGroup::unforge(group.forge().as_ref()).unwrap();
+        assert_eq!(unforged.operations, group.operations);
+    }
+
+    #[test]
+    fn test_unforge_activation() {
+        let op = NewOperation::from(NewActivationOperation {
+            pkh: source(),
+            secret: NewActivationOperation::parse_secret(
+                "9b7f631e52f877a1d363474404da8130b0b940ee",
+            ).unwrap(),
+        });
+        let forged = op.forge().take();
+
+        assert_eq!(forged.len(), 1 + 20 + 20);
+        assert_eq!(forged[0], OperationTag::Activation as u8);
+        assert_eq!(NewOperation::unforge(&forged), Ok(op));
+        assert!(NewActivationOperation::parse_secret("9b7f").is_err());
+    }
+
+    #[test]
+    fn test_unforge_voting_operations() {
+        let proposal = ProtocolHash::from_base58check(
+            "PtHangz2aRngywmSRGGvrcTyMbbdpWdpFKuS4uMWxg2RaH9i1qx",
+        ).unwrap();
+
+        let proposals = NewOperation::from(NewProposalsOperation {
+            source: source(),
+            period: 42,
+            proposals: vec![proposal.clone(), proposal.clone()],
+        });
+        let forged = proposals.forge().take();
+        assert_eq!(forged.len(), 1 + 21 + 4 + 4 + 2 * 32);
+        assert_eq!(forged[0], OperationTag::Proposals as u8);
+        assert_eq!(NewOperation::unforge(&forged), Ok(proposals));
+
+        let ballot = NewOperation::from(NewBallotOperation {
+            source: source(),
+            period: 42,
+            proposal,
+            ballot: Ballot::Pass,
+        });
+        let mut forged = ballot.forge().take();
+        assert_eq!(forged.len(), 1 + 21 + 4 + 32 + 1);
+        assert_eq!(forged[0], OperationTag::Ballot as u8);
+        assert_eq!(NewOperation::unforge(&forged), Ok(ballot));
+
+        *forged.last_mut().unwrap() = 3;
+        assert_eq!(
+            NewOperation::unforge(&forged),
+            Err(UnforgeError::UnknownBallotTag { position: forged.len() - 1, tag: 3 }),
+        );
+    }
+
+    #[test]
+    fn test_unforge_transaction_parameters() {
+        let params = vec![
+            NewTransactionParameters::SetDelegate(source()),
+            NewTransactionParameters::CancelDelegate,
+            NewTransactionParameters::Transfer { to: source().into(), amount: 5 },
+            NewTransactionParameters::Call {
+                entrypoint: MichelineEntrypoint::from_name("transfer").unwrap(),
+                value: MichelinePrim::new(PrimType::Pair)
+                    .with_arg(Micheline::str("foo"))
+                    .with_arg(Micheline::int(-5))
+                    .into(),
+            },
+        ];
+
+        for param in params {
+            assert_eq!(NewTransactionParameters::unforge(param.forge().as_ref()), Ok(param));
+        }
+
+        // `default` entrypoint with `Unit` value.
+        assert_eq!(
+            NewTransactionParameters::unforge(&[0, 0, 0, 0, 2, 3, 0x0b]),
+            Ok(NewTransactionParameters::Call {
+                entrypoint: MichelineEntrypoint::Default,
+                value: MichelinePrim::new(PrimType::Unit).into(),
+            }),
+        );
+    }
+
+    #[test]
+    fn test_unforge_operation_errors() {
+        assert_eq!(
+            NewOperation::unforge(&[1]),
+            Err(UnforgeError::UnknownOperationTag { position: 0, tag: 1 }),
+        );
+        assert_eq!(
+            Address::unforge(&[2]),
+            Err(UnforgeError::UnknownAddressTag { position: 0, tag: 2 }),
+        );
+
+        let forged = operation_group().forge().take();
+        assert!(matches!(
+            UnforgedOperationGroup::unforge(&forged[..forged.len() - 1]),
+            Err(UnforgeError::UnexpectedEnd { expected: 1, .. }),
+        ));
+    }
+}
@@ -9,6 +9,9 @@ pub use network::*;
 mod block_hash;
 pub use block_hash::*;
 
+mod protocol_hash;
+pub use protocol_hash::*;
+
 mod address;
 pub use address::*;
 
@@ -21,11 +24,29 @@ pub use public_key::*;
 mod private_key;
 pub use private_key::*;
 
+mod encrypted_private_key;
+pub use encrypted_private_key::*;
+
+mod mnemonic;
+pub use mnemonic::*;
+
 mod combined_key;
 pub use combined_key::*;
 
+mod signature;
+pub use signature::*;
+
 mod operation;
 pub use operation::*;
 
 mod forge;
 pub use forge::*;
+
+mod michelson;
+pub use michelson::*;
+
+mod contracts;
+pub use contracts::*;
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
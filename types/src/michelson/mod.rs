@@ -0,0 +1,138 @@
+//! Michelson surface syntax (`.tz`) parsing and printing of [Micheline].
+//!
+//! [Micheline]: crate::micheline::Micheline
+
+mod parser;
+pub use parser::*;
+
+mod printer;
+pub use printer::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::micheline::{Micheline, MichelinePrim, PrimType};
+
+    fn prim(prim_type: PrimType) -> MichelinePrim {
+        MichelinePrim::new(prim_type)
+    }
+
+    #[test]
+    fn test_parse_michelson_values() {
+        assert_eq!(parse_michelson("-42"), Ok(Micheline::int(-42)));
+        assert_eq!(parse_michelson(r#""a \"b\"\n""#), Ok(Micheline::str("a \"b\"\n")));
+        assert_eq!(parse_michelson("0x0aff"), Ok(Micheline::Bytes(vec![10, 255])));
+        assert_eq!(parse_michelson("{}"), Ok(Micheline::Array(vec![])));
+        assert_eq!(
+            parse_michelson(r#"Pair 1 (Some "foo")"#),
+            Ok(prim(PrimType::Pair)
+                .with_arg(Micheline::int(1))
+                .with_arg(prim(PrimType::Some).with_arg(Micheline::str("foo")).into())
+                .into()),
+        );
+        assert_eq!(
+            parse_michelson("{ Elt 1 True ; Elt 2 False }"),
+            Ok(Micheline::Array(vec![
+                prim(PrimType::Elt)
+                    .with_arg(Micheline::int(1))
+                    .with_arg(prim(PrimType::True).into())
+                    .into(),
+                prim(PrimType::Elt)
+                    .with_arg(Micheline::int(2))
+                    .with_arg(prim(PrimType::False).into())
+                    .into(),
+            ])),
+        );
+    }
+
+    #[test]
+    fn test_parse_michelson_script() {
+        let source = r#"
+            # manager.tz like contract.
+            parameter (or (lambda %do unit (list operation)) (unit %default));
+            storage key_hash;
+            code { UNPAIR ;
+                   /* block
+                      comment */
+                   IF_LEFT
+                     { DROP ; NIL operation ; PAIR }
+                     { DROP ; NIL operation ; PAIR } }
+        "#;
+        let script = parse_michelson(source).unwrap();
+
+        let items = match &script {
+            Micheline::Array(items) => items,
+            _ => panic!("expected top level sequence"),
+        };
+        assert_eq!(items.len(), 3);
+        assert_eq!(
+            items[0],
+            prim(PrimType::parameter).with_arg(
+                prim(PrimType::or)
+                    .with_arg(prim(PrimType::lambda)
+                        .with_annot("%do").unwrap()
+                        .with_arg(prim(PrimType::unit).into())
+                        .with_arg(prim(PrimType::list)
+                            .with_arg(prim(PrimType::operation).into())
+                            .into())
+                        .into())
+                    .with_arg(prim(PrimType::unit).with_annot("%default").unwrap().into())
+                    .into(),
+            ).into(),
+        );
+
+        // printed script must be parsed back into the same value.
+        assert_eq!(parse_michelson(&format_michelson(&script)), Ok(script));
+
+        let manager = crate::manager_contract_code();
+        assert_eq!(parse_michelson(&manager.to_string()), Ok(manager));
+    }
+
+    #[test]
+    fn test_parse_michelson_errors() {
+        assert_eq!(parse_michelson(""), Err(ParseMichelsonError::UnexpectedEnd));
+        assert_eq!(parse_michelson("{ DROP"), Err(ParseMichelsonError::UnexpectedEnd));
+        assert_eq!(
+            parse_michelson("Pair 1 Foo"),
+            Err(ParseMichelsonError::UnknownPrim { position: 7, name: "Foo".to_string() }),
+        );
+        assert_eq!(
+            parse_michelson("\"abc"),
+            Err(ParseMichelsonError::UnterminatedString { position: 0 }),
+        );
+        assert_eq!(
+            parse_michelson("{ DROP } }"),
+            Err(ParseMichelsonError::UnexpectedToken {
+                position: 9,
+                expected: "';' or end of the sequence",
+            }),
+        );
+        assert_eq!(
+            parse_michelson("Pair 1 2 $"),
+            Err(ParseMichelsonError::UnexpectedChar { position: 9, ch: '$' }),
+        );
+
+        let max_depth = crate::micheline::MAX_MICHELINE_DEPTH;
+        let nested = |depth: usize| format!("{}Unit{}", "(Some ".repeat(depth), ")".repeat(depth));
+        assert!(parse_michelson(&nested(max_depth)).is_ok());
+        assert_eq!(
+            parse_michelson(&nested(max_depth + 1)),
+            Err(ParseMichelsonError::TooDeep { position: max_depth * 6, max_depth }),
+        );
+        assert!(parse_michelson(&"{".repeat(max_depth + 1)).is_err());
+    }
+
+    #[test]
+    fn test_format_michelson() {
+        let value = parse_michelson(r#"Pair (Some "foo") (Left (unit %a))"#).unwrap();
+        assert_eq!(value.to_string(), r#"Pair (Some "foo") (Left (unit %a))"#);
+
+        let long = parse_michelson(
+            "{ DROP ; NIL operation ; PUSH key_hash 0x00a31e81ac3425310e3274a4698a793b2839dc0afa ; SOME ; SET_DELEGATE ; CONS }",
+        ).unwrap();
+        assert_eq!(
+            long.to_string(),
+            "{ DROP ;\n  NIL operation ;\n  PUSH key_hash 0x00a31e81ac3425310e3274a4698a793b2839dc0afa ;\n  SOME ;\n  SET_DELEGATE ;\n  CONS }",
+        );
+    }
+}
@@ -0,0 +1,412 @@
+use std::str::FromStr;
+use sodiumoxide::hex;
+
+use crate::micheline::{Micheline, MichelinePrim, PrimType, MAX_MICHELINE_DEPTH};
+
+/// Error while parsing Michelson source.
+///
+/// `position` is the byte offset in the source at which parsing failed.
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+pub enum ParseMichelsonError {
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("unexpected character '{ch}' at position {position}")]
+    UnexpectedChar {
+        position: usize,
+        ch: char,
+    },
+    #[error("unexpected token at position {position}. Expected {expected}")]
+    UnexpectedToken {
+        position: usize,
+        expected: &'static str,
+    },
+    #[error("unknown primitive \"{name}\" at position {position}")]
+    UnknownPrim {
+        position: usize,
+        name: String,
+    },
+    #[error("invalid bytes at position {position}")]
+    InvalidBytes {
+        position: usize,
+    },
+    #[error("invalid escape sequence in string at position {position}")]
+    InvalidEscape {
+        position: usize,
+    },
+    #[error("unterminated string starting at position {position}")]
+    UnterminatedString {
+        position: usize,
+    },
+    #[error("unterminated comment starting at position {position}")]
+    UnterminatedComment {
+        position: usize,
+    },
+    #[error("expression at position {position} is nested deeper than {max_depth} levels")]
+    TooDeep {
+        position: usize,
+        max_depth: usize,
+    },
+}
+
+pub type ParseMichelsonResult<T> = Result<T, ParseMichelsonError>;
+
+#[derive(PartialEq, Debug, Clone)]
+enum Token {
+    Int(String),
+    String(String),
+    Bytes(Vec<u8>),
+    Ident(String),
+    Annot(String),
+    OpenBrace,
+    CloseBrace,
+    OpenParen,
+    CloseParen,
+    Semicolon,
+}
+
+impl Token {
+    /// Whether token can start an argument of the primitive.
+    fn is_arg_start(&self) -> bool {
+        matches!(
+            self,
+            Self::Int(_) | Self::String(_) | Self::Bytes(_)
+                | Self::Ident(_) | Self::OpenBrace | Self::OpenParen
+        )
+    }
+}
+
+fn is_ident_char(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || ch == '_'
+}
+
+fn is_annot_char(ch: char) -> bool {
+    is_ident_char(ch) || ch == '.' || ch == '%' || ch == '@'
+}
+
+struct Lexer<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn peek_char(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let ch = self.peek_char()?;
+        self.position += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn take_while<F>(&mut self, f: F) -> &'a str
+        where F: Fn(char) -> bool,
+    {
+        let start = self.position;
+        while self.peek_char().map(&f).unwrap_or(false) {
+            self.next_char();
+        }
+        &self.source[start..self.position]
+    }
+
+    /// Skip whitespaces and comments.
+    fn skip_ignored(&mut self) -> ParseMichelsonResult<()> {
+        loop {
+            let rest = &self.source[self.position..];
+
+            if rest.starts_with('#') {
+                self.take_while(|ch| ch != '\n');
+            } else if rest.starts_with("/*") {
+                let start = self.position;
+                match rest.find("*/") {
+                    Some(end) => self.position += end + 2,
+                    None => {
+                        return Err(ParseMichelsonError::UnterminatedComment {
+                            position: start,
+                        });
+                    }
+                }
+            } else if self.peek_char().map(char::is_whitespace).unwrap_or(false) {
+                self.take_while(char::is_whitespace);
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn read_string(&mut self) -> ParseMichelsonResult<String> {
+        let start = self.position;
+        // opening quote.
+        self.next_char();
+
+        let mut result = String::new();
+        loop {
+            match self.next_char() {
+                Some('"') => return Ok(result),
+                Some('\\') => {
+                    let position = self.position - 1;
+                    result.push(match self.next_char() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('b') => '\u{8}',
+                        Some(_) => {
+                            return Err(ParseMichelsonError::InvalidEscape { position });
+                        }
+                        None => {
+                            return Err(ParseMichelsonError::UnterminatedString {
+                                position: start,
+                            });
+                        }
+                    });
+                }
+                Some(ch) => result.push(ch),
+                None => {
+                    return Err(ParseMichelsonError::UnterminatedString {
+                        position: start,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Returns next token along with its position.
+    fn next_token(&mut self) -> ParseMichelsonResult<Option<(usize, Token)>> {
+        self.skip_ignored()?;
+        let position = self.position;
+
+        let ch = match self.peek_char() {
+            Some(ch) => ch,
+            None => return Ok(None),
+        };
+
+        let token = match ch {
+            '{' | '}' | '(' | ')' | ';' => {
+                self.next_char();
+                match ch {
+                    '{' => Token::OpenBrace,
+                    '}' => Token::CloseBrace,
+                    '(' => Token::OpenParen,
+                    ')' => Token::CloseParen,
+                    _ => Token::Semicolon,
+                }
+            }
+            '"' => Token::String(self.read_string()?),
+            '@' | '%' | ':' => {
+                self.next_char();
+                let name = self.take_while(is_annot_char);
+                Token::Annot(format!("{}{}", ch, name))
+            }
+            '0' if self.source[position..].starts_with("0x") => {
+                self.position += 2;
+                let encoded = self.take_while(|ch| ch.is_ascii_hexdigit());
+                Token::Bytes(hex::decode(encoded).map_err(|_| {
+                    ParseMichelsonError::InvalidBytes { position }
+                })?)
+            }
+            '-' | '0'..='9' => {
+                self.next_char();
+                let digits = self.take_while(|ch| ch.is_ascii_digit());
+                if ch == '-' && digits.is_empty() {
+                    return Err(ParseMichelsonError::UnexpectedChar { position, ch });
+                }
+                Token::Int(self.source[position..self.position].to_string())
+            }
+            ch if ch.is_ascii_alphabetic() => {
+                Token::Ident(self.take_while(is_ident_char).to_string())
+            }
+            ch => {
+                return Err(ParseMichelsonError::UnexpectedChar { position, ch });
+            }
+        };
+
+        Ok(Some((position, token)))
+    }
+}
+
+fn tokenize(source: &str) -> ParseMichelsonResult<Vec<(usize, Token)>> {
+    let mut lexer = Lexer { source, position: 0 };
+    let mut tokens = vec![];
+
+    while let Some(token) = lexer.next_token()? {
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    index: usize,
+    /// Number of braces and parentheses the parser is currently in.
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|(_, token)| token)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens.get(self.index)
+            .map(|(position, _)| *position)
+            .unwrap_or_default()
+    }
+
+    fn next(&mut self) -> ParseMichelsonResult<(usize, Token)> {
+        let token = self.tokens.get(self.index)
+            .cloned()
+            .ok_or(ParseMichelsonError::UnexpectedEnd)?;
+        self.index += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, expected_token: Token, expected: &'static str) -> ParseMichelsonResult<()> {
+        let (position, token) = self.next()?;
+        if token == expected_token {
+            Ok(())
+        } else {
+            Err(ParseMichelsonError::UnexpectedToken { position, expected })
+        }
+    }
+
+    /// Parse expression inside braces or parentheses using `f`, failing
+    /// if it's nested too deep, instead of overflowing the stack.
+    fn nested<T, F>(&mut self, position: usize, f: F) -> ParseMichelsonResult<T>
+        where F: FnOnce(&mut Self) -> ParseMichelsonResult<T>,
+    {
+        if self.depth >= MAX_MICHELINE_DEPTH {
+            return Err(ParseMichelsonError::TooDeep {
+                position,
+                max_depth: MAX_MICHELINE_DEPTH,
+            });
+        }
+
+        self.depth += 1;
+        let result = f(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn prim_type(position: usize, name: &str) -> ParseMichelsonResult<PrimType> {
+        PrimType::from_str(name).map_err(|_| ParseMichelsonError::UnknownPrim {
+            position,
+            name: name.to_string(),
+        })
+    }
+
+    /// Parse expressions separated by `;` until `end` token (or end of input).
+    fn parse_sequence(&mut self, end: Option<Token>) -> ParseMichelsonResult<Vec<Micheline>> {
+        let mut items = vec![];
+
+        loop {
+            if self.peek() == end.as_ref() {
+                return Ok(items);
+            }
+            items.push(self.parse_expr()?);
+
+            match self.peek() {
+                Some(Token::Semicolon) => { self.index += 1; }
+                token if token == end.as_ref() => {}
+                None => return Err(ParseMichelsonError::UnexpectedEnd),
+                _ => {
+                    return Err(ParseMichelsonError::UnexpectedToken {
+                        position: self.position(),
+                        expected: "';' or end of the sequence",
+                    });
+                }
+            }
+        }
+    }
+
+    /// Parse expression, which may be a primitive applied to arguments.
+    fn parse_expr(&mut self) -> ParseMichelsonResult<Micheline> {
+        match self.peek() {
+            Some(Token::Ident(_)) => {}
+            _ => return self.parse_arg(),
+        }
+
+        let (position, token) = self.next()?;
+        let name = match token {
+            Token::Ident(name) => name,
+            _ => unreachable!(),
+        };
+        let mut prim = MichelinePrim::new(Self::prim_type(position, &name)?);
+
+        while let Some(Token::Annot(annot)) = self.peek() {
+            // unwrap is fine since lexer only produces valid annotations.
+            prim = prim.with_annot(annot).unwrap();
+            self.index += 1;
+        }
+
+        while self.peek().map(Token::is_arg_start).unwrap_or(false) {
+            prim = prim.with_arg(self.parse_arg()?);
+        }
+
+        Ok(prim.into())
+    }
+
+    /// Parse expression, which can be used as an argument of a primitive
+    /// without wrapping it in parentheses.
+    fn parse_arg(&mut self) -> ParseMichelsonResult<Micheline> {
+        let (position, token) = self.next()?;
+
+        Ok(match token {
+            // unwrap is fine since lexer only produces valid integers.
+            Token::Int(num) => Micheline::Int(num.parse().unwrap()),
+            Token::String(s) => Micheline::String(s),
+            Token::Bytes(bytes) => Micheline::Bytes(bytes),
+            Token::Ident(name) => MichelinePrim::new(Self::prim_type(position, &name)?).into(),
+            Token::OpenBrace => self.nested(position, |parser| {
+                let items = parser.parse_sequence(Some(Token::CloseBrace))?;
+                parser.expect(Token::CloseBrace, "'}'")?;
+                Ok(Micheline::Array(items))
+            })?,
+            Token::OpenParen => self.nested(position, |parser| {
+                let expr = parser.parse_expr()?;
+                parser.expect(Token::CloseParen, "')'")?;
+                Ok(expr)
+            })?,
+            _ => {
+                return Err(ParseMichelsonError::UnexpectedToken {
+                    position,
+                    expected: "expression",
+                });
+            }
+        })
+    }
+}
+
+/// Parse Michelson source into [Micheline].
+///
+/// Source can be a single expression (`Pair 1 "foo"`, `{ DROP; UNIT }`)
+/// or a sequence of expressions separated by `;`, without surrounding
+/// braces, as in `.tz` script files. In the latter case result
+/// is [Micheline::Array].
+pub fn parse_michelson(source: &str) -> ParseMichelsonResult<Micheline> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        index: 0,
+        depth: 0,
+    };
+
+    let expr = parser.parse_expr()?;
+    if parser.peek().is_none() {
+        return Ok(expr);
+    }
+
+    // not a single expression, so parse again as a top level sequence.
+    parser.index = 0;
+    Ok(Micheline::Array(parser.parse_sequence(None)?))
+}
+
+impl FromStr for Micheline {
+    type Err = ParseMichelsonError;
+
+    /// Parse Michelson source. See [parse_michelson].
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        parse_michelson(source)
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt;
+use sodiumoxide::hex;
+
+use crate::micheline::{Micheline, MichelinePrim, PrimType};
+
+/// Expressions longer than this are split into multiple lines.
+const MAX_LINE_WIDTH: usize = 80;
+
+fn escape_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+
+    for ch in s.chars() {
+        match ch {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '\r' => result.push_str("\\r"),
+            '\u{8}' => result.push_str("\\b"),
+            ch => result.push(ch),
+        }
+    }
+
+    result
+}
+
+/// Whether prim needs to be wrapped in parentheses when it's
+/// an argument of another prim.
+fn needs_parens(prim: &MichelinePrim) -> bool {
+    prim.args.as_ref().map(|args| !args.is_empty()).unwrap_or(false)
+        || !prim.annots().is_empty()
+}
+
+/// Prim name along with annotations.
+fn prim_head(prim: &MichelinePrim) -> String {
+    std::iter::once(prim.prim_type.name().to_string())
+        .chain(prim.annots().iter().cloned())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Render expression in a single line.
+fn format_flat(value: &Micheline, is_arg: bool) -> String {
+    match value {
+        Micheline::Int(num) => num.to_string(),
+        Micheline::String(s) => format!("\"{}\"", escape_string(s)),
+        Micheline::Bytes(bytes) => format!("0x{}", hex::encode(bytes)),
+        Micheline::Array(items) if items.is_empty() => "{}".to_string(),
+        Micheline::Array(items) => {
+            let items = items.iter()
+                .map(|item| format_flat(item, false))
+                .collect::<Vec<_>>();
+            format!("{{ {} }}", items.join(" ; "))
+        }
+        Micheline::Prim(prim) => {
+            let formatted = std::iter::once(prim_head(prim))
+                .chain(prim.args.iter().flatten().map(|arg| format_flat(arg, true)))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            if is_arg && needs_parens(prim) {
+                format!("({})", formatted)
+            } else {
+                formatted
+            }
+        }
+    }
+}
+
+/// Render expression, which starts at `indent` column, splitting it
+/// into multiple lines if it's too long.
+fn format_pretty(out: &mut String, value: &Micheline, indent: usize, is_arg: bool) {
+    let flat = format_flat(value, is_arg);
+    if indent + flat.len() <= MAX_LINE_WIDTH {
+        out.push_str(&flat);
+        return;
+    }
+
+    match value {
+        Micheline::Array(items) => {
+            out.push_str("{ ");
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(" ;\n");
+                    out.push_str(&" ".repeat(indent + 2));
+                }
+                format_pretty(out, item, indent + 2, false);
+            }
+            out.push_str(" }");
+        }
+        Micheline::Prim(prim) if needs_parens(prim) => {
+            let indent = if is_arg {
+                out.push('(');
+                indent + 1
+            } else {
+                indent
+            };
+
+            out.push_str(&prim_head(prim));
+            for arg in prim.args.iter().flatten() {
+                out.push('\n');
+                out.push_str(&" ".repeat(indent + 2));
+                format_pretty(out, arg, indent + 2, true);
+            }
+
+            if is_arg {
+                out.push(')');
+            }
+        }
+        _ => out.push_str(&flat),
+    }
+}
+
+/// Whether value is a contract script: `parameter`, `storage`
+/// and `code` sections.
+fn is_script(value: &Micheline) -> bool {
+    match value {
+        Micheline::Array(items) if !items.is_empty() => {
+            items.iter().all(|item| match item {
+                Micheline::Prim(prim) => matches!(
+                    prim.prim_type,
+                    PrimType::parameter | PrimType::storage | PrimType::code
+                ),
+                _ => false,
+            })
+        }
+        _ => false,
+    }
+}
+
+/// Render [Micheline] as indented Michelson source.
+///
+/// Contract scripts are rendered the same way as in `.tz` files,
+/// without surrounding braces.
+pub fn format_michelson(value: &Micheline) -> String {
+    let mut out = String::new();
+
+    match value {
+        Micheline::Array(items) if is_script(value) => {
+            for item in items {
+                format_pretty(&mut out, item, 0, false);
+                out.push_str(";\n");
+            }
+        }
+        value => format_pretty(&mut out, value, 0, false),
+    }
+
+    out
+}
+
+impl fmt::Display for Micheline {
+    /// Display as Michelson. See [format_michelson].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_michelson(self))
+    }
+}
@@ -0,0 +1,112 @@
+use sodiumoxide::crypto::sign::ed25519;
+
+use crypto::mnemonic::{mnemonic_to_seed, InvalidMnemonicError};
+use crypto::slip10::{derive_ed25519, NonHardenedIndexError};
+use crate::{KeyDerivationPath, PrivateKey, PublicKey};
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+#[error(transparent)]
+pub enum FromMnemonicError {
+    InvalidMnemonic(#[from] InvalidMnemonicError),
+    NonHardenedIndex(#[from] NonHardenedIndexError),
+}
+
+/// Derive ed25519 key pair from BIP39 mnemonic and optional passphrase,
+/// using SLIP-10 derivation for the given path.
+///
+/// For the same path, resulting address is the same as the one
+/// produced by Ledger or Trezor, initialized with the same mnemonic.
+pub fn ed25519_keys_from_mnemonic(
+    mnemonic: &str,
+    passphrase: &str,
+    path: &KeyDerivationPath,
+) -> Result<(PublicKey, PrivateKey), FromMnemonicError>
+{
+    let seed = mnemonic_to_seed(mnemonic, passphrase)?;
+    let key = derive_ed25519(&seed, path.as_ref())?;
+
+    let (public_key, _) = ed25519::keypair_from_seed(&ed25519::Seed(key));
+
+    Ok((PublicKey::edpk(public_key.0), PrivateKey::edsk(key)))
+}
+
+/// Derive ed25519 key pair of the fundraiser account from the
+/// mnemonic, email and password, received during the fundraiser.
+///
+/// First 32 bytes of the BIP39 seed are used as a private key,
+/// where email followed by the password is used as a passphrase.
+pub fn fundraiser_keys(
+    mnemonic: &str,
+    email: &str,
+    password: &str,
+) -> Result<(PublicKey, PrivateKey), FromMnemonicError>
+{
+    let seed = mnemonic_to_seed(mnemonic, &format!("{}{}", email, password))?;
+    let mut key = [0; 32];
+    key.copy_from_slice(&seed[..32]);
+
+    let (public_key, _) = ed25519::keypair_from_seed(&ed25519::Seed(key));
+
+    Ok((PublicKey::edpk(public_key.0), PrivateKey::edsk(key)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto::ToBase58Check;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_ed25519_keys_from_mnemonic() {
+        let path: KeyDerivationPath = "m/44'/1729'/0'/0'".parse().unwrap();
+        let (public_key, private_key) = ed25519_keys_from_mnemonic(MNEMONIC, "", &path).unwrap();
+
+        assert_eq!(
+            private_key.to_base58check(),
+            "edsk4BBVKnpwdnJrx9PB4hLkXZHtceSdSZVTfKBXArhmZ3Jg87Lcxi",
+        );
+        assert_eq!(
+            public_key.to_base58check(),
+            "edpku4US3ZykcZifjzSGFCmFr3zRgCKndE82estE4irj4d5oqDNDvf",
+        );
+        assert_eq!(
+            public_key.hash().to_base58check(),
+            "tz1VQA4RP4fLjEEMW2FR4pE9kAg5abb5h5GL",
+        );
+
+        // passphrase is NFKD normalized before it's used.
+        for passphrase in &["caf\u{e9}", "cafe\u{301}"] {
+            let (public_key, _) = ed25519_keys_from_mnemonic(MNEMONIC, passphrase, &path).unwrap();
+            assert_eq!(
+                public_key.hash().to_base58check(),
+                "tz1hJgs7RWotdK6Mw9VBocGMu4mzRf1GaWZi",
+            );
+        }
+
+        let non_hardened: KeyDerivationPath = "m/44'/1729'/0'/0".parse().unwrap();
+        assert_eq!(
+            ed25519_keys_from_mnemonic(MNEMONIC, "", &non_hardened).err(),
+            Some(FromMnemonicError::NonHardenedIndex(NonHardenedIndexError(0))),
+        );
+    }
+
+    #[test]
+    fn test_fundraiser_keys() {
+        let (public_key, private_key) = fundraiser_keys(MNEMONIC, "foo@example.com", "bar").unwrap();
+
+        assert_eq!(
+            private_key.to_base58check(),
+            "edsk39vPCLK4Y7YiXze8iim9MzbcjDzto2tUyM8Lxc6mA1mjbpkS7v",
+        );
+        assert_eq!(
+            public_key.to_base58check(),
+            "edpkvVCGWsQ6J5Lfrg7UnyWyE6pRUPQUU7bdeNaMsDZAhMcX94yX2u",
+        );
+        assert_eq!(
+            public_key.hash().to_base58check(),
+            "tz1QDsWditWXZQnTte5FDzBzjrQWXr9Ez9jt",
+        );
+        assert!(fundraiser_keys("abandon", "foo@example.com", "bar").is_err());
+    }
+}
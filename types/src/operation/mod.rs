@@ -16,5 +16,14 @@ pub use new_delegation_operation::*;
 mod new_origination_operation;
 pub use new_origination_operation::*;
 
+mod new_activation_operation;
+pub use new_activation_operation::*;
+
+mod new_proposals_operation;
+pub use new_proposals_operation::*;
+
+mod new_ballot_operation;
+pub use new_ballot_operation::*;
+
 mod new_operation_group;
 pub use new_operation_group::*;
@@ -0,0 +1,55 @@
+use serde::{Serialize, Serializer};
+use sodiumoxide::hex;
+
+use crate::{Forge, ImplicitAddress};
+
+pub const ACTIVATION_SECRET_LEN: usize = 20;
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+#[error("invalid activation secret. Expected {} bytes as hex", ACTIVATION_SECRET_LEN)]
+pub struct InvalidActivationSecretError;
+
+fn serialize_secret<S>(secret: &[u8; ACTIVATION_SECRET_LEN], s: S) -> Result<S::Ok, S::Error>
+    where S: Serializer,
+{
+    s.serialize_str(&hex::encode(secret))
+}
+
+/// Activation of the fundraiser account.
+///
+/// Unlike manager operations, activation has no source, fee or
+/// counter and doesn't need to be signed. It can't be batched
+/// together with manager operations.
+#[derive(Serialize, PartialEq, Debug, Clone)]
+pub struct NewActivationOperation {
+    /// Fundraiser address. Must be `tz1` address.
+    pub pkh: ImplicitAddress,
+    /// Activation code of the fundraiser account.
+    #[serde(serialize_with = "serialize_secret")]
+    pub secret: [u8; ACTIVATION_SECRET_LEN],
+}
+
+impl NewActivationOperation {
+    /// Parse hex encoded activation secret.
+    pub fn parse_secret(
+        secret: &str,
+    ) -> Result<[u8; ACTIVATION_SECRET_LEN], InvalidActivationSecretError>
+    {
+        let bytes = hex::decode(secret.trim())
+            .map_err(|_| InvalidActivationSecretError)?;
+
+        if bytes.len() != ACTIVATION_SECRET_LEN {
+            return Err(InvalidActivationSecretError);
+        }
+        let mut result = [0; ACTIVATION_SECRET_LEN];
+        result.copy_from_slice(&bytes);
+        Ok(result)
+    }
+
+    /// Estimate byte size of the operation.
+    ///
+    /// Forges the operation and counts bytes.
+    pub fn estimate_bytes(&self) -> u64 {
+        self.forge().take().len() as u64
+    }
+}
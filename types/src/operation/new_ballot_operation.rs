@@ -0,0 +1,68 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::Serialize;
+
+use crate::{Forge, ImplicitAddress, ProtocolHash};
+
+#[derive(Serialize, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum Ballot {
+    Yay = 0,
+    Nay = 1,
+    Pass = 2,
+}
+
+impl Ballot {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Yay => "yay",
+            Self::Nay => "nay",
+            Self::Pass => "pass",
+        }
+    }
+}
+
+impl fmt::Display for Ballot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+#[error("invalid ballot \"{0}\". Expected one of: yay, nay, pass")]
+pub struct InvalidBallotError(String);
+
+impl FromStr for Ballot {
+    type Err = InvalidBallotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "yay" | "yea" => Ok(Self::Yay),
+            "nay" => Ok(Self::Nay),
+            "pass" => Ok(Self::Pass),
+            _ => Err(InvalidBallotError(s.to_string())),
+        }
+    }
+}
+
+/// Vote for the proposal during exploration or promotion voting period.
+///
+/// Like other voting operations, it has no fee or counter, but still
+/// needs to be signed by the `source` (delegate).
+#[derive(Serialize, PartialEq, Debug, Clone)]
+pub struct NewBallotOperation {
+    pub source: ImplicitAddress,
+    /// Index of the current voting period.
+    pub period: u32,
+    pub proposal: ProtocolHash,
+    pub ballot: Ballot,
+}
+
+impl NewBallotOperation {
+    /// Estimate byte size of the operation.
+    ///
+    /// Forges the operation and counts bytes.
+    pub fn estimate_bytes(&self) -> u64 {
+        self.forge().take().len() as u64
+    }
+}
@@ -64,7 +64,7 @@ impl NewDelegationOperationBuilder {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct NewDelegationOperation {
     pub source: ImplicitAddress,
     #[serde(rename = "delegate", skip_serializing_if = "Option::is_none")]
@@ -1,15 +1,23 @@
 use serde::Serialize;
 
 use utils::estimate_operation_fee;
-use super::{NewRevealOperation, NewTransactionOperation, NewDelegationOperation, NewOriginationOperation};
+use crate::ImplicitAddress;
+use super::{
+    NewRevealOperation, NewTransactionOperation, NewDelegationOperation,
+    NewOriginationOperation, NewActivationOperation,
+    NewProposalsOperation, NewBallotOperation,
+};
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, PartialEq, Debug, Clone)]
 #[serde(untagged)]
 pub enum NewOperation {
     Reveal(NewRevealOperation),
     Transaction(NewTransactionOperation),
     Delegation(NewDelegationOperation),
     Origination(NewOriginationOperation),
+    Activation(NewActivationOperation),
+    Proposals(NewProposalsOperation),
+    Ballot(NewBallotOperation),
 }
 
 impl NewOperation {
@@ -19,6 +27,35 @@ impl NewOperation {
             Self::Transaction(_) => "transaction",
             Self::Delegation(_) => "delegation",
             Self::Origination(_) => "origination",
+            Self::Activation(_) => "activate_account",
+            Self::Proposals(_) => "proposals",
+            Self::Ballot(_) => "ballot",
+        }
+    }
+
+    /// Whether it's a manager operation, which has fee, counter,
+    /// gas and storage limits.
+    pub fn is_manager(&self) -> bool {
+        match self {
+            Self::Reveal(_) | Self::Transaction(_)
+                | Self::Delegation(_) | Self::Origination(_) => true,
+            Self::Activation(_) | Self::Proposals(_) | Self::Ballot(_) => false,
+        }
+    }
+
+    /// Account, which needs to sign the operation.
+    ///
+    /// Activation has no source, since it doesn't need to be signed
+    /// by the activated account.
+    pub fn get_source(&self) -> Option<&ImplicitAddress> {
+        match self {
+            Self::Reveal(op) => Some(&op.source),
+            Self::Transaction(op) => Some(&op.source),
+            Self::Delegation(op) => Some(&op.source),
+            Self::Origination(op) => Some(&op.source),
+            Self::Proposals(op) => Some(&op.source),
+            Self::Ballot(op) => Some(&op.source),
+            Self::Activation(_) => None,
         }
     }
 
@@ -28,6 +65,8 @@ impl NewOperation {
             Self::Transaction(op) => op.fee,
             Self::Delegation(op) => op.fee,
             Self::Origination(op) => op.fee,
+            // only manager operations have fee, counter and gas limit.
+            Self::Activation(_) | Self::Proposals(_) | Self::Ballot(_) => 0,
         }
     }
 
@@ -37,6 +76,69 @@ impl NewOperation {
             Self::Transaction(op) => op.fee = fee,
             Self::Delegation(op) => op.fee = fee,
             Self::Origination(op) => op.fee = fee,
+            Self::Activation(_) | Self::Proposals(_) | Self::Ballot(_) => {}
+        }
+    }
+
+    pub fn get_counter(&self) -> u64 {
+        match self {
+            Self::Reveal(op) => op.counter,
+            Self::Transaction(op) => op.counter,
+            Self::Delegation(op) => op.counter,
+            Self::Origination(op) => op.counter,
+            // only manager operations have fee, counter and gas limit.
+            Self::Activation(_) | Self::Proposals(_) | Self::Ballot(_) => 0,
+        }
+    }
+
+    pub fn set_counter(&mut self, counter: u64) {
+        match self {
+            Self::Reveal(op) => op.counter = counter,
+            Self::Transaction(op) => op.counter = counter,
+            Self::Delegation(op) => op.counter = counter,
+            Self::Origination(op) => op.counter = counter,
+            Self::Activation(_) | Self::Proposals(_) | Self::Ballot(_) => {}
+        }
+    }
+
+    pub fn get_gas_limit(&self) -> u64 {
+        match self {
+            Self::Reveal(op) => op.gas_limit,
+            Self::Transaction(op) => op.gas_limit,
+            Self::Delegation(op) => op.gas_limit,
+            Self::Origination(op) => op.gas_limit,
+            // only manager operations have fee, counter and gas limit.
+            Self::Activation(_) | Self::Proposals(_) | Self::Ballot(_) => 0,
+        }
+    }
+
+    pub fn set_gas_limit(&mut self, gas_limit: u64) {
+        match self {
+            Self::Reveal(op) => op.gas_limit = gas_limit,
+            Self::Transaction(op) => op.gas_limit = gas_limit,
+            Self::Delegation(op) => op.gas_limit = gas_limit,
+            Self::Origination(op) => op.gas_limit = gas_limit,
+            Self::Activation(_) | Self::Proposals(_) | Self::Ballot(_) => {}
+        }
+    }
+
+    pub fn get_storage_limit(&self) -> u64 {
+        match self {
+            Self::Reveal(op) => op.storage_limit,
+            Self::Transaction(op) => op.storage_limit,
+            Self::Delegation(op) => op.storage_limit,
+            Self::Origination(op) => op.storage_limit,
+            Self::Activation(_) | Self::Proposals(_) | Self::Ballot(_) => 0,
+        }
+    }
+
+    pub fn set_storage_limit(&mut self, storage_limit: u64) {
+        match self {
+            Self::Reveal(op) => op.storage_limit = storage_limit,
+            Self::Transaction(op) => op.storage_limit = storage_limit,
+            Self::Delegation(op) => op.storage_limit = storage_limit,
+            Self::Origination(op) => op.storage_limit = storage_limit,
+            Self::Activation(_) | Self::Proposals(_) | Self::Ballot(_) => {}
         }
     }
 
@@ -49,6 +151,9 @@ impl NewOperation {
             Self::Transaction(op) => op.estimate_bytes(),
             Self::Delegation(op) => op.estimate_bytes(),
             Self::Origination(op) => op.estimate_bytes(),
+            Self::Activation(op) => op.estimate_bytes(),
+            Self::Proposals(op) => op.estimate_bytes(),
+            Self::Ballot(op) => op.estimate_bytes(),
         }
     }
 
@@ -94,6 +199,24 @@ impl From<NewOriginationOperation> for NewOperation {
     }
 }
 
+impl From<NewActivationOperation> for NewOperation {
+    fn from(op: NewActivationOperation) -> Self {
+        Self::Activation(op)
+    }
+}
+
+impl From<NewProposalsOperation> for NewOperation {
+    fn from(op: NewProposalsOperation) -> Self {
+        Self::Proposals(op)
+    }
+}
+
+impl From<NewBallotOperation> for NewOperation {
+    fn from(op: NewBallotOperation) -> Self {
+        Self::Ballot(op)
+    }
+}
+
 #[derive(Serialize, Debug, Clone)]
 pub struct NewOperationWithKind {
     kind: String,
@@ -1,23 +1,16 @@
 use serde::Serialize;
 
 use crate::BlockHash;
-use super::{
-    NewOperation,
-    NewRevealOperation,
-    NewTransactionOperation,
-    NewDelegationOperation,
-    NewOriginationOperation,
-};
+use super::NewOperation;
 
 /// Group/Batch of Operations.
+///
+/// Operations are applied in the same order as they are added.
 #[derive(Serialize, Debug, Clone)]
 pub struct NewOperationGroup {
     pub branch: BlockHash,
     pub next_protocol_hash: String,
-    pub reveal: Option<NewRevealOperation>,
-    pub transaction: Option<NewTransactionOperation>,
-    pub delegation: Option<NewDelegationOperation>,
-    pub origination: Option<NewOriginationOperation>,
+    pub operations: Vec<NewOperation>,
 }
 
 impl NewOperationGroup {
@@ -25,56 +18,150 @@ impl NewOperationGroup {
         Self {
             branch,
             next_protocol_hash,
-            reveal: None,
-            transaction: None,
-            delegation: None,
-            origination: None,
+            operations: vec![],
         }
     }
 
-    /// Set reveal operation
-    pub fn with_reveal(mut self, op: NewRevealOperation) -> Self {
-        self.reveal = Some(op);
+    /// Append operation to the end of the group.
+    ///
+    /// If added operation is a manager operation and group already
+    /// contains one, counter of the added operation is set to the
+    /// counter of the last manager operation + 1. Otherwise counter
+    /// is left as it is.
+    pub fn with_operation<T>(mut self, op: T) -> Self
+        where T: Into<NewOperation>,
+    {
+        let mut op = op.into();
+
+        let last_manager_op = self.operations.iter().rev()
+            .find(|op| op.is_manager());
+        if let (true, Some(last_op)) = (op.is_manager(), last_manager_op) {
+            op.set_counter(last_op.get_counter() + 1);
+        }
+
+        self.operations.push(op);
         self
     }
 
-    /// Set transaction operation
-    pub fn with_transaction(mut self, op: NewTransactionOperation) -> Self {
-        self.transaction = Some(op);
-        self
+    /// Append operations to the end of the group.
+    ///
+    /// Counters are assigned the same way as in [NewOperationGroup::with_operation].
+    pub fn with_operations<I, T>(self, ops: I) -> Self
+        where I: IntoIterator<Item = T>,
+              T: Into<NewOperation>,
+    {
+        ops.into_iter().fold(self, |group, op| group.with_operation(op))
     }
 
-    /// Set delegation operation
-    pub fn with_delegation(mut self, op: NewDelegationOperation) -> Self {
-        self.delegation = Some(op);
+    /// Assign consecutive counters to manager operations, starting
+    /// from `counter`. Other operations don't have a counter.
+    ///
+    /// `counter` must be the source's current counter + 1.
+    pub fn with_counter(mut self, counter: u64) -> Self {
+        let manager_ops = self.operations.iter_mut()
+            .filter(|op| op.is_manager());
+        for (index, op) in manager_ops.enumerate() {
+            op.set_counter(counter + index as u64);
+        }
         self
     }
 
-    /// Set origination operation
-    pub fn with_origination(mut self, op: NewOriginationOperation) -> Self {
-        self.origination = Some(op);
-        self
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
     }
 
-    pub fn with_operation<T>(self, op: T) -> Self
-        where T: Into<NewOperation>,
-    {
-        match op.into() {
-            NewOperation::Reveal(op) => self.with_reveal(op),
-            NewOperation::Transaction(op) => self.with_transaction(op),
-            NewOperation::Delegation(op) => self.with_delegation(op),
-            NewOperation::Origination(op) => self.with_origination(op),
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Sum of fees of all the operations in the group.
+    pub fn total_fee(&self) -> u64 {
+        self.operations.iter().map(|op| op.get_fee()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ImplicitAddress, NewTransactionOperation, NewActivationOperation,
+        NewBallotOperation, Ballot, ProtocolHash,
+    };
+    use super::*;
+
+    fn source() -> ImplicitAddress {
+        ImplicitAddress::from_base58check("tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx").unwrap()
+    }
+
+    fn transaction(counter: u64, fee: u64) -> NewTransactionOperation {
+        NewTransactionOperation {
+            source: source(),
+            destination: source().into(),
+            amount: 1000,
+            fee,
+            counter,
+            gas_limit: 10300,
+            storage_limit: 257,
+            parameters: None,
+        }
+    }
+
+    fn activation() -> NewActivationOperation {
+        NewActivationOperation {
+            pkh: source(),
+            secret: [0; 20],
+        }
+    }
+
+    fn ballot() -> NewBallotOperation {
+        NewBallotOperation {
+            source: source(),
+            period: 1,
+            proposal: ProtocolHash::from([0; 32]),
+            ballot: Ballot::Yay,
         }
     }
 
-    pub fn to_operations_vec(&self) -> Vec<NewOperation> {
-        let reveal = self.reveal.clone().map(|x| x.into());
-        let transaction = self.transaction.clone().map(|x| x.into());
-        let delegation = self.delegation.clone().map(|x| x.into());
-        let origination = self.origination.clone().map(|x| x.into());
-        vec![reveal, transaction, delegation, origination]
-            .into_iter()
-            .filter_map(|x| x)
-            .collect()
+    fn counters(group: &NewOperationGroup) -> Vec<u64> {
+        group.operations.iter().map(|op| op.get_counter()).collect()
+    }
+
+    #[test]
+    fn test_with_operation() {
+        let group = NewOperationGroup::new(BlockHash::from([0; 32]), "".to_string())
+            .with_operation(transaction(5, 0))
+            .with_operation(transaction(0, 0))
+            .with_operation(transaction(42, 0));
+        assert_eq!(counters(&group), vec![5, 6, 7]);
+
+        // counter isn't chained from non-manager operations.
+        let group = NewOperationGroup::new(BlockHash::from([0; 32]), "".to_string())
+            .with_operation(activation())
+            .with_operation(transaction(5, 0))
+            .with_operation(ballot())
+            .with_operation(transaction(0, 0));
+        assert_eq!(counters(&group), vec![0, 5, 0, 6]);
+    }
+
+    #[test]
+    fn test_with_counter() {
+        let group = NewOperationGroup::new(BlockHash::from([0; 32]), "".to_string())
+            .with_operation(activation())
+            .with_operations(vec![transaction(0, 0), transaction(0, 0)])
+            .with_operation(ballot())
+            .with_operation(transaction(0, 0))
+            .with_counter(10);
+        assert_eq!(counters(&group), vec![0, 10, 11, 0, 12]);
+    }
+
+    #[test]
+    fn test_total_fee() {
+        let group = NewOperationGroup::new(BlockHash::from([0; 32]), "".to_string());
+        assert_eq!(group.total_fee(), 0);
+
+        let group = group
+            .with_operation(transaction(1, 1000))
+            .with_operation(activation())
+            .with_operation(transaction(2, 2500));
+        assert_eq!(group.total_fee(), 3500);
     }
 }
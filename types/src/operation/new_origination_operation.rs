@@ -1,29 +1,55 @@
-use serde::{Serialize, Serializer};
+use serde::{Serialize, Deserialize};
 
-use crate::{Forge, Forged, ImplicitAddress};
-use crate::micheline::Micheline;
+use crate::{Forge, ImplicitAddress};
+use crate::micheline::{Micheline, PrimType};
 use utils::estimate_operation_fee;
 
+/// `manager.tz` script code in Micheline JSON format.
+pub const MANAGER_CONTRACT_CODE_JSON: &'static str = r#"[{"prim":"parameter","args":[{"prim":"or","args":[{"prim":"lambda","args":[{"prim":"unit"},{"prim":"list","args":[{"prim":"operation"}]}],"annots":["%do"]},{"prim":"unit","annots":["%default"]}]}]},{"prim":"storage","args":[{"prim":"key_hash"}]},{"prim":"code","args":[[{"prim":"UNPAIR"},{"prim":"IF_LEFT","args":[[{"prim":"PUSH","args":[{"prim":"mutez"},{"int":"0"}]},{"prim":"AMOUNT"},[[{"prim":"COMPARE"},{"prim":"EQ"}],{"prim":"IF","args":[[],[[{"prim":"UNIT"},{"prim":"FAILWITH"}]]]}],{"prim":"DUP","args":[{"int":"2"}]},{"prim":"IMPLICIT_ACCOUNT"},{"prim":"ADDRESS"},{"prim":"SENDER"},[[{"prim":"COMPARE"},{"prim":"EQ"}],{"prim":"IF","args":[[],[[{"prim":"UNIT"},{"prim":"FAILWITH"}]]]}],{"prim":"UNIT"},{"prim":"EXEC"},{"prim":"PAIR"}],[{"prim":"DROP"},{"prim":"NIL","args":[{"prim":"operation"}]},{"prim":"PAIR"}]]}]]}]"#;
+
+/// `manager.tz` script code, forged and hex encoded.
 pub const MANAGER_CONTRACT_CODE: &'static str = "02000000a005000764085e036c055f036d0000000325646f046c000000082564656661756c740501035d05020200000074037a072e020000005e0743036a00000313020000001e020000000403190325072c020000000002000000090200000004034f032705210002031e03540348020000001e020000000403190325072c020000000002000000090200000004034f0327034f0326034202000000080320053d036d0342";
 
-#[derive(Debug, Clone)]
+/// Parsed `manager.tz` script code.
+pub fn manager_contract_code() -> Micheline {
+    // unwrap is fine here since `MANAGER_CONTRACT_CODE_JSON` is a constant
+    // and a valid Micheline JSON.
+    serde_json::from_str(MANAGER_CONTRACT_CODE_JSON).unwrap()
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct NewOriginationScript {
-    // TODO: replace `Forged` type with Micheline.
-    pub code: Forged,
+    pub code: Micheline,
     pub storage: Micheline,
 }
 
-// TODO: this is just a placeholder to make type system happy.
-// remove after Serialization is implemented for `Micheline`.
-impl Serialize for NewOriginationScript {
-    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
-        where S: Serializer
-    {
-        s.serialize_none()
+impl NewOriginationScript {
+    /// Type of the contract's parameter (`parameter` section of the code).
+    pub fn parameter_type(&self) -> Option<&Micheline> {
+        self.code_section(PrimType::parameter)
+    }
+
+    /// Type of the contract's storage (`storage` section of the code).
+    pub fn storage_type(&self) -> Option<&Micheline> {
+        self.code_section(PrimType::storage)
+    }
+
+    fn code_section(&self, section: PrimType) -> Option<&Micheline> {
+        let sections = match &self.code {
+            Micheline::Array(sections) => sections,
+            _ => return None,
+        };
+
+        sections.iter().find_map(|item| match item {
+            Micheline::Prim(prim) if prim.prim_type == section => {
+                prim.args.as_ref()?.first()
+            }
+            _ => None,
+        })
     }
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct NewOriginationOperation {
     pub source: ImplicitAddress,
     #[serde(with = "utils::serde_amount")]
@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+use crate::{Forge, ImplicitAddress, ProtocolHash};
+
+/// Submit or upvote proposals during proposal voting period.
+///
+/// Like other voting operations, it has no fee or counter, but still
+/// needs to be signed by the `source` (delegate).
+#[derive(Serialize, PartialEq, Debug, Clone)]
+pub struct NewProposalsOperation {
+    pub source: ImplicitAddress,
+    /// Index of the current voting period.
+    pub period: u32,
+    pub proposals: Vec<ProtocolHash>,
+}
+
+impl NewProposalsOperation {
+    /// Estimate byte size of the operation.
+    ///
+    /// Forges the operation and counts bytes.
+    pub fn estimate_bytes(&self) -> u64 {
+        self.forge().take().len() as u64
+    }
+}
@@ -3,7 +3,7 @@ use serde::Serialize;
 use crate::{Forge, PublicKey, ImplicitAddress};
 use utils::estimate_operation_fee;
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, PartialEq, Debug, Clone)]
 pub struct NewRevealOperation {
     pub source: ImplicitAddress,
     pub public_key: PublicKey,
@@ -52,7 +52,7 @@ impl NewTransactionOperationBuilder {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct NewTransactionOperation {
     pub source: ImplicitAddress,
     pub destination: Address,
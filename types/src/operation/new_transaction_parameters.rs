@@ -3,12 +3,16 @@ use serde_json::json;
 use sodiumoxide::hex;
 
 use crate::{Forge, Address, ImplicitAddress};
+use crate::micheline::{Micheline, MichelineEntrypoint};
 
 /// Parameters for Smart Contract.
 ///
 /// In order to interact with the Smart Contract, transaction must be
 /// created with the destination set to smart contract's address.
 ///
+/// All variants except [NewTransactionParameters::Call] are calls to
+/// the `do` entrypoint of the `manager.tz` contract.
+///
 /// Note: Smart contract's address starts with **KT1**.
 #[derive(Deserialize, PartialEq, Debug, Clone)]
 pub enum NewTransactionParameters {
@@ -23,6 +27,20 @@ pub enum NewTransactionParameters {
         /// Amount to transfer.
         amount: u64,
     },
+    /// Call any entrypoint of any smart contract.
+    Call {
+        entrypoint: MichelineEntrypoint,
+        value: Micheline,
+    },
+}
+
+impl NewTransactionParameters {
+    pub fn entrypoint(&self) -> MichelineEntrypoint {
+        match self {
+            Self::Call { entrypoint, .. } => entrypoint.clone(),
+            _ => MichelineEntrypoint::Do,
+        }
+    }
 }
 
 fn set_delegate_json(addr: &ImplicitAddress) -> serde_json::Value {
@@ -99,11 +117,12 @@ impl Serialize for NewTransactionParameters {
         where S: Serializer,
     {
         json!({
-            "entrypoint": "do",
+            "entrypoint": self.entrypoint(),
             "value": match self {
                 Self::SetDelegate(addr) => set_delegate_json(addr),
                 Self::CancelDelegate => cancel_delegate_json(),
                 Self::Transfer { to, amount } => transfer_json(to, *amount),
+                Self::Call { value, .. } => json!(value),
             },
         }).serialize(s)
     }
@@ -1,36 +1,152 @@
 use std::convert::TryInto;
+use std::fmt;
+use std::str::FromStr;
 
-use crypto::{Prefix, WithPrefix, WithoutPrefix};
+use crypto::{Prefix, WithPrefix, WithoutPrefix, NotMatchingPrefixError};
 use crypto::base58check::{FromBase58Check, ToBase58Check};
 use super::FromPrefixedBase58CheckError;
 
-type PrivateKeyInner = [u8; 32];
+pub const PRIVATE_KEY_LEN: usize = 32;
+type PrivateKeyInner = [u8; PRIVATE_KEY_LEN];
 
+/// Elliptic curve of the key.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Curve {
+    /// tz1 addresses.
+    Ed25519,
+    /// tz2 addresses.
+    Secp256k1,
+    /// tz3 addresses.
+    P256,
+}
+
+impl Curve {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ed25519 => "ed25519",
+            Self::Secp256k1 => "secp256k1",
+            Self::P256 => "p256",
+        }
+    }
+}
+
+impl fmt::Display for Curve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(thiserror::Error, PartialEq, Debug, Clone)]
+#[error("unknown curve \"{0}\". Expected one of: ed25519, secp256k1, p256")]
+pub struct UnknownCurveError(String);
+
+impl FromStr for Curve {
+    type Err = UnknownCurveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "ed25519" | "tz1" => Ok(Self::Ed25519),
+            "secp256k1" | "tz2" => Ok(Self::Secp256k1),
+            "p256" | "p-256" | "tz3" => Ok(Self::P256),
+            _ => Err(UnknownCurveError(s.to_string())),
+        }
+    }
+}
+
+/// Secret key for one of the supported curves.
+///
+/// - `edsk` - Ed25519 seed.
+/// - `spsk` - Secp256k1 secret key.
+/// - `p2sk` - P-256 secret key.
+#[allow(non_camel_case_types)]
 #[derive(Clone)]
-pub struct PrivateKey(PrivateKeyInner);
+pub enum PrivateKey {
+    edsk(PrivateKeyInner),
+    spsk(PrivateKeyInner),
+    p2sk(PrivateKeyInner),
+}
 
 impl PrivateKey {
+    pub fn new(curve: Curve, key: PrivateKeyInner) -> Self {
+        match curve {
+            Curve::Ed25519 => Self::edsk(key),
+            Curve::Secp256k1 => Self::spsk(key),
+            Curve::P256 => Self::p2sk(key),
+        }
+    }
+
+    pub fn curve(&self) -> Curve {
+        match self {
+            Self::edsk(_) => Curve::Ed25519,
+            Self::spsk(_) => Curve::Secp256k1,
+            Self::p2sk(_) => Curve::P256,
+        }
+    }
+
+    fn try_without_prefix(
+        bytes: &[u8],
+        prefix: Prefix,
+    ) -> Result<(Prefix, Vec<u8>), NotMatchingPrefixError>
+    {
+        bytes
+            .without_prefix(prefix)
+            .map(|bytes| (prefix, bytes))
+    }
+
+    /// Parse base58check encoded private key.
+    ///
+    /// Besides 32 byte Ed25519 seed (`edsk`, 54 characters), 64 byte
+    /// Ed25519 secret key (`edsk`, 98 characters) is accepted as well,
+    /// in which case first 32 bytes (the seed) are used.
     pub fn from_base58check(encoded: &str) -> Result<Self, FromPrefixedBase58CheckError> {
-        let key_bytes: PrivateKeyInner = encoded
-            .from_base58check()?
-            .without_prefix(Prefix::edsk32)?
-            .try_into()
+        let bytes = encoded.from_base58check()?;
+
+        let (prefix, mut bytes_vec) = Self::try_without_prefix(&bytes, Prefix::edsk32)
+            .or_else(|_| Self::try_without_prefix(&bytes, Prefix::edsk64))
+            .or_else(|_| Self::try_without_prefix(&bytes, Prefix::spsk))
+            .or_else(|_| Self::try_without_prefix(&bytes, Prefix::p2sk))?;
+
+        if let Prefix::edsk64 = prefix {
+            if bytes_vec.len() != 64 {
+                return Err(FromPrefixedBase58CheckError::InvalidSize);
+            }
+            bytes_vec.truncate(PRIVATE_KEY_LEN);
+        }
+
+        let inner = bytes_vec.try_into()
             .or(Err(FromPrefixedBase58CheckError::InvalidSize))?;
 
-        Ok(Self(key_bytes))
+        match prefix {
+            Prefix::edsk32 | Prefix::edsk64 => Ok(Self::edsk(inner)),
+            Prefix::spsk => Ok(Self::spsk(inner)),
+            Prefix::p2sk => Ok(Self::p2sk(inner)),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn get_prefix(&self) -> Prefix {
+        match self {
+            Self::edsk(_) => Prefix::edsk32,
+            Self::spsk(_) => Prefix::spsk,
+            Self::p2sk(_) => Prefix::p2sk,
+        }
     }
 }
 
 impl ToBase58Check for PrivateKey {
     fn to_base58check(&self) -> String {
-        self.0
-            .with_prefix(Prefix::edsk32)
+        self.as_ref()
+            .with_prefix(self.get_prefix())
             .to_base58check()
     }
 }
 
 impl AsRef<PrivateKeyInner> for PrivateKey {
     fn as_ref(&self) -> &PrivateKeyInner {
-        &self.0
+        match self {
+            Self::edsk(k) => k,
+            Self::spsk(k) => k,
+            Self::p2sk(k) => k,
+        }
     }
 }
@@ -0,0 +1,68 @@
+use std::convert::TryInto;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crypto::{Prefix, WithPrefix, WithoutPrefix};
+use crypto::base58check::{FromBase58Check, ToBase58Check};
+use super::FromPrefixedBase58CheckError;
+
+type ProtocolHashInner = [u8; 32];
+
+/// Hash of the protocol (`P...`), used for voting on protocol amendments.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ProtocolHash(ProtocolHashInner);
+
+impl ProtocolHash {
+    pub fn from_base58check(encoded: &str) -> Result<Self, FromPrefixedBase58CheckError> {
+        let key_bytes: ProtocolHashInner = encoded
+            .from_base58check()?
+            .without_prefix(Prefix::P)?
+            .try_into()
+            .or(Err(FromPrefixedBase58CheckError::InvalidSize))?;
+
+        Ok(Self(key_bytes))
+    }
+}
+
+impl ToBase58Check for ProtocolHash {
+    fn to_base58check(&self) -> String {
+        self.0
+            .with_prefix(Prefix::P)
+            .to_base58check()
+    }
+}
+
+impl From<ProtocolHashInner> for ProtocolHash {
+    fn from(inner: ProtocolHashInner) -> Self {
+        Self(inner)
+    }
+}
+
+impl AsRef<ProtocolHashInner> for ProtocolHash {
+    fn as_ref(&self) -> &ProtocolHashInner {
+        &self.0
+    }
+}
+
+impl Serialize for ProtocolHash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_str(
+            &self.to_base58check()
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for ProtocolHash {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>,
+    {
+        let encoded = String::deserialize(deserializer)?;
+
+        ProtocolHash::from_base58check(&encoded)
+            .map_err(|err| {
+                serde::de::Error::custom(err)
+            })
+    }
+}
+
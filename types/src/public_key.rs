@@ -1,5 +1,5 @@
 use std::convert::TryInto;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crypto::{blake2b, Prefix, WithPrefix, WithoutPrefix, NotMatchingPrefixError};
 use crypto::base58check::{FromBase58Check, ToBase58Check};
@@ -49,6 +49,14 @@ impl PublicKey {
         }
     }
 
+    pub fn get_prefix(&self) -> Prefix {
+        match self {
+            Self::edpk(_) => Prefix::edpk,
+            Self::sppk(_) => Prefix::sppk,
+            Self::p2pk(_) => Prefix::p2pk,
+        }
+    }
+
     fn curve_prefix(&self) -> u8 {
         match self {
             Self::edpk(_) => 0x01,
@@ -82,7 +90,7 @@ impl PublicKey {
 impl ToBase58Check for PublicKey {
     fn to_base58check(&self) -> String {
         self.as_ref()
-            .with_prefix(Prefix::edpk)
+            .with_prefix(self.get_prefix())
             .to_base58check()
     }
 }
@@ -106,3 +114,16 @@ impl Serialize for PublicKey {
         )
     }
 }
+
+impl<'de> Deserialize<'de> for PublicKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>,
+    {
+        let encoded = String::deserialize(deserializer)?;
+
+        Self::from_base58check(&encoded)
+            .map_err(|err| {
+                serde::de::Error::custom(err)
+            })
+    }
+}
@@ -0,0 +1,106 @@
+use std::convert::TryInto;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crypto::{Prefix, WithPrefix, WithoutPrefix, NotMatchingPrefixError};
+use crypto::base58check::{FromBase58Check, ToBase58Check};
+use super::FromPrefixedBase58CheckError;
+
+pub const SIGNATURE_LEN: usize = 64;
+type SignatureInner = [u8; SIGNATURE_LEN];
+
+/// Signature created by one of the supported curves.
+///
+/// - `edsig` - Ed25519.
+/// - `spsig1` - Secp256k1.
+/// - `p2sig` - P-256.
+/// - `sig` - Generic signature, where curve is unknown. Signature bytes
+///   are the same for all curves, so any of them can be represented as `sig`.
+#[allow(non_camel_case_types)]
+#[derive(PartialEq, Debug, Clone)]
+pub enum Signature {
+    edsig(SignatureInner),
+    spsig1(SignatureInner),
+    p2sig(SignatureInner),
+    sig(SignatureInner),
+}
+
+impl Signature {
+    fn try_without_prefix(
+        bytes: &[u8],
+        prefix: Prefix,
+    ) -> Result<(Prefix, Vec<u8>), NotMatchingPrefixError>
+    {
+        bytes
+            .without_prefix(prefix)
+            .map(|bytes| (prefix, bytes))
+    }
+
+    pub fn from_base58check(encoded: &str) -> Result<Self, FromPrefixedBase58CheckError> {
+        let bytes = encoded.from_base58check()?;
+
+        let (prefix, bytes_vec) = Self::try_without_prefix(&bytes, Prefix::edsig)
+            .or_else(|_| Self::try_without_prefix(&bytes, Prefix::spsig1))
+            .or_else(|_| Self::try_without_prefix(&bytes, Prefix::p2sig))
+            .or_else(|_| Self::try_without_prefix(&bytes, Prefix::sig))?;
+
+        let inner = bytes_vec.try_into()
+            .or(Err(FromPrefixedBase58CheckError::InvalidSize))?;
+
+        match prefix {
+            Prefix::edsig => Ok(Self::edsig(inner)),
+            Prefix::spsig1 => Ok(Self::spsig1(inner)),
+            Prefix::p2sig => Ok(Self::p2sig(inner)),
+            Prefix::sig => Ok(Self::sig(inner)),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn get_prefix(&self) -> Prefix {
+        match self {
+            Self::edsig(_) => Prefix::edsig,
+            Self::spsig1(_) => Prefix::spsig1,
+            Self::p2sig(_) => Prefix::p2sig,
+            Self::sig(_) => Prefix::sig,
+        }
+    }
+}
+
+impl ToBase58Check for Signature {
+    fn to_base58check(&self) -> String {
+        self.as_ref().with_prefix(self.get_prefix()).to_base58check()
+    }
+}
+
+impl AsRef<SignatureInner> for Signature {
+    fn as_ref(&self) -> &SignatureInner {
+        match self {
+            Self::edsig(sig) => sig,
+            Self::spsig1(sig) => sig,
+            Self::p2sig(sig) => sig,
+            Self::sig(sig) => sig,
+        }
+    }
+}
+
+impl Serialize for Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_str(
+            &self.to_base58check()
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>,
+    {
+        let encoded = String::deserialize(deserializer)?;
+
+        Self::from_base58check(&encoded)
+            .map_err(|err| {
+                serde::de::Error::custom(err)
+            })
+    }
+}
@@ -0,0 +1,15 @@
+//! Fixtures shared between tests of this and dependent crates.
+
+use crate::Address;
+
+/// Private key used in tests, for each of the supported curves.
+pub const EDSK: &str = "edsk3NmghEMdi8CFKU3VwJKfzmGbPvBTVukhEXqe4XuXKRYbvx4mxo";
+pub const SPSK: &str = "spsk2rBDDeUqakQ42nBHDGQTtP3GErb6AahHPwF9bhca3Q5KA5HESE";
+pub const P2SK: &str = "p2sk2obfVMEuPUnadAConLWk7Tf4Dt3n4svSgJwrgpamRqJXvaYcg1";
+
+/// Public key of the [EDSK].
+pub const EDPK: &str = "edpkvDFBqnw7WyvKjQMf1WcCnbeocqMwASys3Te4Z9gaznyfzuPFiU";
+
+pub fn address(address: &str) -> Address {
+    Address::from_base58check(address).unwrap()
+}